@@ -1,6 +1,7 @@
+use bevy::prelude::*;
 use thiserror::Error;
 
-#[derive(Debug, Error, PartialEq)]
+#[derive(Debug, Error, PartialEq, Clone)]
 pub enum StylesFileError {
     #[error("Failed to read the file")]
     ReadStylesFileFail,
@@ -12,7 +13,7 @@ pub enum StylesFileError {
     ReadStylesFromFileToStructFail,
 }
 
-#[derive(Debug, Error, PartialEq)]
+#[derive(Debug, Error, PartialEq, Clone)]
 pub enum ToRValErr {
     #[error("Unsupported type for convert into RVal")]
     ConvertToRValFail,
@@ -20,3 +21,79 @@ pub enum ToRValErr {
     #[error("Unsupported type")]
     UnsupportedType
 }
+
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum ComputedKeyErr {
+    #[error("Computed key dependency cycle: {0}")]
+    DependencyCycle(String),
+}
+
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum ExpressionErr {
+    #[error("Invalid expression: {0}")]
+    Syntax(String),
+
+    #[error("Reactive key not found: {0}")]
+    KeyNotFound(String),
+
+    #[error("Unknown filter: {0}")]
+    UnknownFilter(String),
+
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+}
+
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum PersistErr {
+    #[error("Failed to read persisted data: {0}")]
+    Read(String),
+
+    #[error("Failed to write persisted data: {0}")]
+    Write(String),
+
+    #[error("Invalid persisted data: {0}")]
+    Parse(String),
+}
+
+/// Crate-wide error. Returned by `try_*` methods and sent as `FamiqErrorEvent`
+/// by systems, so misuse can be handled instead of aborting.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum FamiqError {
+    #[error("Reactive key not found: {0}")]
+    KeyNotFound(String),
+
+    #[error("Expected RVal::{expected}, found RVal::{found}")]
+    TypeMismatch {
+        expected: &'static str,
+        found: &'static str
+    },
+
+    #[error("ID {0:?} is duplicated")]
+    DuplicatedId(String),
+
+    #[error("Persistence is not enabled, see FamiqPlugin::with_persistence")]
+    PersistenceDisabled,
+
+    #[error("Invalid styles: {0}")]
+    InvalidStyles(String),
+
+    #[error(transparent)]
+    StylesFile(#[from] StylesFileError),
+
+    #[error(transparent)]
+    ToRVal(#[from] ToRValErr),
+
+    #[error(transparent)]
+    ComputedKey(#[from] ComputedKeyErr),
+
+    #[error(transparent)]
+    Expression(#[from] ExpressionErr),
+
+    #[error(transparent)]
+    Persist(#[from] PersistErr),
+}
+
+/// Sent when famiq runs into an error it can recover from, eg. duplicated IDs
+/// or an invalid styles file.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct FamiqErrorEvent(pub FamiqError);
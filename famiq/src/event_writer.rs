@@ -1,4 +1,5 @@
 use crate::widgets::*;
+use crate::reactivity::RVal;
 
 use bevy::ecs::event::EventWriter;
 use bevy::prelude::*;
@@ -100,3 +101,131 @@ impl FaMouseEvent {
         });
     }
 }
+
+/// Value of a widget is changed by user, eg. slider's thumb is moved.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct FaValueChangeEvent {
+    pub widget_type: WidgetType,
+    pub entity: Entity,
+    pub id: Option<String>,
+    pub value: RVal
+}
+
+impl FaValueChangeEvent {
+    pub fn new(entity: Entity, id: Option<String>, widget_type: WidgetType, value: RVal) -> Self {
+        Self {
+            widget_type,
+            entity,
+            id,
+            value
+        }
+    }
+
+    /// value of widget with provided id is changed
+    pub fn is_changed(&self, id: &str) -> bool {
+        self.id.as_deref() == Some(id)
+    }
+
+    pub(crate) fn send_event(
+        writer: &mut EventWriter<FaValueChangeEvent>,
+        widget_type: WidgetType,
+        entity: Entity,
+        id: Option<&WidgetId>,
+        value: RVal
+    ) {
+        writer.write(FaValueChangeEvent {
+            widget_type,
+            entity,
+            id: id.map(|_id| _id.0.clone()),
+            value
+        });
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TreeEventType {
+    Expand,
+    Collapse,
+    Select,
+    Activate // double click or Enter
+}
+
+/// A node of a tree is expanded, collapsed, selected or activated.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct FaTreeEvent {
+    pub event_type: TreeEventType,
+    pub entity: Entity,
+    pub id: Option<String>,
+    /// id of the node, or its index path if it has no id. Eg, "0.2"
+    pub node: String,
+    /// reactive path of the node. Eg, "files.0.children.2"
+    pub path: String
+}
+
+impl FaTreeEvent {
+    /// node of tree with provided id is expanded
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.event_type == TreeEventType::Expand && self.id.as_deref() == Some(id)
+    }
+
+    /// node of tree with provided id is activated
+    pub fn is_activated(&self, id: &str) -> bool {
+        self.event_type == TreeEventType::Activate && self.id.as_deref() == Some(id)
+    }
+
+    /// reactive path of the node's children, where children of lazy nodes are loaded.
+    pub fn children_path(&self) -> String {
+        format!("{}.children", self.path)
+    }
+
+    pub(crate) fn send_event(
+        writer: &mut EventWriter<FaTreeEvent>,
+        event_type: TreeEventType,
+        entity: Entity,
+        id: Option<&WidgetId>,
+        node: &str,
+        path: &str
+    ) {
+        writer.write(FaTreeEvent {
+            event_type,
+            entity,
+            id: id.map(|_id| _id.0.clone()),
+            node: node.to_string(),
+            path: path.to_string()
+        });
+    }
+}
+
+/// An item of a menu or menu bar is clicked.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct FaMenuEvent {
+    /// menu or menu bar entity
+    pub entity: Entity,
+    pub id: Option<String>,
+    /// id of the item, or its label if it has no id
+    pub item: String,
+    /// new state of checkable item
+    pub checked: Option<bool>
+}
+
+impl FaMenuEvent {
+    /// item with provided id (or label) is clicked
+    pub fn is_clicked(&self, item: &str) -> bool {
+        self.item == item
+    }
+
+    pub(crate) fn send_event(
+        writer: &mut EventWriter<FaMenuEvent>,
+        entity: Entity,
+        id: Option<&WidgetId>,
+        item: &str,
+        checked: Option<bool>
+    ) {
+        writer.write(FaMenuEvent {
+            entity,
+            id: id.map(|_id| _id.0.clone()),
+            item: item.to_string(),
+            checked
+        });
+    }
+}
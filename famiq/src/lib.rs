@@ -35,6 +35,9 @@
 //! ```
 
 #![deny(ambiguous_glob_reexports)]
+// lets derive macros refer to `::famiq` inside this crate too
+extern crate self as famiq;
+
 pub mod errors;
 pub mod resources;
 pub mod event_writer;
@@ -45,11 +48,13 @@ pub mod reactivity;
 
 pub mod prelude {
     pub use crate::plugin::FamiqPlugin;
+    pub use crate::errors::{FamiqError, FamiqErrorEvent};
+    pub use famiq_macros::{IntoRVal, FromRVal};
     pub use crate::resources::*;
     pub use crate::reactivity::*;
     pub use crate::utils::*;
     pub use crate::widgets::{
-        FamiqBuilder, WidgetType, WidgetId,
+        FamiqBuilder, WidgetType, WidgetId, WidgetColor,
         DefaultTextConfig, DefaultWidgetConfig,
         SetWidgetAttributes, FaQuery, WidgetSelector,
         SetupWidget, WidgetBuilder, BuilderType,
@@ -57,13 +62,24 @@ pub mod prelude {
         text::TextBuilder,
         button::ButtonBuilder,
         container::ContainerBuilder,
+        conditional::ConditionalBuilder,
+        for_each::ForEachBuilder,
         image::ImageBuilder,
         checkbox::CheckboxBuilder,
         circular::CircularBuilder,
         text_input::TextInputBuilder,
+        textarea::TextAreaBuilder,
         selection::SelectionBuilder,
         dialog::DialogBuilder,
         progress_bar::ProgressBarBuilder,
+        slider::SliderBuilder,
+        radio::RadioBuilder,
+        switch::SwitchBuilder,
+        tabs::TabsBuilder,
+        table::{TableBuilder, TableColumn},
+        tree::{TreeBuilder, TreeNode},
+        menu::{MenuBuilder, MenuBarBuilder, MenuItem, FaMenuState},
+        notification::{Notifications, NotificationCorner},
         scroll::ScrollBuilder,
         fps::FpsBuilder,
     };
@@ -72,15 +88,27 @@ pub mod prelude {
     pub use crate::checkbox;
     pub use crate::circular;
     pub use crate::container;
+    pub use crate::for_each;
+    pub use crate::if_else;
     pub use crate::fps;
     pub use crate::image;
     pub use crate::scroll;
     pub use crate::dialog;
     pub use crate::progress_bar;
+    pub use crate::slider;
+    pub use crate::radio;
+    pub use crate::switch;
+    pub use crate::tabs;
+    pub use crate::table;
+    pub use crate::tree;
+    pub use crate::menu;
+    pub use crate::menu_bar;
     pub use crate::selection;
     pub use crate::text;
     pub use crate::text_input;
-    pub use crate::event_writer::FaMouseEvent;
+    pub use crate::number_input;
+    pub use crate::textarea;
+    pub use crate::event_writer::{FaMouseEvent, FaValueChangeEvent, FaTreeEvent, TreeEventType, FaMenuEvent};
     pub use crate::errors::*;
     pub use bevy::platform::collections::HashMap;
     pub use serde_json;
@@ -1,16 +1,28 @@
 //! Famiq's plugin.
 
 use crate::event_writer;
+use crate::errors::{FamiqError, FamiqErrorEvent};
 use crate::resources::*;
 use crate::reactivity::*;
 use crate::widgets::{
     selection::*,
     text_input::*,
+    textarea::*,
     scroll::*,
     fps::*,
+    for_each::*,
+    conditional::*,
     circular::*,
     dialog::*,
     progress_bar::*,
+    slider::*,
+    radio::*,
+    switch::*,
+    tabs::*,
+    table::*,
+    tree::*,
+    menu::*,
+    notification::*,
     *
 };
 
@@ -86,7 +98,13 @@ fn external_styles_file_systems(app: &mut App) {
 
     #[cfg(target_arch = "wasm32")]
     {
-        app.add_systems(PreUpdate, style::load_json_style_asset_wasm);
+        app.add_systems(
+            PreUpdate,
+            (
+                style::report_json_style_asset_failure,
+                style::load_json_style_asset_wasm
+            ).chain()
+        );
     }
 }
 
@@ -110,7 +128,9 @@ fn fa_text_input_systems(app: &mut App) {
             detect_text_input_text_style_change.after(detect_new_text_input_widget_system),
             handle_text_input_on_focused,
             handle_cursor_blink_system,
-            detect_new_text_input_widget_system,
+            detect_new_text_input_widget_system.after(detect_text_input_reactive_model_change),
+            detect_text_input_reactive_model_change.after(handle_text_input_on_typing),
+            clamp_number_input_on_blur.after(detect_text_input_reactive_model_change),
 
             #[cfg(target_arch = "wasm32")]
             on_wasm_paste,
@@ -120,7 +140,24 @@ fn fa_text_input_systems(app: &mut App) {
     app.add_systems(
         PostUpdate,
         on_request_redraw_editor_buffer
-            .run_if(cosmic_font_system_exists.and(can_run_text_input_systems))
+            .run_if(cosmic_font_system_exists.and(can_run_text_input_systems.or(can_run_textarea_systems)))
+    );
+}
+
+fn fa_textarea_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            handle_textarea_on_typing,
+            detect_textarea_reactive_model_change,
+            detect_new_textarea_widget_system,
+            detect_textarea_text_style_change,
+            update_textarea_layout,
+            handle_textarea_on_focused,
+            handle_textarea_cursor_blink_system
+        )
+        .chain()
+        .run_if(cosmic_font_system_exists.and(can_run_textarea_systems))
     );
 }
 
@@ -132,6 +169,25 @@ fn fa_scroll_systems(app: &mut App) {
     app.add_systems(Update, detect_new_scroll_system.run_if(can_run_scroll_systems));
 }
 
+fn fa_for_each_systems(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        reconcile_for_each_system
+            .before(detect_reactive_data_change)
+            .run_if(can_run_for_each_systems)
+    );
+}
+
+fn fa_conditional_systems(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        update_conditional_system
+            .after(reconcile_for_each_system)
+            .before(detect_reactive_data_change)
+            .run_if(can_run_conditional_systems)
+    );
+}
+
 fn fa_fps_text_systems(app: &mut App) {
     app.add_systems(
         Update,
@@ -161,23 +217,156 @@ fn fa_progress_bar_systems(app: &mut App) {
     );
 }
 
+fn fa_slider_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            handle_slider_dragging,
+            handle_slider_keyboard,
+            detect_slider_reactive_model_change,
+            update_slider_nodes
+        )
+        .chain()
+        .run_if(can_run_slider_systems)
+    );
+}
+
+fn fa_radio_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (detect_radio_reactive_model_change, update_radio_dots)
+            .chain()
+            .run_if(can_run_radio_systems)
+    );
+}
+
+fn fa_switch_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            detect_switch_reactive_model_change,
+            update_switch_label,
+            animate_switch
+        )
+        .chain()
+        .run_if(can_run_switch_systems)
+    );
+}
+
+fn fa_tabs_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            handle_tabs_keyboard,
+            detect_tabs_reactive_model_change,
+            update_tab_headers
+        )
+        .chain()
+        .run_if(can_run_tabs_systems)
+    );
+    app.add_systems(
+        PostUpdate,
+        update_tab_panels
+            .after(reconcile_for_each_system)
+            .before(detect_reactive_data_change)
+            .run_if(can_run_tabs_systems)
+    );
+}
+
+fn fa_table_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            handle_table_editing,
+            detect_table_reactive_model_change,
+            update_table_rows,
+            update_table_columns,
+            update_sticky_table_headers
+        )
+        .chain()
+        .run_if(can_run_table_systems)
+    );
+}
+
+fn fa_tree_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            handle_tree_keyboard,
+            detect_tree_reactive_model_change,
+            update_tree_rows
+        )
+        .chain()
+        .run_if(can_run_tree_systems)
+    );
+}
+
+fn fa_menu_systems(app: &mut App) {
+    app.add_observer(on_context_menu_pressed);
+    app.add_systems(
+        Update,
+        (
+            handle_menu_closing,
+            update_menu_visibility,
+            keep_menus_in_window
+        )
+        .chain()
+        .run_if(can_run_menu_systems)
+    );
+}
+
+fn fa_notification_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            spawn_notifications,
+            update_notification_layer,
+            tick_notification_timers,
+            animate_notifications
+        )
+        .chain()
+        .run_if(can_run_notification_systems)
+    );
+}
+
 pub struct FamiqPlugin {
     /// Setup Camera2d by default
-    pub default_camera: bool
+    pub default_camera: bool,
+    /// Max undo steps of reactive data, history is disabled if None
+    pub history_size: Option<usize>,
+    /// File (local storage key on web) saving persistent keys, disabled if None
+    pub persist_path: Option<String>
 }
 
 impl FamiqPlugin {
     pub fn new() -> Self {
         Self {
-            default_camera: true
+            default_camera: true,
+            history_size: None,
+            persist_path: None
         }
     }
 
     pub fn new_no_camera() -> Self {
         Self {
-            default_camera: false
+            default_camera: false,
+            history_size: None,
+            persist_path: None
         }
     }
+
+    /// Record changes of reactive data for undo/redo, see `RHistory`.
+    pub fn with_history(mut self, max_entries: usize) -> Self {
+        self.history_size = Some(max_entries);
+        self
+    }
+
+    /// Save keys marked with `fa_query.persist` to `path` (JSON) when they change,
+    /// and restore them on next run. On web, `path` is the local storage key.
+    pub fn with_persistence(mut self, path: &str) -> Self {
+        self.persist_path = Some(path.to_string());
+        self
+    }
 }
 
 impl Plugin for FamiqPlugin {
@@ -207,6 +396,19 @@ impl Plugin for FamiqPlugin {
         );
         app.add_systems(PostUpdate, on_update_subscriber_event);
         app.add_systems(PostUpdate, detect_reactive_data_change);
+        app.add_systems(PostUpdate, run_reactive_watchers.after(detect_reactive_data_change));
+        app.add_systems(
+            PostUpdate,
+            save_persistent_data
+                .after(detect_reactive_data_change)
+                .run_if(resource_exists::<RPersist>)
+        );
+        app.add_systems(
+            PostUpdate,
+            record_reactive_history
+                .after(detect_reactive_data_change)
+                .run_if(resource_exists::<RHistory>)
+        );
 
         app.add_plugins(UiMaterialPlugin::<ProgressBarMaterial>::default());
         app.add_plugins(UiMaterialPlugin::<CircularMaterial>::default());
@@ -230,8 +432,17 @@ impl Plugin for FamiqPlugin {
 
         app.insert_resource(CosmicSwashCache(SwashCache::new()));
         app.insert_resource(RSubscriber::default());
+        app.init_resource::<RWatchers>();
+        if let Some(max_entries) = self.history_size {
+            app.insert_resource(RHistory::new(max_entries));
+        }
+        if let Some(path) = self.persist_path.as_ref() {
+            app.insert_resource(RPersist::load(path));
+        }
         app.insert_resource(CanBeScrolled { entity: None });
         app.insert_resource(FaDialogState::default());
+        app.insert_resource(FaMenuState::default());
+        app.init_resource::<Notifications>();
         app.insert_resource(CursorIcons::default());
 
         app.init_resource::<JsonStyleAssetState>();
@@ -239,21 +450,45 @@ impl Plugin for FamiqPlugin {
         app.init_asset_loader::<JsonStyleAssetLoader>();
 
         app.add_event::<event_writer::FaMouseEvent>();
+        app.add_event::<event_writer::FaValueChangeEvent>();
         app.add_event::<RequestRedrawBuffer>();
         app.add_event::<UpdateReactiveSubscriberEvent>();
+        app.add_event::<ReactiveDataChanged>();
+        app.add_event::<FamiqErrorEvent>();
+        app.add_event::<event_writer::FaTreeEvent>();
+        app.add_event::<event_writer::FaMenuEvent>();
+
+        // persisted data is loaded before events exist, report its failure now
+        if let Some(e) = app.world().get_resource::<RPersist>().and_then(|p| p.last_error.clone()) {
+            app.world_mut().send_event(FamiqErrorEvent(FamiqError::Persist(e)));
+        }
 
         external_styles_file_systems(app);
         fa_selection_systems(app);
         fa_scroll_systems(app);
         fa_text_input_systems(app);
+        fa_textarea_systems(app);
         fa_fps_text_systems(app);
         fa_circular_systems(app);
         fa_progress_bar_systems(app);
+        fa_slider_systems(app);
+        fa_radio_systems(app);
+        fa_switch_systems(app);
+        fa_tabs_systems(app);
+        fa_table_systems(app);
+        fa_tree_systems(app);
+        fa_menu_systems(app);
+        fa_notification_systems(app);
+        fa_for_each_systems(app);
+        fa_conditional_systems(app);
     }
 }
 
 /// Detect when a widget with id is created
-fn detect_new_widget_with_id(widget_q: Query<&WidgetId, Added<MainWidget>>) {
+fn detect_new_widget_with_id(
+    widget_q: Query<&WidgetId, Added<MainWidget>>,
+    mut errors: EventWriter<FamiqErrorEvent>
+) {
     let mut ids: Vec<&str> = Vec::new();
 
     for id in widget_q.iter() {
@@ -261,7 +496,7 @@ fn detect_new_widget_with_id(widget_q: Query<&WidgetId, Added<MainWidget>>) {
             ids.push(&id.0);
         }
         else {
-            panic!("\n[FamiqError]: ID {:?} is duplicated\n", id.0);
+            errors.write(FamiqErrorEvent(FamiqError::DuplicatedId(id.0.clone())));
         }
     }
 }
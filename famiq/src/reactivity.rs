@@ -1,31 +1,182 @@
 use bevy::prelude::*;
-use bevy::platform::collections::HashMap;
+use bevy::platform::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize, Serializer};
+use std::sync::Arc;
 
+use crate::errors::{ComputedKeyErr, FamiqError};
 use crate::resources::*;
+use crate::utils::{get_color, get_reactive_key, replace_reactive_keys};
+use crate::widgets::style_parse::{parse_val, parse_display};
 use crate::widgets::style::*;
 use crate::widgets::*;
 
+pub mod watch;
+pub mod expression;
+pub mod history;
+pub mod persist;
+pub mod bind;
+pub mod scope;
+pub mod convert;
+pub use watch::*;
+pub use expression::*;
+pub use history::*;
+pub use persist::*;
+pub use bind::*;
+pub use scope::*;
+pub use convert::*;
+
 pub type Subscriber = HashMap<Entity, WidgetBuilder>;
 
+/// Component field driven by a reactive template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingTarget {
+    /// `Text` value.
+    Text,
+    /// `Node::width`.
+    Width,
+    /// `Node::height`.
+    Height,
+    /// `Node::display`.
+    Display,
+    /// `WidgetClasses`, external styles are applied again.
+    Class,
+    /// `BackgroundColor` & `BorderColor`, or `TextColor` of text widgets.
+    Color
+}
+
+/// Reactive template bound to a single component field.
+///
+/// Unlike subscribers, bindings don't rebuild the widget, only the target field
+/// gets patched when any key in the template changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactiveBinding {
+    pub target: BindingTarget,
+    pub template: String,
+    /// Entity owning the field, widget's main entity if None.
+    pub entity: Option<Entity>
+}
+
+impl ReactiveBinding {
+    pub fn new(target: BindingTarget, template: &str, entity: Option<Entity>) -> Self {
+        Self {
+            target,
+            template: template.to_string(),
+            entity
+        }
+    }
+}
+
+/// (Entity, target field) - template
+pub type Bindings = HashMap<(Entity, BindingTarget), String>;
+
 /// Reactive subscribers
 #[derive(Resource, Default, Debug)]
 pub struct RSubscriber {
-    pub data: HashMap<String, Subscriber> // String is Reactivy data key
+    pub data: HashMap<String, Subscriber>, // String is Reactivy data key
+    pub bindings: HashMap<String, Bindings>
+}
+
+impl RSubscriber {
+    /// Remove subscriptions and bindings of the given entities, eg. after despawning them.
+    pub fn remove_entities(&mut self, entities: &[Entity]) {
+        for subscribers in self.data.values_mut() {
+            subscribers.retain(|e, _| !entities.contains(e));
+        }
+        for bindings in self.bindings.values_mut() {
+            bindings.retain(|(e, _), _| !entities.contains(e));
+        }
+        self.data.retain(|_, subscribers| !subscribers.is_empty());
+        self.bindings.retain(|_, bindings| !bindings.is_empty());
+    }
 }
 
 /// Reactive data type
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RVal {
     #[default]
     None,
     Num(i32),
+    Num64(i64),
+    UNum(u64),
     FNum(f32),
+    FNum64(f64),
     Str(String),
     List(Vec<String>),
-    Bool(bool)
+    Bool(bool),
+    /// Nested values, accessible with dotted path. Eg, `$[player.stats.hp]`.
+    Map(HashMap<String, RVal>),
+    /// List of any values, accessible with index path. Eg, `$[items.0.name]`.
+    Array(Vec<RVal>)
+}
+
+/// `try_*` accessor, returns `FamiqError::TypeMismatch` instead of panicking.
+macro_rules! try_accessor {
+    ($name:ident, $variant:ident, $ret:ty, $v:ident => $value:expr) => {
+        #[doc = concat!("Get inner value of ", stringify!($variant), ", error if it's another variant.")]
+        pub fn $name(&self) -> Result<$ret, FamiqError> {
+            match self {
+                RVal::$variant($v) => Ok($value),
+                _ => Err(self.type_mismatch(stringify!($variant)))
+            }
+        }
+    };
+    (mut $name:ident, $variant:ident, $ret:ty) => {
+        #[doc = concat!("Get mutable inner value of ", stringify!($variant), ", error if it's another variant.")]
+        pub fn $name(&mut self) -> Result<$ret, FamiqError> {
+            match self {
+                RVal::$variant(v) => Ok(v),
+                _ => Err(self.type_mismatch(stringify!($variant)))
+            }
+        }
+    };
 }
 
 impl RVal {
+    /// Name of the variant, eg. "Num".
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            RVal::None => "None",
+            RVal::Num(_) => "Num",
+            RVal::Num64(_) => "Num64",
+            RVal::UNum(_) => "UNum",
+            RVal::FNum(_) => "FNum",
+            RVal::FNum64(_) => "FNum64",
+            RVal::Str(_) => "Str",
+            RVal::List(_) => "List",
+            RVal::Bool(_) => "Bool",
+            RVal::Map(_) => "Map",
+            RVal::Array(_) => "Array"
+        }
+    }
+
+    fn type_mismatch(&self, expected: &'static str) -> FamiqError {
+        FamiqError::TypeMismatch {
+            expected,
+            found: self.variant_name()
+        }
+    }
+
+    try_accessor!(try_as_num, Num, i32, v => *v);
+    try_accessor!(mut try_as_num_mut, Num, &mut i32);
+    try_accessor!(try_as_num64, Num64, i64, v => *v);
+    try_accessor!(mut try_as_num64_mut, Num64, &mut i64);
+    try_accessor!(try_as_unum, UNum, u64, v => *v);
+    try_accessor!(mut try_as_unum_mut, UNum, &mut u64);
+    try_accessor!(try_as_fnum, FNum, f32, v => *v);
+    try_accessor!(mut try_as_fnum_mut, FNum, &mut f32);
+    try_accessor!(try_as_fnum64, FNum64, f64, v => *v);
+    try_accessor!(mut try_as_fnum64_mut, FNum64, &mut f64);
+    try_accessor!(try_as_str, Str, &str, v => v.as_str());
+    try_accessor!(mut try_as_str_mut, Str, &mut String);
+    try_accessor!(try_as_vec, List, &Vec<String>, v => v);
+    try_accessor!(mut try_as_vec_mut, List, &mut Vec<String>);
+    try_accessor!(try_as_bool, Bool, bool, v => *v);
+    try_accessor!(mut try_as_bool_mut, Bool, &mut bool);
+    try_accessor!(try_as_map, Map, &HashMap<String, RVal>, v => v);
+    try_accessor!(mut try_as_map_mut, Map, &mut HashMap<String, RVal>);
+    try_accessor!(try_as_array, Array, &Vec<RVal>, v => v);
+    try_accessor!(mut try_as_array_mut, Array, &mut Vec<RVal>);
+
     /// Get inner value of Num as i32.
     pub fn as_num(&self) -> i32 {
         match self {
@@ -42,6 +193,38 @@ impl RVal {
         }
     }
 
+    /// Get inner value of Num64 as i64.
+    pub fn as_num64(&self) -> i64 {
+        match self {
+            RVal::Num64(v) => *v,
+            _ => panic!("\n[FamiqError]: calling as_num64() on none RVal::Num64\n")
+        }
+    }
+
+    /// Get inner value of Num64 as &mut i64.
+    pub fn as_num64_mut(&mut self) -> &mut i64 {
+        match self {
+            RVal::Num64(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_num64_mut() on none RVal::Num64\n")
+        }
+    }
+
+    /// Get inner value of UNum as u64.
+    pub fn as_unum(&self) -> u64 {
+        match self {
+            RVal::UNum(v) => *v,
+            _ => panic!("\n[FamiqError]: calling as_unum() on none RVal::UNum\n")
+        }
+    }
+
+    /// Get inner value of UNum as &mut u64.
+    pub fn as_unum_mut(&mut self) -> &mut u64 {
+        match self {
+            RVal::UNum(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_unum_mut() on none RVal::UNum\n")
+        }
+    }
+
     /// Get inner value of FNum as f32.
     pub fn as_fnum(&self) -> f32 {
         match self {
@@ -58,6 +241,22 @@ impl RVal {
         }
     }
 
+    /// Get inner value of FNum64 as f64.
+    pub fn as_fnum64(&self) -> f64 {
+        match self {
+            RVal::FNum64(v) => *v,
+            _ => panic!("\n[FamiqError]: calling as_fnum64() on none RVal::FNum64\n"),
+        }
+    }
+
+    /// Get inner value of FNum64 as &mut f64.
+    pub fn as_fnum64_mut(&mut self) -> &mut f64 {
+        match self {
+            RVal::FNum64(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_fnum64_mut() on none RVal::FNum64\n"),
+        }
+    }
+
     /// Get inner value of Str as &str.
     pub fn as_str(&self) -> &str {
         match self {
@@ -106,24 +305,349 @@ impl RVal {
         }
     }
 
+    /// Get inner value of Map as `&HashMap<String, RVal>`
+    pub fn as_map(&self) -> &HashMap<String, RVal> {
+        match self {
+            RVal::Map(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_map() on none RVal::Map\n")
+        }
+    }
+
+    /// Get inner value of Map as `&mut HashMap<String, RVal>`
+    pub fn as_map_mut(&mut self) -> &mut HashMap<String, RVal> {
+        match self {
+            RVal::Map(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_map_mut() on none RVal::Map\n")
+        }
+    }
+
+    /// Get inner value of Array as `&Vec<RVal>`
+    pub fn as_array(&self) -> &Vec<RVal> {
+        match self {
+            RVal::Array(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_array() on none RVal::Array\n")
+        }
+    }
+
+    /// Get inner value of Array as `&mut Vec<RVal>`
+    pub fn as_array_mut(&mut self) -> &mut Vec<RVal> {
+        match self {
+            RVal::Array(v) => v,
+            _ => panic!("\n[FamiqError]: calling as_array_mut() on none RVal::Array\n")
+        }
+    }
+
+    /// Whether the value counts as true when used as a condition.
+    /// None, false, zero and empty values are false.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            RVal::None => false,
+            RVal::Num(v) => *v != 0,
+            RVal::Num64(v) => *v != 0,
+            RVal::UNum(v) => *v != 0,
+            RVal::FNum(v) => *v != 0.0,
+            RVal::FNum64(v) => *v != 0.0,
+            RVal::Str(v) => !v.is_empty(),
+            RVal::List(v) => !v.is_empty(),
+            RVal::Bool(v) => *v,
+            RVal::Map(v) => !v.is_empty(),
+            RVal::Array(v) => !v.is_empty()
+        }
+    }
+
+    /// Get nested value by path relative to this value.
+    /// Eg, "stats.hp" on a Map or "0.name" on an Array.
+    pub fn get_path(&self, path: &str) -> Option<&RVal> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        let mut current = self;
+
+        for segment in path.split('.') {
+            current = match current {
+                RVal::Map(map) => map.get(segment)?,
+                RVal::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+                _ => return None
+            };
+        }
+        Some(current)
+    }
+
+    /// Get mutable nested value by path relative to this value.
+    pub fn get_path_mut(&mut self, path: &str) -> Option<&mut RVal> {
+        if path.is_empty() {
+            return Some(self);
+        }
+        let mut current = self;
+
+        for segment in path.split('.') {
+            current = match current {
+                RVal::Map(map) => map.get_mut(segment)?,
+                RVal::Array(items) => items.get_mut(segment.parse::<usize>().ok()?)?,
+                _ => return None
+            };
+        }
+        Some(current)
+    }
+
     /// convert value to string.
     pub fn to_string(&self) -> String {
         match self {
             RVal::None => "None".to_string(),
             RVal::Num(v) => v.to_string(),
+            RVal::Num64(v) => v.to_string(),
+            RVal::UNum(v) => v.to_string(),
             RVal::FNum(v) => v.to_string(),
+            RVal::FNum64(v) => v.to_string(),
             RVal::Str(v) => v.clone(),
             RVal::List(v) => format!("[{}]", v.join(", ")),
             RVal::Bool(v) => v.to_string(),
+            RVal::Map(v) => {
+                // sort keys so the output is stable between rebuilds
+                let mut keys: Vec<&String> = v.keys().collect();
+                keys.sort();
+                let items: Vec<String> = keys
+                    .iter()
+                    .map(|k| format!("{}: {}", k, v[*k].to_string()))
+                    .collect();
+                format!("{{{}}}", items.join(", "))
+            }
+            RVal::Array(v) => {
+                let items: Vec<String> = v.iter().map(|item| item.to_string()).collect();
+                format!("[{}]", items.join(", "))
+            }
         }
     }
 }
 
-/// Reactive data
-#[derive(Resource, Debug, Default)]
+/// Get value from reactive data by key or path.
+/// Eg, "player", "player.stats.hp" or "items.0.name".
+pub fn get_reactive_value<'a>(data: &'a HashMap<String, RVal>, path: &str) -> Option<&'a RVal> {
+    if let Some(value) = data.get(path) {
+        return Some(value);
+    }
+    let (root, rest) = path.split_once('.')?;
+    data.get(root)?.get_path(rest)
+}
+
+/// Get mutable value from reactive data by key or path.
+pub fn get_reactive_value_mut<'a>(data: &'a mut HashMap<String, RVal>, path: &str) -> Option<&'a mut RVal> {
+    if data.contains_key(path) {
+        return data.get_mut(path);
+    }
+    let (root, rest) = path.split_once('.')?;
+    data.get_mut(root)?.get_path_mut(rest)
+}
+
+/// Check if `path` is a sub path of `parent`. Eg, "player.stats" is a sub path of "player".
+pub(crate) fn is_sub_path(path: &str, parent: &str) -> bool {
+    path.len() > parent.len()
+        && path.starts_with(parent)
+        && path.as_bytes()[parent.len()] == b'.'
+}
+
+/// Check if `key` is internal, eg. data of `for_each` rows. Internal keys are
+/// not sent as `ReactiveDataChanged`, recorded, persisted nor serialized.
+pub(crate) fn is_internal_key(key: &str) -> bool {
+    key.starts_with("__fa_")
+}
+
+/// Serialize reactive data without internal keys.
+fn serialize_public_data<S: Serializer>(data: &HashMap<String, RVal>, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(data.iter().filter(|(key, _)| !is_internal_key(key)))
+}
+
+/// Check if a change on `changed_key` affects widgets subscribed to `subscribed_key`.
+///
+/// Changing "player" affects "player.stats.hp" and changing "player.stats.hp"
+/// affects "player", but changing "player.name" doesn't affect "player.stats.hp".
+pub(crate) fn is_related_path(changed_key: &str, subscribed_key: &str) -> bool {
+    changed_key == subscribed_key
+        || is_sub_path(subscribed_key, changed_key)
+        || is_sub_path(changed_key, subscribed_key)
+}
+
+/// Closure computing value of a computed key from reactive data.
+pub type ComputeFn = Arc<dyn Fn(&HashMap<String, RVal>) -> RVal + Send + Sync>;
+
+/// Key whose value is derived from other keys.
+#[derive(Clone)]
+pub struct ComputedKey {
+    pub deps: Vec<String>,
+    pub compute: ComputeFn
+}
+
+impl std::fmt::Debug for ComputedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputedKey").field("deps", &self.deps).finish()
+    }
+}
+
+/// Reactive data. Only `data` is (de)serialized, computed keys can't be.
+#[derive(Resource, Debug, Default, Serialize, Deserialize)]
 pub struct RData {
+    #[serde(serialize_with = "serialize_public_data")]
     pub data: HashMap<String, RVal>,
+    #[serde(skip)]
     pub changed_keys: Vec<String>,
+    #[serde(skip)]
+    pub computed: HashMap<String, ComputedKey>,
+    /// values of changed keys before their first change in this frame
+    #[serde(skip)]
+    pub(crate) old_values: HashMap<String, RVal>,
+}
+
+impl RData {
+    /// Mark key or path as changed. Must be called before the value is mutated,
+    /// so `ReactiveDataChanged` event can carry the old value.
+    pub fn mark_changed(&mut self, key: &str) {
+        if self.changed_keys.iter().any(|k| k == key) {
+            return;
+        }
+        let old = get_reactive_value(&self.data, key).cloned().unwrap_or_default();
+        self.old_values.insert(key.to_string(), old);
+        self.changed_keys.push(key.to_string());
+    }
+
+    /// Write a key or path, marked as changed first. Returns error if it doesn't exist.
+    pub fn try_mutate(&mut self, key: &str, new_val: impl IntoRVal) -> Result<(), FamiqError> {
+        if get_reactive_value(&self.data, key).is_none() {
+            return Err(FamiqError::KeyNotFound(key.to_string()));
+        }
+        self.mark_changed(key);
+
+        if let Some(old_val) = get_reactive_value_mut(&mut self.data, key) {
+            *old_val = new_val.into_rval();
+        }
+        Ok(())
+    }
+
+    /// Register a computed key. Its value is computed right away and
+    /// recomputed whenever any of `deps` (or their parents/sub paths) changes.
+    ///
+    /// Returns error if the key would depend on itself, directly or through
+    /// other computed keys.
+    pub fn insert_computed(
+        &mut self,
+        key: &str,
+        deps: &[&str],
+        compute: impl Fn(&HashMap<String, RVal>) -> RVal + Send + Sync + 'static
+    ) -> Result<(), ComputedKeyErr> {
+        let computed_key = ComputedKey {
+            deps: deps.iter().map(|d| d.to_string()).collect(),
+            compute: Arc::new(compute)
+        };
+        let old = self.computed.insert(key.to_string(), computed_key);
+
+        if let Some(cycle) = self.find_computed_cycle(key) {
+            match old {
+                Some(old) => self.computed.insert(key.to_string(), old),
+                None => self.computed.remove(key)
+            };
+            return Err(ComputedKeyErr::DependencyCycle(cycle.join(" -> ")));
+        }
+        let value = (self.computed[key].compute)(&self.data);
+        self.data.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    /// Computed keys that `key` directly depends on.
+    fn computed_deps_of(&self, key: &str) -> Vec<String> {
+        let Some(computed_key) = self.computed.get(key) else {
+            return Vec::new();
+        };
+        self.computed
+            .keys()
+            .filter(|k| computed_key.deps.iter().any(|d| is_related_path(d, k)))
+            .cloned()
+            .collect()
+    }
+
+    /// Find a dependency path from `key` back to itself, if any.
+    fn find_computed_cycle(&self, key: &str) -> Option<Vec<String>> {
+        let mut stack: Vec<Vec<String>> = vec![vec![key.to_string()]];
+        let mut visited: HashSet<String> = HashSet::new();
+
+        while let Some(path) = stack.pop() {
+            let current = path.last().unwrap();
+
+            for dep in self.computed_deps_of(current) {
+                if dep == key {
+                    let mut cycle = path.clone();
+                    cycle.push(dep);
+                    return Some(cycle);
+                }
+                if visited.insert(dep.clone()) {
+                    let mut next = path.clone();
+                    next.push(dep);
+                    stack.push(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Recompute computed keys affected by `changed_keys`, dependencies first.
+    /// Computed keys whose value changed are pushed to `changed_keys`.
+    pub(crate) fn recompute_computed_keys(&mut self) {
+        if self.computed.is_empty() || self.changed_keys.is_empty() {
+            return;
+        }
+
+        // every computed key depending on changed keys, directly or not
+        let mut affected: HashSet<String> = HashSet::new();
+        let mut to_check = self.changed_keys.clone();
+
+        while let Some(changed) = to_check.pop() {
+            for (key, computed_key) in self.computed.iter() {
+                if affected.contains(key) {
+                    continue;
+                }
+                if computed_key.deps.iter().any(|d| is_related_path(&changed, d)) {
+                    affected.insert(key.clone());
+                    to_check.push(key.clone());
+                }
+            }
+        }
+
+        // dependencies first
+        let mut ordered: Vec<String> = Vec::with_capacity(affected.len());
+        let mut visiting: HashSet<String> = HashSet::new();
+        let mut keys: Vec<&String> = affected.iter().collect();
+        keys.sort();
+
+        for key in keys {
+            self.sort_computed_key(key, &affected, &mut visiting, &mut ordered);
+        }
+
+        for key in ordered {
+            let value = (self.computed[&key].compute)(&self.data);
+
+            if self.data.get(&key) != Some(&value) {
+                self.mark_changed(&key);
+                self.data.insert(key, value);
+            }
+        }
+    }
+
+    fn sort_computed_key(
+        &self,
+        key: &String,
+        affected: &HashSet<String>,
+        visiting: &mut HashSet<String>,
+        ordered: &mut Vec<String>
+    ) {
+        if ordered.contains(key) || !visiting.insert(key.clone()) {
+            // already sorted, or a cycle which insert_computed never allows
+            return;
+        }
+        for dep in self.computed_deps_of(key) {
+            if affected.contains(&dep) {
+                self.sort_computed_key(&dep, affected, visiting, ordered);
+            }
+        }
+        ordered.push(key.clone());
+    }
 }
 
 #[derive(Event, Debug)]
@@ -162,75 +686,276 @@ pub fn on_update_subscriber_event(
                 reactive_subscriber.data.insert(key.to_string(), subscribers);
             }
         });
+
+        for binding in e.builder.bindings() {
+            let target = (binding.entity.unwrap_or(e.entity), binding.target);
+
+            for key in get_reactive_key(&binding.template) {
+                reactive_subscriber.bindings
+                    .entry(key)
+                    .or_default()
+                    .insert(target, binding.template.clone());
+            }
+        }
     }
 }
 
 pub(crate) fn detect_reactive_data_change(
     mut commands: Commands,
     mut fa_query: FaQuery,
-    mut famiq_res: ResMut<FamiqResource>,
-    styles: Res<StylesKeyValueResource>
+    mut change_events: EventWriter<ReactiveDataChanged>,
+    mut famiq_res: ResMut<FamiqResource>
 ) {
     if fa_query.reactive_data.is_changed() && !fa_query.reactive_data.is_added() {
         FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        fa_query.reactive_data.recompute_computed_keys();
 
         // Entity - (index withtin its parent, builder)
         let mut to_remove_subscribers: HashMap<Entity, WidgetBuilder> = HashMap::new();
+        let mut to_patch: Bindings = HashMap::new();
 
         for key in fa_query.reactive_data.changed_keys.iter() {
-            let subscribers = fa_query.reactive_subscriber.data.get_mut(key);
-            if subscribers.is_none() {
-                continue;
+            // subscribers of the key itself, its parents and its sub paths
+            for (sub_key, subscribers) in fa_query.reactive_subscriber.data.iter_mut() {
+                if !is_related_path(key, sub_key) {
+                    continue;
+                }
+                for (entity, widget_builder) in subscribers.iter() {
+                    if to_remove_subscribers.contains_key(entity) {
+                        continue;
+                    }
+                    to_remove_subscribers.insert(
+                        *entity,
+                        widget_builder.to_owned()
+                    );
+                }
+            }
+            for (sub_key, bindings) in fa_query.reactive_subscriber.bindings.iter() {
+                if is_related_path(key, sub_key) {
+                    to_patch.extend(bindings.iter().map(|(k, v)| (*k, v.clone())));
+                }
             }
-            let subscribers = subscribers.unwrap();
+        }
+        // a widget may subscribe to multiple keys, remove it from all of them.
+        // it will subscribe again on rebuild.
+        if !to_remove_subscribers.is_empty() {
+            for subscribers in fa_query.reactive_subscriber.data.values_mut() {
+                subscribers.retain(|k, _| !to_remove_subscribers.contains_key(k));
+            }
+        }
 
-            for (entity, widget_builder) in subscribers.iter() {
-                if to_remove_subscribers.contains_key(entity) {
+        if !to_remove_subscribers.is_empty() || !to_patch.is_empty() {
+            commands.queue(move |world: &mut World| {
+                world.resource_scope(|world, r_data: Mut<RData>| {
+                    world.resource_scope(|world, styles: Mut<StylesKeyValueResource>| {
+                        let mut to_restyle: Vec<Entity> = Vec::new();
+
+                        to_remove_subscribers.into_iter().for_each(|(entity, widget_builder)| {
+                            // despawned by list reconciliation or user code
+                            if world.get_entity(entity).is_err() {
+                                return;
+                            }
+                            widget_builder.rebuild(&r_data.data, entity, world);
+                            to_restyle.push(entity);
+                        });
+                        to_restyle.extend(patch_bindings(world, &r_data.data, to_patch));
+
+                        let to_restyle = get_restyle_entities(world, &to_restyle);
+                        reset_external_style(world, &styles.values, &to_restyle);
+                        reset_external_text_style_of(world, &styles.values, &to_restyle);
+                    });
+                });
+            });
+        }
+
+        let reactive_data = &mut *fa_query.reactive_data;
+        for key in reactive_data.changed_keys.drain(..) {
+            let old = reactive_data.old_values.remove(&key).unwrap_or_default();
+            let new = get_reactive_value(&reactive_data.data, &key).cloned().unwrap_or_default();
+
+            if old != new && !is_internal_key(&key) {
+                change_events.write(ReactiveDataChanged { key, old, new });
+            }
+        }
+        reactive_data.old_values.clear();
+    }
+}
+
+/// Patch bound fields with new values. Returns entities whose node was patched,
+/// they need external styles to be applied again.
+fn patch_bindings(
+    world: &mut World,
+    r_data: &HashMap<String, RVal>,
+    to_patch: Bindings
+) -> Vec<Entity> {
+    let mut patched_nodes: Vec<Entity> = Vec::new();
+    let mut despawned: Vec<(Entity, BindingTarget)> = Vec::new();
+
+    for ((entity, target), template) in to_patch.into_iter() {
+        if world.get_entity(entity).is_err() {
+            despawned.push((entity, target));
+            continue;
+        }
+        let keys = get_reactive_key(&template);
+        let value = replace_reactive_keys(&template, &keys, r_data);
+
+        match target {
+            BindingTarget::Text => {
+                let Some(mut text) = world.get_mut::<Text>(entity) else {
                     continue;
+                };
+                if text.0 != value {
+                    text.0 = value;
                 }
-                to_remove_subscribers.insert(
-                    *entity,
-                    widget_builder.to_owned()
-                );
             }
-            subscribers.retain(|k, _| !to_remove_subscribers.contains_key(k));
-        }
-
-        let r_data = fa_query.reactive_data.data.clone();
-        let style_res = styles.values.clone();
-
-        commands.queue(move |world: &mut World| {
-            to_remove_subscribers.into_iter().for_each(|(entity, widget_builder)| {
-                match widget_builder.builder {
-                    BuilderType::Button(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Text(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Checkbox(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Circular(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::ProgressBar(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Fps(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Image(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Scroll(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Selection(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Container(mut builder) => builder.rebuild(&r_data, entity, world),
-                    BuilderType::Dialog(mut builder) => builder.rebuild(&r_data, entity, world),
+            BindingTarget::Width => {
+                if let Some(v) = parse_val(&value) {
+                    patch_node(world, entity, |node| node.width = v);
+                    patched_nodes.push(entity);
                 }
-            });
-            reset_external_style(world, &style_res);
-            reset_external_text_style(world, &style_res);
-        });
-        fa_query.reactive_data.changed_keys.clear();
+            }
+            BindingTarget::Height => {
+                if let Some(v) = parse_val(&value) {
+                    patch_node(world, entity, |node| node.height = v);
+                    patched_nodes.push(entity);
+                }
+            }
+            BindingTarget::Display => {
+                if let Some(v) = parse_display(&value) {
+                    patch_node(world, entity, |node| node.display = v);
+                    patched_nodes.push(entity);
+                }
+            }
+            BindingTarget::Class => {
+                if world.get::<WidgetClasses>(entity).is_some_and(|classes| classes.0 == value) {
+                    continue;
+                }
+                reset_default_style(world, entity);
+                world.entity_mut(entity).insert(WidgetClasses(value));
+                patched_nodes.push(entity);
+            }
+            BindingTarget::Color => {
+                patch_color(world, entity, get_color(&WidgetColor::Custom(value)));
+                patched_nodes.push(entity);
+            }
+        }
+    }
+
+    if !despawned.is_empty() {
+        let mut subscriber = world.resource_mut::<RSubscriber>();
+        for bindings in subscriber.bindings.values_mut() {
+            bindings.retain(|k, _| !despawned.contains(k));
+        }
+        subscriber.bindings.retain(|_, bindings| !bindings.is_empty());
+    }
+    patched_nodes
+}
+
+/// Update both `Node` and its default, so external styles fall back to the new value.
+fn patch_node(world: &mut World, entity: Entity, set: impl Fn(&mut Node)) {
+    if let Some(mut node) = world.get_mut::<Node>(entity) {
+        set(&mut node);
+    }
+    if let Some(mut default_config) = world.get_mut::<DefaultWidgetConfig>(entity) {
+        set(&mut default_config.node);
     }
 }
 
+/// Update color of the widget and its default, text widgets are colored by `TextColor`.
+fn patch_color(world: &mut World, entity: Entity, color: Color) {
+    if world.get::<Text>(entity).is_some() {
+        if let Some(mut text_color) = world.get_mut::<TextColor>(entity) {
+            text_color.0 = color;
+        }
+        if let Some(mut default_text) = world.get_mut::<DefaultTextConfig>(entity) {
+            default_text.text_color = TextColor(color);
+        }
+        return;
+    }
+    if let Some(mut bg_color) = world.get_mut::<BackgroundColor>(entity) {
+        bg_color.0 = color;
+    }
+    if let Some(mut border_color) = world.get_mut::<BorderColor>(entity) {
+        border_color.0 = color;
+    }
+    if let Some(mut default_config) = world.get_mut::<DefaultWidgetConfig>(entity) {
+        default_config.background_color = BackgroundColor(color);
+        default_config.border_color = BorderColor(color);
+    }
+}
+
+/// Put back default styles, so styles of old classes don't stay once classes change.
+fn reset_default_style(world: &mut World, entity: Entity) {
+    let default_style = WidgetStyle::default();
+    let mut style_q = world.query::<StyleQuery>();
+
+    if let Ok(mut style) = style_q.get_mut(world, entity) {
+        apply_styles_from_external_json(
+            &mut style.background_color,
+            &mut style.border_color,
+            &mut style.border_radius,
+            &mut style.visibility,
+            &mut style.z_index,
+            &mut style.node,
+            &mut style.box_shadow,
+            &default_style,
+            style.default_style
+        );
+    }
+    let mut text_style_q = world.query::<(
+        Option<&mut TextFont>,
+        Option<&mut TextColor>,
+        Option<&DefaultTextConfig>,
+        Option<&DefaultTextSpanConfig>,
+    )>();
+
+    if let Ok((text_font, text_color, default_text, default_text_span)) = text_style_q.get_mut(world, entity) {
+        apply_text_styles_from_external_json(
+            &default_style,
+            default_text,
+            default_text_span,
+            text_font,
+            text_color
+        );
+    }
+}
+
+/// Given entities and their internal children (children that are not other widgets).
+fn get_restyle_entities(world: &World, entities: &[Entity]) -> Vec<Entity> {
+    let mut result: Vec<Entity> = Vec::with_capacity(entities.len());
+    let mut stack: Vec<Entity> = entities.to_vec();
+
+    while let Some(entity) = stack.pop() {
+        if result.contains(&entity) {
+            continue;
+        }
+        result.push(entity);
+
+        if let Some(children) = world.get::<Children>(entity) {
+            for child in children.iter() {
+                if world.get::<MainWidget>(child).is_none() {
+                    stack.push(child);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Apply external styles again to given entities.
 pub(crate) fn reset_external_style(
     world: &mut World,
     style_res: &HashMap<String, WidgetStyle>,
+    entities: &[Entity]
 ) {
     let mut style_q = world.query::<StyleQuery>();
 
-    style_q.par_iter_mut(world).for_each(|mut style| {
+    for entity in entities.iter() {
+        let Ok(mut style) = style_q.get_mut(world, *entity) else {
+            continue;
+        };
         if style.id.is_none() && style.class.is_none() {
-            return;
+            continue;
         }
         let mut need_reset_style = false;
         let mut empty_style = WidgetStyle::default();
@@ -268,12 +993,23 @@ pub(crate) fn reset_external_style(
                 &mut style.default_style
             );
         }
-    });
+    }
+}
+
+/// Apply external text styles again to all widgets with id or class.
+pub fn reset_external_text_style(world: &mut World, style_res: &HashMap<String, WidgetStyle>) {
+    let entities: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<WidgetId>, With<WidgetClasses>)>>()
+        .iter(world)
+        .collect();
+    reset_external_text_style_of(world, style_res, &entities);
 }
 
-pub fn reset_external_text_style(
+/// Apply external text styles again to given entities.
+pub(crate) fn reset_external_text_style_of(
     world: &mut World,
-    style_res: &HashMap<String, WidgetStyle>
+    style_res: &HashMap<String, WidgetStyle>,
+    entities: &[Entity]
 ) {
     let mut text_style_q = world.query::<(
         Option<&mut TextFont>,
@@ -284,7 +1020,10 @@ pub fn reset_external_text_style(
         Option<&DefaultTextSpanConfig>,
     )>();
 
-    text_style_q.par_iter_mut(world).for_each(|(text_font, text_color, id, class, default_text, default_text_span)| {
+    for entity in entities.iter() {
+        let Ok((text_font, text_color, id, class, default_text, default_text_span)) = text_style_q.get_mut(world, *entity) else {
+            continue;
+        };
         let mut need_reset_style = false;
         let mut empty_style = WidgetStyle::default();
 
@@ -317,5 +1056,374 @@ pub fn reset_external_text_style(
                 text_color
             );
         }
-    });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::FamiqPlugin;
+    use crate::utils::create_test_app;
+    use crate::widgets::container::{IsFamiqContainer, ContainerBuilder};
+    use crate::widgets::button::{IsFamiqButtonText, ButtonBuilder};
+    use crate::widgets::text::{IsFamiqText, TextBuilder};
+    use crate::{container, button, text};
+    use bevy::color::palettes::css::{BLUE, RED};
+    use bevy::ecs::system::RunSystemOnce;
+    use crate::errors::FamiqErrorEvent;
+
+    #[derive(Resource, Default)]
+    struct WatchedChanges(Vec<ReactiveDataChanged>);
+
+    fn setup_test_count(mut fa_query: FaQuery) {
+        fa_query.insert_num("count", 0);
+        fa_query.insert_str("name", "famiq");
+    }
+
+    fn run_increase_count(mut fa_query: FaQuery) {
+        if let Some(count) = fa_query.get_data_mut("count") {
+            *count.as_num_mut() += 1;
+        }
+    }
+
+    fn setup_test_bindings(mut fa_query: FaQuery, mut famiq_res: ResMut<FamiqResource>) {
+        fa_query.insert_str("width", "100px");
+        fa_query.insert_str("label", "Save");
+
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        container!(id: "#test-container", width: "$[width]");
+        button!(text: "$[label]", id: "#test-btn");
+    }
+
+    fn run_mutate_bindings(mut fa_query: FaQuery) {
+        fa_query.mutate_str("width", "200px");
+        fa_query.mutate_str("label", "Saved");
+    }
+
+    fn setup_test_class_color_bindings(
+        mut fa_query: FaQuery,
+        mut famiq_res: ResMut<FamiqResource>,
+        mut styles: ResMut<StylesKeyValueResource>
+    ) {
+        styles.values.insert(".highlight".to_string(), WidgetStyle {
+            padding: Some("10px 10px 10px 10px".to_string()),
+            ..default()
+        });
+        fa_query.insert_str("theme", "plain");
+        fa_query.insert_str("bg", "red");
+        fa_query.insert_str("fg", "red");
+
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        container!(id: "#test-container", class: "$[theme]", color: "$[bg]");
+        text!(text: "Status", color: "$[fg]");
+    }
+
+    fn on_count_changed(In(change): In<ReactiveDataChanged>, mut changes: ResMut<WatchedChanges>) {
+        changes.0.push(change);
+    }
+
+    fn player_data() -> HashMap<String, RVal> {
+        let mut stats = HashMap::new();
+        stats.insert("hp".to_string(), RVal::Num(80));
+
+        let mut player = HashMap::new();
+        player.insert("name".to_string(), RVal::Str("Kim".to_string()));
+        player.insert("stats".to_string(), RVal::Map(stats));
+
+        let mut item = HashMap::new();
+        item.insert("name".to_string(), RVal::Str("Sword".to_string()));
+
+        let mut data = HashMap::new();
+        data.insert("player".to_string(), RVal::Map(player));
+        data.insert("items".to_string(), RVal::Array(vec![RVal::Map(item)]));
+        data
+    }
+
+    #[test]
+    fn test_get_reactive_value_with_path() {
+        let data = player_data();
+
+        assert_eq!(get_reactive_value(&data, "player.stats.hp"), Some(&RVal::Num(80)));
+        assert_eq!(get_reactive_value(&data, "items.0.name"), Some(&RVal::Str("Sword".to_string())));
+        assert_eq!(get_reactive_value(&data, "items.1.name"), None);
+        assert_eq!(get_reactive_value(&data, "player.stats.mp"), None);
+    }
+
+    #[test]
+    fn test_get_reactive_value_mut_with_path() {
+        let mut data = player_data();
+
+        *get_reactive_value_mut(&mut data, "player.stats.hp").unwrap() = RVal::Num(50);
+        assert_eq!(get_reactive_value(&data, "player.stats.hp"), Some(&RVal::Num(50)));
+    }
+
+    #[test]
+    fn test_is_related_path() {
+        assert!(is_related_path("player", "player.stats.hp"));
+        assert!(is_related_path("player.stats.hp", "player"));
+        assert!(is_related_path("player.stats.hp", "player.stats.hp"));
+        assert!(!is_related_path("player.name", "player.stats.hp"));
+        assert!(!is_related_path("player", "player_two"));
+    }
+
+    #[test]
+    fn test_to_string_for_structured_values() {
+        let data = player_data();
+
+        assert_eq!(data["player"].to_string(), "{name: Kim, stats: {hp: 80}}");
+        assert_eq!(RVal::Array(vec![RVal::Num(1), RVal::FNum64(2.5)]).to_string(), "[1, 2.5]");
+    }
+
+    #[test]
+    fn test_recompute_computed_keys() {
+        let mut r_data = RData::default();
+        r_data.data.insert("price".to_string(), RVal::Num(5));
+        r_data.data.insert("qty".to_string(), RVal::Num(2));
+
+        r_data.insert_computed("total", &["price", "qty"], |data| {
+            RVal::Num(data["price"].as_num() * data["qty"].as_num())
+        }).unwrap();
+        r_data.insert_computed("label", &["total"], |data| {
+            RVal::Str(format!("Total: {}", data["total"].as_num()))
+        }).unwrap();
+        assert_eq!(r_data.data["total"], RVal::Num(10));
+        assert_eq!(r_data.data["label"], RVal::Str("Total: 10".to_string()));
+
+        r_data.data.insert("qty".to_string(), RVal::Num(3));
+        r_data.changed_keys.push("qty".to_string());
+        r_data.recompute_computed_keys();
+
+        assert_eq!(r_data.data["total"], RVal::Num(15));
+        assert_eq!(r_data.data["label"], RVal::Str("Total: 15".to_string()));
+        assert_eq!(r_data.changed_keys, vec!["qty", "total", "label"]);
+    }
+
+    #[test]
+    fn test_computed_key_dependency_cycle() {
+        let mut r_data = RData::default();
+        r_data.insert_computed("a", &["b"], |_| RVal::Num(1)).unwrap();
+        r_data.insert_computed("b", &["c"], |_| RVal::Num(2)).unwrap();
+
+        let result = r_data.insert_computed("c", &["a"], |_| RVal::Num(3));
+        assert_eq!(result, Err(ComputedKeyErr::DependencyCycle("c -> a -> b -> c".to_string())));
+        assert!(!r_data.computed.contains_key("c"));
+    }
+
+    #[test]
+    fn test_watch_reactive_data() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.init_resource::<WatchedChanges>();
+        app.watch("count", on_count_changed);
+        app.add_systems(Startup, setup_test_count);
+        app.update();
+
+        app.add_systems(Update, run_increase_count);
+        app.update();
+        app.update();
+
+        let changes = &app.world().resource::<WatchedChanges>().0;
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0], ReactiveDataChanged {
+            key: "count".to_string(),
+            old: RVal::Num(0),
+            new: RVal::Num(1)
+        });
+        assert_eq!(changes[1].new, RVal::Num(2));
+    }
+
+    fn setup_test_duplicated_id(mut fa_query: FaQuery, mut famiq_res: ResMut<FamiqResource>) {
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        button!(text: "One", id: "#same");
+        button!(text: "Two", id: "#same");
+    }
+
+    #[test]
+    fn test_try_accessors() {
+        let mut value = RVal::Num(3);
+
+        assert_eq!(value.try_as_num(), Ok(3));
+        *value.try_as_num_mut().unwrap() += 1;
+        assert_eq!(value.as_num(), 4);
+        assert_eq!(value.try_as_str(), Err(FamiqError::TypeMismatch { expected: "Str", found: "Num" }));
+    }
+
+    #[test]
+    fn test_try_mutate_missing_key() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_count);
+        app.update();
+
+        app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+            assert_eq!(fa_query.try_mutate("counter", RVal::Num(1)), Err(FamiqError::KeyNotFound("counter".to_string())));
+            assert_eq!(fa_query.try_mutate("count", RVal::Num(1)), Ok(()));
+            assert!(fa_query.try_get_data("counter").is_err());
+            assert_eq!(fa_query.try_persist("count"), Err(FamiqError::PersistenceDisabled));
+        }).unwrap();
+    }
+
+    #[test]
+    fn test_generic_get_and_insert() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.update();
+
+        app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+            fa_query.insert_data("score", 10_u32);
+            fa_query.insert_data("names", vec!["Kim", "Hong"]);
+            fa_query.mutate_data("score", 20_u32);
+
+            assert_eq!(fa_query.get::<u32>("score"), Some(20));
+            assert_eq!(fa_query.get::<Vec<String>>("names").unwrap().len(), 2);
+            assert_eq!(fa_query.get::<bool>("score"), None);
+            assert_eq!(fa_query.try_get::<u32>("missing"), Err(FamiqError::KeyNotFound("missing".to_string())));
+        }).unwrap();
+    }
+
+    #[test]
+    fn test_duplicated_id_sends_error_event() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_duplicated_id);
+        app.update();
+
+        let events = app.world().resource::<Events<FamiqErrorEvent>>();
+        let errors: Vec<&FamiqErrorEvent> = events.iter_current_update_events().collect();
+        assert_eq!(errors, vec![&FamiqErrorEvent(FamiqError::DuplicatedId("#same".to_string()))]);
+    }
+
+    #[test]
+    fn test_undo_redo_reactive_data() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new().with_history(10));
+        app.add_systems(Startup, setup_test_bindings);
+        app.update();
+
+        app.world_mut().run_system_once(run_mutate_bindings).unwrap();
+        app.update();
+
+        app.world_mut().run_system_once(|mut history: FaHistory| {
+            assert!(history.undo());
+        }).unwrap();
+        app.update();
+
+        let text = app.world_mut()
+            .query_filtered::<&Text, With<IsFamiqButtonText>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(text.0, "Save");
+
+        let history = app.world().resource::<RHistory>();
+        assert_eq!(history.entries().count(), 0);
+        assert_eq!(history.undone_entries().next().unwrap().changes.len(), 2);
+
+        app.world_mut().run_system_once(|mut history: FaHistory| {
+            assert!(history.redo());
+        }).unwrap();
+        app.update();
+
+        let text = app.world_mut()
+            .query_filtered::<&Text, With<IsFamiqButtonText>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(text.0, "Saved");
+    }
+
+    #[test]
+    fn test_patch_bindings_without_rebuild() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_bindings);
+        app.update();
+
+        let subscriber = app.world().resource::<RSubscriber>();
+        assert!(subscriber.data.get("width").is_none());
+        assert!(subscriber.data.get("label").is_none());
+        assert_eq!(subscriber.bindings["width"].len(), 1);
+        assert_eq!(subscriber.bindings["label"].len(), 1);
+
+        app.add_systems(Update, run_mutate_bindings);
+        app.update();
+
+        let node = app.world_mut()
+            .query_filtered::<&Node, With<IsFamiqContainer>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(node.width, Val::Px(200.0));
+
+        let text = app.world_mut()
+            .query_filtered::<&Text, With<IsFamiqButtonText>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(text.0, "Saved");
+    }
+
+    #[test]
+    fn test_patch_class_and_color_bindings() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_class_color_bindings);
+        app.update();
+
+        let subscriber = app.world().resource::<RSubscriber>();
+        for key in ["theme", "bg", "fg"] {
+            assert!(subscriber.data.get(key).is_none());
+            assert_eq!(subscriber.bindings[key].len(), 1);
+        }
+        let container = app.world_mut()
+            .query_filtered::<Entity, With<IsFamiqContainer>>()
+            .single(app.world())
+            .unwrap();
+        let text = app.world_mut()
+            .query_filtered::<Entity, With<IsFamiqText>>()
+            .single(app.world())
+            .unwrap();
+        let default_padding = app.world().get::<Node>(container).unwrap().padding;
+        assert_eq!(app.world().get::<BackgroundColor>(container).unwrap().0, Color::from(RED));
+
+        app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+            fa_query.mutate_str("theme", "highlight");
+            fa_query.mutate_str("bg", "blue");
+            fa_query.mutate_str("fg", "blue");
+        }).unwrap();
+        app.update();
+
+        assert_eq!(app.world().get::<WidgetClasses>(container).unwrap().0, "highlight");
+        assert_eq!(app.world().get::<Node>(container).unwrap().padding, UiRect::all(Val::Px(10.0)));
+        assert_eq!(app.world().get::<BackgroundColor>(container).unwrap().0, Color::from(BLUE));
+        assert_eq!(app.world().get::<TextColor>(text).unwrap().0, Color::from(BLUE));
+
+        // styles of the old class don't stay
+        app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+            fa_query.mutate_str("theme", "plain");
+        }).unwrap();
+        app.update();
+        assert_eq!(app.world().get::<Node>(container).unwrap().padding, default_padding);
+        assert_eq!(app.world().get::<BackgroundColor>(container).unwrap().0, Color::from(BLUE));
+    }
+
+    #[test]
+    fn test_hyphenated_key_keeps_subscription() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, |mut fa_query: FaQuery, mut famiq_res: ResMut<FamiqResource>| {
+            fa_query.insert_str("bg-color", "red");
+            FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+            text!(text: "Color: $[bg-color]");
+        });
+        app.update();
+
+        let text = app.world_mut()
+            .query_filtered::<Entity, With<IsFamiqText>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(app.world().get::<Text>(text).unwrap().0, "Color: red");
+
+        app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+            fa_query.mutate_str("bg-color", "blue");
+        }).unwrap();
+        app.update();
+        assert_eq!(app.world().get::<Text>(text).unwrap().0, "Color: blue");
+    }
 }
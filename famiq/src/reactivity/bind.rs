@@ -0,0 +1,510 @@
+//! Keep Bevy resources and components in sync with reactive data.
+
+use bevy::prelude::*;
+use bevy::ecs::component::Mutable;
+use bevy::platform::collections::HashMap;
+use bevy::reflect::{DynamicEnum, DynamicVariant, ReflectMut, ReflectRef, VariantType};
+
+use super::{RData, RVal, ReactiveDataChanged, detect_reactive_data_change, get_reactive_value, get_reactive_value_mut, is_related_path};
+
+/// Convert a reflected value into `RVal`.
+///
+/// Structs become `RVal::Map` so each field is a path key, lists become `RVal::Array`
+/// (`Vec<String>` becomes `RVal::List`) and unit enum variants become `RVal::Str`.
+/// Returns `None` for types `RVal` can't hold.
+pub fn reflect_to_rval(value: &dyn PartialReflect) -> Option<RVal> {
+    if let Some(list) = value.try_downcast_ref::<Vec<String>>() {
+        return Some(RVal::List(list.clone()));
+    }
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            let mut map = HashMap::new();
+            for i in 0..s.field_len() {
+                let (Some(name), Some(field)) = (s.name_at(i), s.field_at(i)) else {
+                    continue;
+                };
+                if let Some(field) = reflect_to_rval(field) {
+                    map.insert(name.to_string(), field);
+                }
+            }
+            Some(RVal::Map(map))
+        }
+        ReflectRef::TupleStruct(s) if s.field_len() == 1 => reflect_to_rval(s.field(0)?),
+        ReflectRef::List(list) => list.iter().map(reflect_to_rval).collect::<Option<_>>().map(RVal::Array),
+        ReflectRef::Array(array) => array.iter().map(reflect_to_rval).collect::<Option<_>>().map(RVal::Array),
+        ReflectRef::Enum(e) if e.variant_type() == VariantType::Unit => Some(RVal::Str(e.variant_name().to_string())),
+        ReflectRef::Opaque(v) => opaque_to_rval(v),
+        _ => None
+    }
+}
+
+fn opaque_to_rval(value: &dyn PartialReflect) -> Option<RVal> {
+    let any = value.try_as_reflect()?.as_any();
+
+    if let Some(v) = any.downcast_ref::<i8>() { return Some(RVal::Num(*v as i32)); }
+    if let Some(v) = any.downcast_ref::<i16>() { return Some(RVal::Num(*v as i32)); }
+    if let Some(v) = any.downcast_ref::<i32>() { return Some(RVal::Num(*v)); }
+    if let Some(v) = any.downcast_ref::<i64>() { return Some(RVal::Num64(*v)); }
+    if let Some(v) = any.downcast_ref::<isize>() { return Some(RVal::Num64(*v as i64)); }
+    if let Some(v) = any.downcast_ref::<u8>() { return Some(RVal::UNum(*v as u64)); }
+    if let Some(v) = any.downcast_ref::<u16>() { return Some(RVal::UNum(*v as u64)); }
+    if let Some(v) = any.downcast_ref::<u64>() { return Some(RVal::UNum(*v)); }
+    if let Some(v) = any.downcast_ref::<u32>() { return Some(RVal::UNum(*v as u64)); }
+    if let Some(v) = any.downcast_ref::<usize>() { return Some(RVal::UNum(*v as u64)); }
+    if let Some(v) = any.downcast_ref::<f32>() { return Some(RVal::FNum(*v)); }
+    if let Some(v) = any.downcast_ref::<f64>() { return Some(RVal::FNum64(*v)); }
+    if let Some(v) = any.downcast_ref::<bool>() { return Some(RVal::Bool(*v)); }
+    if let Some(v) = any.downcast_ref::<String>() { return Some(RVal::Str(v.clone())); }
+    None
+}
+
+/// Write `RVal` into a reflected value, the opposite of [`reflect_to_rval`].
+///
+/// Numbers are converted between numeric types, strings are parsed for numeric fields.
+/// Fields missing from `RVal::Map` are left untouched. Returns false if nothing could be written.
+pub fn apply_rval(target: &mut dyn PartialReflect, value: &RVal) -> bool {
+    if let Some(list) = target.try_downcast_mut::<Vec<String>>() {
+        let RVal::List(value) = value else {
+            return false;
+        };
+        list.clone_from(value);
+        return true;
+    }
+    match (target.reflect_mut(), value) {
+        (ReflectMut::Struct(s), RVal::Map(map)) => {
+            let mut applied = false;
+            for (name, value) in map.iter() {
+                if let Some(field) = s.field_mut(name) {
+                    applied |= apply_rval(field, value);
+                }
+            }
+            applied
+        }
+        (ReflectMut::TupleStruct(s), _) if s.field_len() == 1 => {
+            s.field_mut(0).is_some_and(|field| apply_rval(field, value))
+        }
+        (ReflectMut::List(list), RVal::Array(values)) if list.len() == values.len() => {
+            let mut applied = false;
+            for (i, value) in values.iter().enumerate() {
+                if let Some(item) = list.get_mut(i) {
+                    applied |= apply_rval(item, value);
+                }
+            }
+            applied
+        }
+        (ReflectMut::Array(array), RVal::Array(values)) if array.len() == values.len() => {
+            let mut applied = false;
+            for (i, value) in values.iter().enumerate() {
+                if let Some(item) = array.get_mut(i) {
+                    applied |= apply_rval(item, value);
+                }
+            }
+            applied
+        }
+        (ReflectMut::Enum(e), RVal::Str(variant)) => {
+            e.try_apply(&DynamicEnum::new(variant.as_str(), DynamicVariant::Unit)).is_ok()
+        }
+        (ReflectMut::Opaque(v), _) => apply_opaque(v, value),
+        _ => false
+    }
+}
+
+fn apply_opaque(target: &mut dyn PartialReflect, value: &RVal) -> bool {
+    let Some(any) = target.try_as_reflect_mut().map(|r| r.as_any_mut()) else {
+        return false;
+    };
+    let num = match value {
+        RVal::Num(v) => Some(*v as f64),
+        RVal::Num64(v) => Some(*v as f64),
+        RVal::UNum(v) => Some(*v as f64),
+        RVal::FNum(v) => Some(*v as f64),
+        RVal::FNum64(v) => Some(*v),
+        // text inputs edit numbers as strings
+        RVal::Str(s) if !any.is::<String>() => s.trim().parse::<f64>().ok(),
+        _ => None
+    };
+
+    if let Some(num) = num {
+        if let Some(v) = any.downcast_mut::<i8>() { *v = num as i8; return true; }
+        if let Some(v) = any.downcast_mut::<i16>() { *v = num as i16; return true; }
+        if let Some(v) = any.downcast_mut::<i32>() { *v = num as i32; return true; }
+        if let Some(v) = any.downcast_mut::<i64>() { *v = num as i64; return true; }
+        if let Some(v) = any.downcast_mut::<isize>() { *v = num as isize; return true; }
+        if let Some(v) = any.downcast_mut::<u8>() { *v = num as u8; return true; }
+        if let Some(v) = any.downcast_mut::<u16>() { *v = num as u16; return true; }
+        if let Some(v) = any.downcast_mut::<u32>() { *v = num as u32; return true; }
+        if let Some(v) = any.downcast_mut::<u64>() { *v = num as u64; return true; }
+        if let Some(v) = any.downcast_mut::<usize>() { *v = num as usize; return true; }
+        if let Some(v) = any.downcast_mut::<f32>() { *v = num as f32; return true; }
+        if let Some(v) = any.downcast_mut::<f64>() { *v = num; return true; }
+        return false;
+    }
+    match value {
+        RVal::Bool(b) => any.downcast_mut::<bool>().map(|v| *v = *b).is_some(),
+        RVal::Str(s) => any.downcast_mut::<String>().map(|v| v.clone_from(s)).is_some(),
+        _ => false
+    }
+}
+
+/// Copy `value` into reactive data under `key`, marking only the paths whose value changed.
+fn write_bound_value(r_data: &mut RData, key: &str, value: RVal) {
+    let current = get_reactive_value(&r_data.data, key);
+
+    if current == Some(&value) {
+        return;
+    }
+    match (current, &value) {
+        (Some(RVal::Map(current)), RVal::Map(new)) if current.len() == new.len() => {
+            let changed: Vec<String> = new
+                .iter()
+                .filter(|(field, v)| current.get(*field) != Some(*v))
+                .map(|(field, _)| format!("{key}.{field}"))
+                .collect();
+
+            for path in changed.iter() {
+                r_data.mark_changed(path);
+            }
+        }
+        _ => r_data.mark_changed(key)
+    }
+    match get_reactive_value_mut(&mut r_data.data, key) {
+        Some(current) => *current = value,
+        None => {
+            r_data.data.insert(key.to_string(), value);
+        }
+    }
+}
+
+/// Changes of reactive data that touch `key`.
+fn bound_key_changed(events: &mut EventReader<ReactiveDataChanged>, key: &str) -> bool {
+    events.read().fold(false, |changed, e| changed | is_related_path(&e.key, key))
+}
+
+/// Internal system to insert initial value of a bound resource, so widgets built
+/// on startup can use it.
+fn insert_bound_resource<R: Resource + Reflect>(
+    key: String
+) -> impl FnMut(Option<Res<R>>, ResMut<RData>) {
+    move |resource, mut r_data| {
+        let Some(resource) = resource else {
+            return;
+        };
+        if get_reactive_value(&r_data.data, &key).is_some() {
+            return;
+        }
+        if let Some(value) = reflect_to_rval(resource.as_partial_reflect()) {
+            r_data.data.insert(key.clone(), value);
+        }
+    }
+}
+
+/// Internal system to copy a bound resource into reactive data when it changes.
+fn sync_resource_to_data<R: Resource + Reflect>(
+    key: String
+) -> impl FnMut(Option<Res<R>>, ResMut<RData>, Local<bool>) {
+    move |resource, mut r_data, mut synced| {
+        // changes made in the frame RData is inserted are not detected
+        let Some(resource) = resource.filter(|_| !r_data.is_added()) else {
+            return;
+        };
+        if !resource.is_changed() && *synced {
+            return;
+        }
+        *synced = true;
+
+        if let Some(value) = reflect_to_rval(resource.as_partial_reflect()) {
+            write_bound_value(&mut r_data, &key, value);
+        }
+    }
+}
+
+/// Internal system to write changed reactive data, eg from a `text_input` model, back to a bound resource.
+fn sync_data_to_resource<R: Resource + Reflect>(
+    key: String
+) -> impl FnMut(EventReader<ReactiveDataChanged>, Option<ResMut<R>>, Res<RData>) {
+    move |mut events, resource, r_data| {
+        if !bound_key_changed(&mut events, &key) {
+            return;
+        }
+        let (Some(mut resource), Some(value)) = (resource, get_reactive_value(&r_data.data, &key)) else {
+            return;
+        };
+        if reflect_to_rval(resource.as_partial_reflect()).as_ref() != Some(value) {
+            apply_rval(resource.as_partial_reflect_mut(), value);
+        }
+    }
+}
+
+/// Internal system to copy a bound component into reactive data when it changes.
+fn sync_component_to_data<C: Component + Reflect>(
+    key: String
+) -> impl FnMut(Query<Ref<C>>, ResMut<RData>, Local<bool>) {
+    move |query, mut r_data, mut synced| {
+        let Some(component) = query.single().ok().filter(|_| !r_data.is_added()) else {
+            return;
+        };
+        if !component.is_changed() && *synced {
+            return;
+        }
+        *synced = true;
+
+        if let Some(value) = reflect_to_rval(component.as_partial_reflect()) {
+            write_bound_value(&mut r_data, &key, value);
+        }
+    }
+}
+
+/// Internal system to write changed reactive data back to a bound component.
+fn sync_data_to_component<C: Component<Mutability = Mutable> + Reflect>(
+    key: String
+) -> impl FnMut(EventReader<ReactiveDataChanged>, Query<&mut C>, Res<RData>) {
+    move |mut events, mut query, r_data| {
+        if !bound_key_changed(&mut events, &key) {
+            return;
+        }
+        let (Ok(mut component), Some(value)) = (query.single_mut(), get_reactive_value(&r_data.data, &key)) else {
+            return;
+        };
+        if reflect_to_rval(component.as_partial_reflect()).as_ref() != Some(value) {
+            apply_rval(component.as_partial_reflect_mut(), value);
+        }
+    }
+}
+
+/// Bind resources and components to reactive data from `App`.
+///
+/// The bound value is copied into reactive data whenever it changes, each struct field
+/// being a path key, and changes to those keys (eg, from `text_input` or `selection` models)
+/// are written back to it.
+///
+/// ```rust, no_run
+/// use bevy::prelude::*;
+/// use famiq::prelude::*;
+///
+/// #[derive(Resource, Reflect, Default)]
+/// struct Settings {
+///     name: String,
+///     volume: i32
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(FamiqPlugin::new())
+///     .init_resource::<Settings>()
+///     .bind_resource::<Settings>("settings") // $[settings.name], $[settings.volume]
+///     .run();
+/// ```
+pub trait ReactiveBindExt {
+    /// Keep resource `R` in sync with reactive `key`. If `R` is inserted before startup,
+    /// widgets start with its value.
+    fn bind_resource<R: Resource + Reflect>(&mut self, key: &str) -> &mut Self;
+
+    /// Keep component `C` of the only entity having it in sync with reactive `key`.
+    fn bind_component<C: Component<Mutability = Mutable> + Reflect>(
+        &mut self,
+        key: &str
+    ) -> &mut Self;
+}
+
+impl ReactiveBindExt for App {
+    fn bind_resource<R: Resource + Reflect>(&mut self, key: &str) -> &mut Self {
+        self.add_systems(PreStartup, insert_bound_resource::<R>(key.to_string()));
+        self.add_systems(
+            PostUpdate,
+            (
+                sync_resource_to_data::<R>(key.to_string()).before(detect_reactive_data_change),
+                sync_data_to_resource::<R>(key.to_string()).after(detect_reactive_data_change)
+            )
+        )
+    }
+
+    fn bind_component<C: Component<Mutability = Mutable> + Reflect>(
+        &mut self,
+        key: &str
+    ) -> &mut Self {
+        self.add_systems(
+            PostUpdate,
+            (
+                sync_component_to_data::<C>(key.to_string()).before(detect_reactive_data_change),
+                sync_data_to_component::<C>(key.to_string()).after(detect_reactive_data_change)
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::FamiqPlugin;
+    use crate::utils::{create_test_app, press_key};
+    use crate::widgets::{FaQuery, FamiqBuilder, SetupWidget, SetWidgetAttributes, builder_mut};
+    use crate::resources::FamiqResource;
+    use crate::widgets::text::{TextBuilder, IsFamiqText};
+    use crate::widgets::text_input::{TextInputBuilder, FaTextEdit, IsFamiqTextInput};
+    use crate::{text, text_input};
+    use bevy::input::InputPlugin;
+    use bevy::input::keyboard::Key;
+
+    #[derive(Reflect, Debug, Clone, Copy, PartialEq, Default)]
+    enum Difficulty {
+        #[default]
+        Easy,
+        Hard
+    }
+
+    #[derive(Resource, Reflect, Debug, Default)]
+    struct Settings {
+        name: String,
+        volume: i32,
+        scale: f32,
+        difficulty: Difficulty
+    }
+
+    #[derive(Component, Reflect, Debug)]
+    struct Health(i32);
+
+    #[derive(Reflect, Debug, Default)]
+    struct Limits {
+        tiny: i8,
+        short: u16,
+        offset: isize
+    }
+
+    fn setup_test_settings(mut fa_query: FaQuery, mut famiq_res: ResMut<FamiqResource>) {
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        text!(text: "$[settings.name]: $[settings.volume]");
+    }
+
+    fn get_text(app: &mut App) -> String {
+        app.world_mut()
+            .query_filtered::<&Text, With<IsFamiqText>>()
+            .single(app.world())
+            .unwrap()
+            .0
+            .clone()
+    }
+
+    #[test]
+    fn test_reflect_to_rval_and_back() {
+        let mut settings = Settings {
+            name: "Kim".to_string(),
+            volume: 50,
+            scale: 1.5,
+            difficulty: Difficulty::Easy
+        };
+        let RVal::Map(mut map) = reflect_to_rval(&settings).unwrap() else {
+            panic!("settings should be a map");
+        };
+        assert_eq!(map["name"], RVal::Str("Kim".to_string()));
+        assert_eq!(map["volume"], RVal::Num(50));
+        assert_eq!(map["difficulty"], RVal::Str("Easy".to_string()));
+
+        map.insert("volume".to_string(), RVal::FNum(20.0));
+        map.insert("difficulty".to_string(), RVal::Str("Hard".to_string()));
+        assert!(apply_rval(&mut settings, &RVal::Map(map)));
+        assert_eq!(settings.volume, 20);
+        assert_eq!(settings.difficulty, Difficulty::Hard);
+    }
+
+    #[test]
+    fn test_apply_rval_integer_widths_and_strings() {
+        let mut limits = Limits::default();
+        let map = HashMap::from([
+            ("tiny".to_string(), RVal::Num(-8)),
+            ("short".to_string(), RVal::Str(" 300 ".to_string())),
+            ("offset".to_string(), RVal::UNum(42))
+        ]);
+        assert!(apply_rval(&mut limits, &RVal::Map(map)));
+        assert_eq!((limits.tiny, limits.short, limits.offset), (-8, 300, 42));
+
+        // strings that aren't numbers aren't written
+        assert!(!apply_rval(&mut limits.short, &RVal::Str("3a".to_string())));
+        assert_eq!(limits.short, 300);
+        assert_eq!(reflect_to_rval(&limits.tiny), Some(RVal::Num(-8)));
+    }
+
+    fn setup_test_volume_input(mut fa_query: FaQuery, mut famiq_res: ResMut<FamiqResource>) {
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        text_input!(placeholder: "Volume", model: "settings.volume");
+    }
+
+    #[test]
+    fn test_bind_resource_edited_by_text_input() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_plugins(InputPlugin);
+        app.insert_resource(Settings { volume: 5, ..default() });
+        app.bind_resource::<Settings>("settings");
+        app.add_systems(Startup, setup_test_volume_input);
+        app.update();
+        app.update();
+
+        let input = app.world_mut()
+            .query_filtered::<Entity, With<IsFamiqTextInput>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(app.world().get::<FaTextEdit>(input).unwrap().value, "5");
+
+        app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(input, true);
+        press_key(&mut app, KeyCode::Digit7, Key::Character("7".into()));
+
+        // model value is set with the cursor at the end
+        assert_eq!(app.world().get::<FaTextEdit>(input).unwrap().value, "57");
+        assert_eq!(app.world().resource::<Settings>().volume, 57);
+
+        // a string model, eg. set by code, is parsed for the numeric field
+        app.update();
+        app.world_mut().resource_mut::<RData>().mark_changed("settings.volume");
+        *get_reactive_value_mut(&mut app.world_mut().resource_mut::<RData>().data, "settings.volume").unwrap() =
+            RVal::Str("12".to_string());
+        app.update();
+        assert_eq!(app.world().resource::<Settings>().volume, 12);
+        assert_eq!(app.world().get::<FaTextEdit>(input).unwrap().value, "12");
+    }
+
+    #[test]
+    fn test_bind_resource_both_ways() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.insert_resource(Settings { name: "Kim".to_string(), volume: 50, ..default() });
+        app.bind_resource::<Settings>("settings");
+        app.add_systems(Startup, setup_test_settings);
+        app.update();
+        app.update();
+        assert_eq!(get_text(&mut app), "Kim: 50");
+
+        app.world_mut().resource_mut::<Settings>().volume = 80;
+        app.update();
+        assert_eq!(get_text(&mut app), "Kim: 80");
+
+        app.world_mut().resource_mut::<RData>().mark_changed("settings.name");
+        app.world_mut().resource_mut::<RData>().data.insert(
+            "settings".to_string(),
+            RVal::Map(HashMap::from([
+                ("name".to_string(), RVal::Str("Hong".to_string())),
+                ("volume".to_string(), RVal::Num(80)),
+                ("scale".to_string(), RVal::FNum(0.0)),
+                ("difficulty".to_string(), RVal::Str("Easy".to_string()))
+            ]))
+        );
+        app.update();
+        assert_eq!(app.world().resource::<Settings>().name, "Hong");
+        assert_eq!(get_text(&mut app), "Hong: 80");
+    }
+
+    #[test]
+    fn test_bind_component() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.bind_component::<Health>("hp");
+        let player = app.world_mut().spawn(Health(100)).id();
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<RData>().data["hp"], RVal::Num(100));
+
+        app.world_mut().resource_mut::<RData>().mark_changed("hp");
+        app.world_mut().resource_mut::<RData>().data.insert("hp".to_string(), RVal::Num(70));
+        app.update();
+        assert_eq!(app.world().get::<Health>(player).unwrap().0, 70);
+    }
+}
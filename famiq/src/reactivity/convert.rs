@@ -0,0 +1,318 @@
+//! Conversion between Rust values and `RVal`.
+//!
+//! Structs can derive both traits, fields become entries of `RVal::Map`:
+//!
+//! ```rust
+//! use famiq::prelude::*;
+//!
+//! #[derive(IntoRVal, FromRVal, Debug, PartialEq)]
+//! struct Player {
+//!     name: String,
+//!     hp: u32,
+//!     items: Vec<String>
+//! }
+//!
+//! let player = Player { name: "Kim".into(), hp: 80, items: vec![] };
+//! let value = player.into_rval();
+//! assert_eq!(value.try_as_map().unwrap()["hp"], RVal::UNum(80));
+//! assert_eq!(Player::from_rval(&value).unwrap().name, "Kim");
+//! ```
+
+use bevy::platform::collections::HashMap;
+use std::hash::BuildHasher;
+
+use crate::errors::FamiqError;
+use super::RVal;
+
+/// Convert a value into `RVal`.
+pub trait IntoRVal {
+    fn into_rval(self) -> RVal;
+}
+
+/// Create a value from `RVal`.
+pub trait FromRVal: Sized {
+    /// Returns `FamiqError::TypeMismatch` if `value` can't be converted.
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError>;
+}
+
+fn mismatch<T>(value: &RVal) -> FamiqError {
+    FamiqError::TypeMismatch {
+        expected: std::any::type_name::<T>(),
+        found: value.variant_name()
+    }
+}
+
+impl IntoRVal for RVal {
+    fn into_rval(self) -> RVal {
+        self
+    }
+}
+
+impl FromRVal for RVal {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        Ok(value.clone())
+    }
+}
+
+/// Integers from any integer variant, as long as the value fits.
+macro_rules! impl_rval_int {
+    ($($ty:ty => $variant:ident($inner:ty)),* $(,)?) => {$(
+        impl IntoRVal for $ty {
+            fn into_rval(self) -> RVal {
+                RVal::$variant(self as $inner)
+            }
+        }
+
+        impl FromRVal for $ty {
+            fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+                let converted = match value {
+                    RVal::Num(v) => <$ty>::try_from(*v).ok(),
+                    RVal::Num64(v) => <$ty>::try_from(*v).ok(),
+                    RVal::UNum(v) => <$ty>::try_from(*v).ok(),
+                    _ => None
+                };
+                converted.ok_or_else(|| mismatch::<$ty>(value))
+            }
+        }
+    )*};
+}
+
+impl_rval_int!(
+    i8 => Num(i32),
+    i16 => Num(i32),
+    i32 => Num(i32),
+    i64 => Num64(i64),
+    isize => Num64(i64),
+    u8 => UNum(u64),
+    u16 => UNum(u64),
+    u32 => UNum(u64),
+    u64 => UNum(u64),
+    usize => UNum(u64)
+);
+
+/// Floats from any numeric variant.
+macro_rules! impl_rval_float {
+    ($($ty:ty => $variant:ident),* $(,)?) => {$(
+        impl IntoRVal for $ty {
+            fn into_rval(self) -> RVal {
+                RVal::$variant(self)
+            }
+        }
+
+        impl FromRVal for $ty {
+            fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+                match value {
+                    RVal::FNum(v) => Ok(*v as $ty),
+                    RVal::FNum64(v) => Ok(*v as $ty),
+                    RVal::Num(v) => Ok(*v as $ty),
+                    RVal::Num64(v) => Ok(*v as $ty),
+                    RVal::UNum(v) => Ok(*v as $ty),
+                    _ => Err(mismatch::<$ty>(value))
+                }
+            }
+        }
+    )*};
+}
+
+impl_rval_float!(f32 => FNum, f64 => FNum64);
+
+impl IntoRVal for bool {
+    fn into_rval(self) -> RVal {
+        RVal::Bool(self)
+    }
+}
+
+impl FromRVal for bool {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        match value {
+            RVal::Bool(v) => Ok(*v),
+            _ => Err(mismatch::<bool>(value))
+        }
+    }
+}
+
+impl IntoRVal for String {
+    fn into_rval(self) -> RVal {
+        RVal::Str(self)
+    }
+}
+
+impl IntoRVal for &str {
+    fn into_rval(self) -> RVal {
+        RVal::Str(self.to_string())
+    }
+}
+
+impl IntoRVal for char {
+    fn into_rval(self) -> RVal {
+        RVal::Str(self.to_string())
+    }
+}
+
+impl FromRVal for String {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        match value {
+            RVal::Str(v) => Ok(v.clone()),
+            _ => Err(mismatch::<String>(value))
+        }
+    }
+}
+
+impl<T: IntoRVal> IntoRVal for Option<T> {
+    fn into_rval(self) -> RVal {
+        match self {
+            Some(v) => v.into_rval(),
+            None => RVal::None
+        }
+    }
+}
+
+impl<T: FromRVal> FromRVal for Option<T> {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        match value {
+            RVal::None => Ok(None),
+            _ => T::from_rval(value).map(Some)
+        }
+    }
+}
+
+impl<T: IntoRVal> IntoRVal for Vec<T> {
+    fn into_rval(self) -> RVal {
+        RVal::Array(self.into_iter().map(IntoRVal::into_rval).collect())
+    }
+}
+
+/// From `RVal::Array`, or `RVal::List` whose items are `RVal::Str`.
+impl<T: FromRVal> FromRVal for Vec<T> {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        match value {
+            RVal::Array(items) => items.iter().map(T::from_rval).collect(),
+            RVal::List(items) => items
+                .iter()
+                .map(|item| T::from_rval(&RVal::Str(item.clone())))
+                .collect(),
+            _ => Err(mismatch::<Vec<T>>(value))
+        }
+    }
+}
+
+impl<T: IntoRVal, S> IntoRVal for HashMap<String, T, S> {
+    fn into_rval(self) -> RVal {
+        RVal::Map(self.into_iter().map(|(k, v)| (k, v.into_rval())).collect())
+    }
+}
+
+impl<T: FromRVal, S: BuildHasher + Default> FromRVal for HashMap<String, T, S> {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        match value {
+            RVal::Map(map) => map
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), T::from_rval(v)?)))
+                .collect(),
+            _ => Err(mismatch::<Self>(value))
+        }
+    }
+}
+
+impl<T: IntoRVal, S> IntoRVal for std::collections::HashMap<String, T, S> {
+    fn into_rval(self) -> RVal {
+        RVal::Map(self.into_iter().map(|(k, v)| (k, v.into_rval())).collect())
+    }
+}
+
+impl<T: FromRVal, S: BuildHasher + Default> FromRVal for std::collections::HashMap<String, T, S> {
+    fn from_rval(value: &RVal) -> Result<Self, FamiqError> {
+        match value {
+            RVal::Map(map) => map
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), T::from_rval(v)?)))
+                .collect(),
+            _ => Err(mismatch::<Self>(value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use famiq_macros::{IntoRVal, FromRVal};
+
+    #[derive(IntoRVal, FromRVal, Debug, Clone, PartialEq)]
+    enum Class {
+        Warrior,
+        Mage
+    }
+
+    #[derive(IntoRVal, FromRVal, Debug, Clone, PartialEq)]
+    struct Score(u32);
+
+    #[derive(IntoRVal, FromRVal, Debug, Clone, PartialEq)]
+    struct Player {
+        name: String,
+        score: Score,
+        class: Class,
+        pet: Option<String>,
+        items: Vec<String>
+    }
+
+    // only compiles if the derives bound `T` by `IntoRVal` & `FromRVal`
+    #[derive(IntoRVal, FromRVal, Debug, Clone, PartialEq)]
+    struct Tagged<T>
+    where
+        T: Clone
+    {
+        tag: String,
+        value: T
+    }
+
+    #[derive(IntoRVal, FromRVal, Debug, Clone, PartialEq)]
+    struct Wrapper<T>(T);
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(5_u32.into_rval(), RVal::UNum(5));
+        assert_eq!(u32::from_rval(&RVal::Num(5)), Ok(5));
+        assert_eq!(f64::from_rval(&RVal::Num(2)), Ok(2.0));
+        assert_eq!(
+            u8::from_rval(&RVal::Num(-1)),
+            Err(FamiqError::TypeMismatch { expected: "u8", found: "Num" })
+        );
+        assert_eq!(Option::<i32>::from_rval(&RVal::None), Ok(None));
+        assert_eq!(Class::from_rval(&RVal::Str("Warrior".to_string())), Ok(Class::Warrior));
+        assert_eq!(
+            Vec::<String>::from_rval(&RVal::List(vec!["a".to_string()])),
+            Ok(vec!["a".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_derive_round_trip() {
+        let player = Player {
+            name: "Kim".to_string(),
+            score: Score(10),
+            class: Class::Mage,
+            pet: None,
+            items: vec!["sword".to_string()]
+        };
+        let value = player.clone().into_rval();
+        let map = value.try_as_map().unwrap();
+        assert_eq!(map["score"], RVal::UNum(10));
+        assert_eq!(map["class"], RVal::Str("Mage".to_string()));
+        assert_eq!(Player::from_rval(&value), Ok(player));
+
+        let mut missing = map.clone();
+        missing.remove("name");
+        assert_eq!(
+            Player::from_rval(&RVal::Map(missing)),
+            Err(FamiqError::KeyNotFound("name".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_derive_generic_round_trip() {
+        let tagged = Tagged { tag: "hp".to_string(), value: Wrapper(Score(3)) };
+        let value = tagged.clone().into_rval();
+        assert_eq!(value.try_as_map().unwrap()["value"], RVal::UNum(3));
+        assert_eq!(Tagged::<Wrapper<Score>>::from_rval(&value), Ok(tagged));
+    }
+}
@@ -0,0 +1,769 @@
+//! Expressions inside `$[...]` templates.
+//!
+//! Supports number, string and bool literals, reactive keys or paths, arithmetic
+//! (`+ - * / %`), comparison (`== != < <= > >=`), boolean logic (`&& || !`, or `and or not`),
+//! ternaries (`a ? b : c`) and filters (`value | fixed(2) | upper`).
+
+use bevy::platform::collections::HashMap;
+use std::ops::Range;
+
+use crate::errors::ExpressionErr;
+use super::{RVal, get_reactive_value};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    /// reactive key or path, also keywords and filter names
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+    Question,
+    Colon,
+    Pipe
+}
+
+const OPERATORS: [&str; 15] = [
+    "==", "!=", "<=", ">=", "&&", "||", "<", ">", "+", "-", "*", "/", "%", "!", "="
+];
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn tokenize(src: &str) -> Result<Vec<(Token, Range<usize>)>, ExpressionErr> {
+    let chars: Vec<(usize, char)> = src.char_indices().collect();
+    let end_of = |i: usize| chars.get(i).map(|(pos, _)| *pos).unwrap_or(src.len());
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, c) = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let mut j = i;
+            let mut is_float = false;
+            while j < chars.len() {
+                let d = chars[j].1;
+                let next_is_digit = chars.get(j + 1).is_some_and(|(_, n)| n.is_ascii_digit());
+
+                if d.is_ascii_digit() {
+                    j += 1;
+                } else if d == '.' && !is_float && next_is_digit {
+                    is_float = true;
+                    j += 1;
+                } else {
+                    break;
+                }
+            }
+            let text = &src[start..end_of(j)];
+            let token = if is_float {
+                Token::Float(text.parse().map_err(|_| ExpressionErr::Syntax(text.to_string()))?)
+            } else {
+                match text.parse::<i64>() {
+                    Ok(v) => Token::Int(v),
+                    Err(_) => Token::Float(text.parse().map_err(|_| ExpressionErr::Syntax(text.to_string()))?)
+                }
+            };
+            tokens.push((token, start..end_of(j)));
+            i = j;
+            continue;
+        }
+
+        if is_ident_start(c) {
+            let mut j = i + 1;
+            while j < chars.len() {
+                let d = chars[j].1;
+                let next_is_ident = chars.get(j + 1).is_some_and(|(_, n)| is_ident_char(*n));
+
+                if is_ident_char(d) || (d == '.' && next_is_ident) {
+                    j += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push((Token::Ident(src[start..end_of(j)].to_string()), start..end_of(j)));
+            i = j;
+            continue;
+        }
+
+        if c == '"' || c == '\'' {
+            let mut j = i + 1;
+            let mut value = String::new();
+            let mut closed = false;
+
+            while j < chars.len() {
+                let d = chars[j].1;
+                if d == '\\' && j + 1 < chars.len() {
+                    value.push(chars[j + 1].1);
+                    j += 2;
+                    continue;
+                }
+                j += 1;
+                if d == c {
+                    closed = true;
+                    break;
+                }
+                value.push(d);
+            }
+            if !closed {
+                return Err(ExpressionErr::Syntax(format!("unclosed string in {src:?}")));
+            }
+            tokens.push((Token::Str(value), start..end_of(j)));
+            i = j;
+            continue;
+        }
+
+        let single = match c {
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            ',' => Some(Token::Comma),
+            '?' => Some(Token::Question),
+            ':' => Some(Token::Colon),
+            '|' if chars.get(i + 1).map(|(_, n)| *n) != Some('|') => Some(Token::Pipe),
+            _ => None
+        };
+        if let Some(token) = single {
+            tokens.push((token, start..end_of(i + 1)));
+            i += 1;
+            continue;
+        }
+
+        let rest = &src[start..];
+        let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(*op)) else {
+            return Err(ExpressionErr::Syntax(format!("unexpected {c:?} in {src:?}")));
+        };
+        // single `=` is most likely a typo of `==`
+        if *op == "=" {
+            return Err(ExpressionErr::Syntax(format!("use == instead of = in {src:?}")));
+        }
+        let op_len = op.chars().count();
+        tokens.push((Token::Op(op), start..end_of(i + op_len)));
+        i += op_len;
+    }
+    Ok(tokens)
+}
+
+/// Parsed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Literal(RVal),
+    /// reactive key or path
+    Path(String),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    Binary(&'static str, Box<Expr>, Box<Expr>),
+    Ternary(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// value, filter name, filter arguments
+    Filter(Box<Expr>, String, Vec<Expr>)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ExpressionErr> {
+        match self.advance() {
+            Some(token) if token == expected => Ok(()),
+            other => Err(ExpressionErr::Syntax(format!("expected {expected:?}, found {other:?}")))
+        }
+    }
+
+    /// Next token is one of the given operators or keywords, returns the operator.
+    fn eat_op(&mut self, ops: &[(&str, &'static str)]) -> Option<&'static str> {
+        let found = match self.peek() {
+            Some(Token::Op(op)) => ops.iter().find(|(o, _)| o == op),
+            Some(Token::Ident(word)) => ops.iter().find(|(o, _)| o == word),
+            _ => None
+        };
+        let op = found?.1;
+        self.pos += 1;
+        Some(op)
+    }
+
+    fn parse_pipe(&mut self) -> Result<Expr, ExpressionErr> {
+        let mut expr = self.parse_ternary()?;
+
+        while self.peek() == Some(&Token::Pipe) {
+            self.pos += 1;
+            let Some(Token::Ident(name)) = self.advance() else {
+                return Err(ExpressionErr::Syntax("expected filter name after |".to_string()));
+            };
+            let mut args = Vec::new();
+
+            if self.peek() == Some(&Token::LParen) {
+                self.pos += 1;
+                if self.peek() != Some(&Token::RParen) {
+                    args.push(self.parse_ternary()?);
+                    while self.peek() == Some(&Token::Comma) {
+                        self.pos += 1;
+                        args.push(self.parse_ternary()?);
+                    }
+                }
+                self.expect(Token::RParen)?;
+            }
+            expr = Expr::Filter(Box::new(expr), name, args);
+        }
+        Ok(expr)
+    }
+
+    fn parse_ternary(&mut self) -> Result<Expr, ExpressionErr> {
+        let condition = self.parse_binary(0)?;
+
+        if self.peek() != Some(&Token::Question) {
+            return Ok(condition);
+        }
+        self.pos += 1;
+        let then = self.parse_ternary()?;
+        self.expect(Token::Colon)?;
+        let otherwise = self.parse_ternary()?;
+
+        Ok(Expr::Ternary(Box::new(condition), Box::new(then), Box::new(otherwise)))
+    }
+
+    /// Binary operators from lowest to highest precedence.
+    const LEVELS: [&'static [(&'static str, &'static str)]; 6] = [
+        &[("||", "||"), ("or", "||")],
+        &[("&&", "&&"), ("and", "&&")],
+        &[("==", "=="), ("!=", "!=")],
+        &[("<", "<"), ("<=", "<="), (">", ">"), (">=", ">=")],
+        &[("+", "+"), ("-", "-")],
+        &[("*", "*"), ("/", "/"), ("%", "%")]
+    ];
+
+    fn parse_binary(&mut self, level: usize) -> Result<Expr, ExpressionErr> {
+        if level == Self::LEVELS.len() {
+            return self.parse_unary();
+        }
+        let mut left = self.parse_binary(level + 1)?;
+
+        while let Some(op) = self.eat_op(Self::LEVELS[level]) {
+            let right = self.parse_binary(level + 1)?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ExpressionErr> {
+        if self.eat_op(&[("!", "!"), ("not", "!")]).is_some() {
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.eat_op(&[("-", "-")]).is_some() {
+            return Ok(Expr::Neg(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ExpressionErr> {
+        match self.advance() {
+            Some(Token::Int(v)) => Ok(Expr::Literal(RVal::Num64(v))),
+            Some(Token::Float(v)) => Ok(Expr::Literal(RVal::FNum64(v))),
+            Some(Token::Str(v)) => Ok(Expr::Literal(RVal::Str(v))),
+            Some(Token::Ident(word)) => Ok(match word.as_str() {
+                "true" => Expr::Literal(RVal::Bool(true)),
+                "false" => Expr::Literal(RVal::Bool(false)),
+                "none" | "null" => Expr::Literal(RVal::None),
+                _ => Expr::Path(word)
+            }),
+            Some(Token::LParen) => {
+                let expr = self.parse_pipe()?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
+            }
+            other => Err(ExpressionErr::Syntax(format!("unexpected {other:?}")))
+        }
+    }
+}
+
+/// Parse an expression, eg. `"hp / max_hp * 100 | fixed(1)"`.
+pub fn parse_expression(src: &str) -> Result<Expr, ExpressionErr> {
+    let tokens = tokenize(src)?.into_iter().map(|(token, _)| token).collect();
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_pipe()?;
+
+    if let Some(token) = parser.peek() {
+        return Err(ExpressionErr::Syntax(format!("unexpected {token:?} in {src:?}")));
+    }
+    Ok(expr)
+}
+
+impl Expr {
+    /// Reactive keys or paths used by the expression.
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+        self.collect_keys(&mut keys);
+        keys
+    }
+
+    fn collect_keys(&self, keys: &mut Vec<String>) {
+        match self {
+            Expr::Literal(_) => {}
+            Expr::Path(path) => {
+                if !keys.contains(path) {
+                    keys.push(path.clone());
+                }
+            }
+            Expr::Not(e) | Expr::Neg(e) => e.collect_keys(keys),
+            Expr::Binary(_, left, right) => {
+                left.collect_keys(keys);
+                right.collect_keys(keys);
+            }
+            Expr::Ternary(condition, then, otherwise) => {
+                condition.collect_keys(keys);
+                then.collect_keys(keys);
+                otherwise.collect_keys(keys);
+            }
+            Expr::Filter(value, _, args) => {
+                value.collect_keys(keys);
+                args.iter().for_each(|arg| arg.collect_keys(keys));
+            }
+        }
+    }
+
+    /// Evaluate against reactive data. Missing keys are an error if `strict`, otherwise `RVal::None`.
+    pub fn eval(&self, data: &HashMap<String, RVal>, strict: bool) -> Result<RVal, ExpressionErr> {
+        match self {
+            Expr::Literal(v) => Ok(v.clone()),
+            Expr::Path(path) => match get_reactive_value(data, path) {
+                Some(v) => Ok(v.clone()),
+                None if strict => Err(ExpressionErr::KeyNotFound(path.clone())),
+                None => Ok(RVal::None)
+            },
+            Expr::Not(e) => Ok(RVal::Bool(!e.eval(data, strict)?.is_truthy())),
+            Expr::Neg(e) => match as_number(&e.eval(data, strict)?) {
+                Some(Number::Int(v)) => Ok(v.checked_neg().map_or(RVal::FNum64(-(v as f64)), RVal::Num64)),
+                Some(Number::Float(v)) => Ok(RVal::FNum64(-v)),
+                None => Err(ExpressionErr::InvalidOperation("- on non number".to_string()))
+            },
+            Expr::Binary("&&", left, right) => {
+                let value = left.eval(data, strict)?.is_truthy() && right.eval(data, strict)?.is_truthy();
+                Ok(RVal::Bool(value))
+            }
+            Expr::Binary("||", left, right) => {
+                let value = left.eval(data, strict)?.is_truthy() || right.eval(data, strict)?.is_truthy();
+                Ok(RVal::Bool(value))
+            }
+            Expr::Binary(op, left, right) => {
+                binary(op, &left.eval(data, strict)?, &right.eval(data, strict)?)
+            }
+            Expr::Ternary(condition, then, otherwise) => {
+                if condition.eval(data, strict)?.is_truthy() {
+                    then.eval(data, strict)
+                } else {
+                    otherwise.eval(data, strict)
+                }
+            }
+            Expr::Filter(value, name, args) => {
+                let value = match value.eval(data, strict) {
+                    // missing key is what default is for
+                    Err(ExpressionErr::KeyNotFound(_)) if name == "default" => RVal::None,
+                    other => other?
+                };
+                let args = args
+                    .iter()
+                    .map(|arg| arg.eval(data, strict))
+                    .collect::<Result<Vec<RVal>, ExpressionErr>>()?;
+                filter(name, value, &args)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Number {
+    Int(i64),
+    Float(f64)
+}
+
+impl Number {
+    fn as_f64(self) -> f64 {
+        match self {
+            Number::Int(v) => v as f64,
+            Number::Float(v) => v
+        }
+    }
+}
+
+fn as_number(value: &RVal) -> Option<Number> {
+    match value {
+        RVal::Num(v) => Some(Number::Int(*v as i64)),
+        RVal::Num64(v) => Some(Number::Int(*v)),
+        RVal::UNum(v) => Some(i64::try_from(*v).map(Number::Int).unwrap_or(Number::Float(*v as f64))),
+        RVal::FNum(v) => Some(Number::Float(*v as f64)),
+        RVal::FNum64(v) => Some(Number::Float(*v)),
+        _ => None
+    }
+}
+
+fn from_number(number: Number) -> RVal {
+    match number {
+        Number::Int(v) => RVal::Num64(v),
+        Number::Float(v) => RVal::FNum64(v)
+    }
+}
+
+fn arithmetic(op: &str, left: Number, right: Number) -> Result<RVal, ExpressionErr> {
+    if (op == "/" || op == "%") && right.as_f64() == 0.0 {
+        return Err(ExpressionErr::InvalidOperation("division by zero".to_string()));
+    }
+    if let (Number::Int(l), Number::Int(r)) = (left, right) {
+        let value = match op {
+            "+" => l.checked_add(r),
+            "-" => l.checked_sub(r),
+            "*" => l.checked_mul(r),
+            "%" => l.checked_rem(r),
+            // always float, 1 / 2 is 0.5
+            _ => None
+        };
+        if let Some(v) = value {
+            return Ok(RVal::Num64(v));
+        }
+    }
+    let (l, r) = (left.as_f64(), right.as_f64());
+    let value = match op {
+        "+" => l + r,
+        "-" => l - r,
+        "*" => l * r,
+        "/" => l / r,
+        _ => l % r
+    };
+    Ok(from_number(Number::Float(value)))
+}
+
+fn binary(op: &str, left: &RVal, right: &RVal) -> Result<RVal, ExpressionErr> {
+    let numbers = as_number(left).zip(as_number(right));
+
+    match op {
+        "+" | "-" | "*" | "/" | "%" => {
+            if let Some((l, r)) = numbers {
+                return arithmetic(op, l, r);
+            }
+            if op == "+" && (matches!(left, RVal::Str(_)) || matches!(right, RVal::Str(_))) {
+                return Ok(RVal::Str(format!("{}{}", left.to_string(), right.to_string())));
+            }
+            Err(ExpressionErr::InvalidOperation(format!("{left:?} {op} {right:?}")))
+        }
+        "==" | "!=" => {
+            let equal = match numbers {
+                Some((l, r)) => l.as_f64() == r.as_f64(),
+                None => left == right
+            };
+            Ok(RVal::Bool(equal == (op == "==")))
+        }
+        _ => {
+            let ordering = match (numbers, left, right) {
+                (Some((l, r)), _, _) => l.as_f64().partial_cmp(&r.as_f64()),
+                (None, RVal::Str(l), RVal::Str(r)) => Some(l.cmp(r)),
+                _ => None
+            };
+            let Some(ordering) = ordering else {
+                return Err(ExpressionErr::InvalidOperation(format!("{left:?} {op} {right:?}")));
+            };
+            let value = match op {
+                "<" => ordering.is_lt(),
+                "<=" => ordering.is_le(),
+                ">" => ordering.is_gt(),
+                _ => ordering.is_ge()
+            };
+            Ok(RVal::Bool(value))
+        }
+    }
+}
+
+fn number_arg(name: &str, args: &[RVal], index: usize, default: i64) -> Result<i64, ExpressionErr> {
+    match args.get(index) {
+        None => Ok(default),
+        Some(arg) => match as_number(arg) {
+            Some(Number::Int(v)) => Ok(v),
+            Some(Number::Float(v)) => Ok(v as i64),
+            None => Err(ExpressionErr::InvalidOperation(format!("{name} expects a number, got {arg:?}")))
+        }
+    }
+}
+
+/// Apply a filter.
+///
+/// - `fixed(n)`: number with n decimals, default 0
+/// - `round`, `floor`, `ceil`, `abs`: number
+/// - `upper`, `lower`, `capitalize`, `trim`: text
+/// - `truncate(n)`: cut text longer than n characters and add `...`
+/// - `len`: length of text, list or map
+/// - `join(sep)`: join list items, default separator is `, `
+/// - `default(v)`: `v` if the value is missing, none or empty text
+fn filter(name: &str, value: RVal, args: &[RVal]) -> Result<RVal, ExpressionErr> {
+    let number = || {
+        as_number(&value)
+            .ok_or_else(|| ExpressionErr::InvalidOperation(format!("{name} expects a number, got {value:?}")))
+    };
+
+    match name {
+        "fixed" => {
+            let digits = number_arg(name, args, 0, 0)?.max(0) as usize;
+            Ok(RVal::Str(format!("{:.*}", digits, number()?.as_f64())))
+        }
+        "round" | "floor" | "ceil" => {
+            let v = match number()? {
+                Number::Int(v) => return Ok(RVal::Num64(v)),
+                Number::Float(v) => v
+            };
+            let v = match name {
+                "round" => v.round(),
+                "floor" => v.floor(),
+                _ => v.ceil()
+            };
+            Ok(RVal::Num64(v as i64))
+        }
+        "abs" => Ok(match number()? {
+            Number::Int(v) => v.checked_abs().map_or(RVal::FNum64((v as f64).abs()), RVal::Num64),
+            Number::Float(v) => RVal::FNum64(v.abs())
+        }),
+        "upper" => Ok(RVal::Str(value.to_string().to_uppercase())),
+        "lower" => Ok(RVal::Str(value.to_string().to_lowercase())),
+        "trim" => Ok(RVal::Str(value.to_string().trim().to_string())),
+        "capitalize" => {
+            let text = value.to_string();
+            let mut chars = text.chars();
+            let capitalized = match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new()
+            };
+            Ok(RVal::Str(capitalized))
+        }
+        "truncate" => {
+            let max = number_arg(name, args, 0, 20)?.max(0) as usize;
+            let text = value.to_string();
+
+            if text.chars().count() <= max {
+                return Ok(RVal::Str(text));
+            }
+            Ok(RVal::Str(format!("{}...", text.chars().take(max).collect::<String>())))
+        }
+        "len" => {
+            let len = match &value {
+                RVal::Str(v) => v.chars().count(),
+                RVal::List(v) => v.len(),
+                RVal::Array(v) => v.len(),
+                RVal::Map(v) => v.len(),
+                _ => return Err(ExpressionErr::InvalidOperation(format!("len of {value:?}")))
+            };
+            Ok(RVal::Num64(len as i64))
+        }
+        "join" => {
+            let separator = args.first().map(|s| s.to_string()).unwrap_or(", ".to_string());
+            let items: Vec<String> = match &value {
+                RVal::List(v) => v.clone(),
+                RVal::Array(v) => v.iter().map(|item| item.to_string()).collect(),
+                _ => return Err(ExpressionErr::InvalidOperation(format!("join of {value:?}")))
+            };
+            Ok(RVal::Str(items.join(&separator)))
+        }
+        "default" => {
+            let is_empty = matches!(&value, RVal::None) || value == RVal::Str(String::new());
+            match (is_empty, args.first()) {
+                (true, Some(fallback)) => Ok(fallback.clone()),
+                _ => Ok(value)
+            }
+        }
+        _ => Err(ExpressionErr::UnknownFilter(name.to_string()))
+    }
+}
+
+/// Evaluate an expression against reactive data, eg. `"count + 1"`.
+/// An exact key is looked up first, so keys that aren't valid expressions still work.
+pub fn eval_expression(src: &str, data: &HashMap<String, RVal>) -> Result<RVal, ExpressionErr> {
+    if let Some(value) = get_reactive_value(data, src.trim()) {
+        return Ok(value.clone());
+    }
+    parse_expression(src)?.eval(data, true)
+}
+
+/// Evaluate an expression as condition, missing keys count as none (false).
+pub fn eval_condition(src: &str, data: &HashMap<String, RVal>) -> bool {
+    if let Some(value) = get_reactive_value(data, src.trim()) {
+        return value.is_truthy();
+    }
+    parse_expression(src)
+        .and_then(|expr| expr.eval(data, false))
+        .is_ok_and(|value| value.is_truthy())
+}
+
+/// Reactive keys used by an expression. If it can't be parsed, the whole
+/// expression is treated as a key, eg. `"my key"`.
+///
+/// Like `eval_expression`, a source using keys & without whitespace may be a key itself,
+/// eg. `"bg-color"` is a key as well as `bg - color`.
+pub fn expression_keys(src: &str) -> Vec<String> {
+    let src = src.trim();
+    match parse_expression(src) {
+        Ok(expr) => {
+            let mut keys = expr.keys();
+            if !keys.is_empty() && !src.contains(char::is_whitespace) && !keys.iter().any(|key| key == src) {
+                keys.push(src.to_string());
+            }
+            keys
+        }
+        Err(_) => vec![src.to_string()]
+    }
+}
+
+/// Rewrite reactive keys or paths of an expression with `f`, the rest is kept as is.
+pub(crate) fn map_expression_keys(src: &str, f: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let tokens = tokenize(src).ok()?;
+    let mut result = String::with_capacity(src.len());
+    let mut last = 0;
+    let mut after_pipe = false;
+
+    for (token, span) in tokens {
+        // filter names aren't keys
+        let is_filter = after_pipe;
+        after_pipe = token == Token::Pipe;
+
+        let Token::Ident(word) = &token else {
+            continue;
+        };
+        let is_keyword = matches!(word.as_str(), "true" | "false" | "none" | "null" | "and" | "or" | "not");
+        if is_keyword || is_filter {
+            continue;
+        }
+        let Some(replaced) = f(word) else {
+            continue;
+        };
+        result.push_str(&src[last..span.start]);
+        result.push_str(&replaced);
+        last = span.end;
+    }
+    result.push_str(&src[last..]);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data() -> HashMap<String, RVal> {
+        let mut stats = HashMap::new();
+        stats.insert("hp".to_string(), RVal::Num(50));
+        stats.insert("max_hp".to_string(), RVal::Num(200));
+
+        let mut data = HashMap::new();
+        data.insert("count".to_string(), RVal::Num(5));
+        data.insert("price".to_string(), RVal::FNum(4.25678));
+        data.insert("name".to_string(), RVal::Str("famiq".to_string()));
+        data.insert("is_admin".to_string(), RVal::Bool(false));
+        data.insert("tags".to_string(), RVal::List(vec!["a".to_string(), "b".to_string()]));
+        data.insert("stats".to_string(), RVal::Map(stats));
+        data.insert("my key".to_string(), RVal::Str("spaced".to_string()));
+        data
+    }
+
+    fn eval(src: &str) -> String {
+        eval_expression(src, &data()).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_eval_arithmetic() {
+        assert_eq!("6", eval("count + 1"));
+        assert_eq!("25", eval("stats.hp / stats.max_hp * 100"));
+        assert_eq!("2.5", eval("count / 2"));
+        assert_eq!("1", eval("count % 2"));
+        assert_eq!("-9", eval("-(count + 4)"));
+        assert_eq!("13", eval("1 + 2 * 6"));
+        assert_eq!("Hi famiq", eval("'Hi ' + name"));
+    }
+
+    #[test]
+    fn test_eval_logic_and_ternary() {
+        assert_eq!("User", eval(r#"is_admin ? "Admin" : "User""#));
+        assert_eq!("true", eval("count > 1 && !is_admin"));
+        assert_eq!("true", eval("count == 5.0 or false"));
+        assert_eq!("false", eval("not (count >= 5)"));
+        assert_eq!("big", eval("count > 10 ? 'huge' : count > 3 ? 'big' : 'small'"));
+    }
+
+    #[test]
+    fn test_eval_filters() {
+        assert_eq!("4.26", eval("price | fixed(2)"));
+        assert_eq!("FAMIQ", eval("name | upper"));
+        assert_eq!("Famiq", eval("name | capitalize"));
+        assert_eq!("fa...", eval("name | truncate(2) | lower"));
+        assert_eq!("a / b", eval("tags | join(' / ')"));
+        assert_eq!("2", eval("tags | len"));
+        assert_eq!("Guest", eval("nickname | default('Guest')"));
+        assert_eq!("4", eval("price | floor"));
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let data = data();
+        assert_eq!(Err(ExpressionErr::KeyNotFound("missing".to_string())), eval_expression("missing + 1", &data));
+        assert_eq!(Err(ExpressionErr::UnknownFilter("nope".to_string())), eval_expression("count | nope", &data));
+        assert!(matches!(eval_expression("count +", &data), Err(ExpressionErr::Syntax(_))));
+        assert!(matches!(eval_expression("count / 0", &data), Err(ExpressionErr::InvalidOperation(_))));
+    }
+
+    #[test]
+    fn test_eval_int_min_does_not_overflow() {
+        let mut data = data();
+        data.insert("min".to_string(), RVal::Num64(i64::MIN));
+
+        let expected = RVal::FNum64(-(i64::MIN as f64));
+        assert_eq!(Ok(expected.clone()), eval_expression("-min", &data));
+        assert_eq!(Ok(expected), eval_expression("min | abs", &data));
+        assert_eq!(Ok(RVal::Num64(i64::MAX)), eval_expression("-(min + 1)", &data));
+    }
+
+    #[test]
+    fn test_exact_key_and_condition() {
+        let data = data();
+        assert_eq!(Ok(RVal::Str("spaced".to_string())), eval_expression("my key", &data));
+        assert!(eval_condition("!missing", &data));
+        assert!(!eval_condition("missing", &data));
+        assert!(eval_condition("count > 2", &data));
+    }
+
+    #[test]
+    fn test_expression_keys() {
+        assert_eq!(
+            vec!["stats.hp".to_string(), "stats.max_hp".to_string()],
+            expression_keys("stats.hp / stats.max_hp * 100 | fixed(1)")
+        );
+        assert_eq!(vec!["is_admin".to_string()], expression_keys("is_admin ? 'Admin' : 'User'"));
+        assert_eq!(vec!["my key".to_string()], expression_keys("my key"));
+        assert_eq!(
+            vec!["bg".to_string(), "color".to_string(), "bg-color".to_string()],
+            expression_keys(" bg-color ")
+        );
+    }
+
+    #[test]
+    fn test_map_expression_keys() {
+        let mapped = map_expression_keys("item.price * 2 | fixed(item.digits)", |key| {
+            key.strip_prefix("item").map(|rest| format!("todos.0{rest}"))
+        });
+        assert_eq!(Some("todos.0.price * 2 | fixed(todos.0.digits)".to_string()), mapped);
+    }
+}
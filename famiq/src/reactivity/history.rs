@@ -0,0 +1,434 @@
+//! Undo/redo history of reactive data.
+
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::{RData, ReactiveDataChanged, RVal, get_reactive_value_mut, is_internal_key};
+
+/// One undo step, changes made within one frame or within one transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// transaction name, None if recorded outside of transactions
+    pub name: Option<String>,
+    /// changes in the order they were made
+    pub changes: Vec<ReactiveDataChanged>,
+    /// elapsed seconds at the latest change
+    pub time: f64
+}
+
+#[derive(Debug)]
+struct Transaction {
+    entry: HistoryEntry,
+    /// closes once changes of the current frame are recorded
+    closing: bool
+}
+
+/// Opt-in history of reactive data, see `FamiqPlugin::with_history`.
+///
+/// Every change is recorded except computed keys, they follow their dependencies.
+/// Changes made within one frame form one undo step, unless a transaction is open.
+#[derive(Resource, Debug)]
+pub struct RHistory {
+    max_entries: usize,
+    undo_stack: VecDeque<HistoryEntry>,
+    redo_stack: Vec<HistoryEntry>,
+    transaction: Option<Transaction>,
+    /// (name, position)
+    checkpoints: Vec<(String, usize)>,
+    /// entries dropped because of `max_entries`, keeps positions stable
+    dropped: usize,
+    /// (key, value) set by undo/redo, changes to exactly that value are not recorded
+    replayed: Vec<(String, RVal)>
+}
+
+impl RHistory {
+    /// History keeping at most `max_entries` undo steps.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.max(1),
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            transaction: None,
+            checkpoints: Vec::new(),
+            dropped: 0,
+            replayed: Vec::new()
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty() || self.transaction.as_ref().is_some_and(|t| !t.entry.changes.is_empty())
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Steps that can be undone, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.undo_stack.iter()
+    }
+
+    /// Steps that can be redone, next redo first.
+    pub fn undone_entries(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.redo_stack.iter().rev()
+    }
+
+    /// Number of steps applied since the history started, dropped ones included.
+    pub fn position(&self) -> usize {
+        self.dropped + self.undo_stack.len()
+    }
+
+    /// Group changes from this frame on into one undo step named `name`,
+    /// until `end_transaction` is called. An open transaction is committed first.
+    pub fn begin_transaction(&mut self, name: &str) {
+        self.commit_transaction();
+        self.transaction = Some(Transaction {
+            entry: HistoryEntry {
+                name: Some(name.to_string()),
+                changes: Vec::new(),
+                time: 0.0
+            },
+            closing: false
+        });
+    }
+
+    /// Close the open transaction. Changes of the current frame still belong to it.
+    pub fn end_transaction(&mut self) {
+        if let Some(transaction) = self.transaction.as_mut() {
+            transaction.closing = true;
+        }
+    }
+
+    /// Remember current position as `name`, see `travel_to`.
+    pub fn checkpoint(&mut self, name: &str) {
+        self.commit_transaction();
+        let position = self.position();
+        self.checkpoints.retain(|(n, _)| n != name);
+        self.checkpoints.push((name.to_string(), position));
+    }
+
+    /// Checkpoints and their positions.
+    pub fn checkpoints(&self) -> &[(String, usize)] {
+        &self.checkpoints
+    }
+
+    /// Undo the latest step. Returns false if there is nothing to undo.
+    pub fn undo(&mut self, r_data: &mut RData) -> bool {
+        self.commit_transaction();
+        let Some(entry) = self.undo_stack.pop_back() else {
+            return false;
+        };
+        for change in entry.changes.iter().rev() {
+            self.apply(r_data, &change.key, &change.old);
+        }
+        self.redo_stack.push(entry);
+        true
+    }
+
+    /// Redo the latest undone step. Returns false if there is nothing to redo.
+    pub fn redo(&mut self, r_data: &mut RData) -> bool {
+        self.commit_transaction();
+        let Some(entry) = self.redo_stack.pop() else {
+            return false;
+        };
+        for change in entry.changes.iter() {
+            self.apply(r_data, &change.key, &change.new);
+        }
+        self.undo_stack.push_back(entry);
+        true
+    }
+
+    /// Undo or redo until the position of checkpoint `name`.
+    /// Returns false if the checkpoint doesn't exist or its steps were dropped.
+    pub fn travel_to(&mut self, name: &str, r_data: &mut RData) -> bool {
+        self.commit_transaction();
+        let Some(target) = self.checkpoints.iter().find(|(n, _)| n == name).map(|(_, p)| *p) else {
+            return false;
+        };
+        while self.position() > target && self.undo(r_data) {}
+        while self.position() < target && self.redo(r_data) {}
+        self.position() == target
+    }
+
+    /// Drop all steps and checkpoints.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.transaction = None;
+        self.checkpoints.clear();
+        self.dropped = 0;
+    }
+
+    /// Human readable timeline, applied steps then undone ones.
+    ///
+    /// ```text
+    /// #1  0.52s  title: Untitled -> Level 1
+    /// #2  3.10s  [move player]  player.x: 0 -> 12, player.y: 0 -> 4
+    /// ----- current, checkpoints: saved
+    /// #3  4.00s  (undone)  player.hp: 100 -> 80
+    /// ```
+    pub fn timeline(&self) -> String {
+        let mut out = String::new();
+        let mut position = self.dropped;
+
+        for entry in self.undo_stack.iter() {
+            position += 1;
+            write_timeline_entry(&mut out, position, entry, false);
+        }
+        let checkpoints: Vec<&str> = self.checkpoints
+            .iter()
+            .filter(|(_, p)| *p == self.position())
+            .map(|(n, _)| n.as_str())
+            .collect();
+
+        if checkpoints.is_empty() {
+            out.push_str("----- current\n");
+        } else {
+            let _ = writeln!(out, "----- current, checkpoints: {}", checkpoints.join(", "));
+        }
+        for entry in self.redo_stack.iter().rev() {
+            position += 1;
+            write_timeline_entry(&mut out, position, entry, true);
+        }
+        out
+    }
+
+    fn record(&mut self, changes: Vec<ReactiveDataChanged>, time: f64) {
+        if let Some(transaction) = self.transaction.as_mut() {
+            if !changes.is_empty() {
+                transaction.entry.changes.extend(changes);
+                transaction.entry.time = time;
+            }
+            if transaction.closing {
+                self.commit_transaction();
+            }
+            return;
+        }
+        if !changes.is_empty() {
+            self.push(HistoryEntry { name: None, changes, time });
+        }
+    }
+
+    fn commit_transaction(&mut self) {
+        let Some(transaction) = self.transaction.take() else {
+            return;
+        };
+        if !transaction.entry.changes.is_empty() {
+            self.push(transaction.entry);
+        }
+    }
+
+    fn push(&mut self, entry: HistoryEntry) {
+        // a new change makes undone steps unreachable
+        self.redo_stack.clear();
+        let position = self.position();
+        self.checkpoints.retain(|(_, p)| *p <= position);
+
+        self.undo_stack.push_back(entry);
+        while self.undo_stack.len() > self.max_entries {
+            self.undo_stack.pop_front();
+            self.dropped += 1;
+        }
+        let dropped = self.dropped;
+        self.checkpoints.retain(|(_, p)| *p >= dropped);
+    }
+
+    fn apply(&mut self, r_data: &mut RData, key: &str, value: &RVal) {
+        r_data.mark_changed(key);
+
+        match get_reactive_value_mut(&mut r_data.data, key) {
+            Some(current) => *current = value.clone(),
+            None if !key.contains('.') => {
+                r_data.data.insert(key.to_string(), value.clone());
+            }
+            None => {}
+        }
+        self.replayed.retain(|(replayed_key, _)| replayed_key != key);
+        self.replayed.push((key.to_string(), value.clone()));
+    }
+}
+
+fn write_timeline_entry(out: &mut String, position: usize, entry: &HistoryEntry, undone: bool) {
+    let _ = write!(out, "#{}  {:.2}s  ", position, entry.time);
+    if undone {
+        out.push_str("(undone)  ");
+    }
+    if let Some(name) = entry.name.as_ref() {
+        let _ = write!(out, "[{}]  ", name);
+    }
+    let changes: Vec<String> = entry.changes
+        .iter()
+        .map(|c| format!("{}: {} -> {}", c.key, c.old.to_string(), c.new.to_string()))
+        .collect();
+    let _ = writeln!(out, "{}", changes.join(", "));
+}
+
+/// Undo/redo reactive data from systems. Requires history to be enabled.
+///
+/// ```text
+/// fn handle_shortcuts(keys: Res<ButtonInput<KeyCode>>, mut history: FaHistory) {
+///     if keys.pressed(KeyCode::ControlLeft) && keys.just_pressed(KeyCode::KeyZ) {
+///         history.undo();
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct FaHistory<'w> {
+    pub history: ResMut<'w, RHistory>,
+    pub reactive_data: ResMut<'w, RData>
+}
+
+impl FaHistory<'_> {
+    pub fn undo(&mut self) -> bool {
+        self.history.undo(&mut self.reactive_data)
+    }
+
+    pub fn redo(&mut self) -> bool {
+        self.history.redo(&mut self.reactive_data)
+    }
+
+    pub fn checkpoint(&mut self, name: &str) {
+        self.history.checkpoint(name);
+    }
+
+    pub fn travel_to(&mut self, name: &str) -> bool {
+        self.history.travel_to(name, &mut self.reactive_data)
+    }
+
+    pub fn begin_transaction(&mut self, name: &str) {
+        self.history.begin_transaction(name);
+    }
+
+    pub fn end_transaction(&mut self) {
+        self.history.end_transaction();
+    }
+}
+
+/// Change left once undo/redo writes of the same frame are taken out. A key also changed
+/// by the user after undo/redo is kept, starting from the value undo/redo wrote.
+fn unreplayed_change(change: &ReactiveDataChanged, replayed: &[(String, RVal)]) -> Option<ReactiveDataChanged> {
+    match replayed.iter().find(|(key, _)| *key == change.key) {
+        Some((_, value)) if *value == change.new => None,
+        Some((_, value)) => Some(ReactiveDataChanged { old: value.clone(), ..change.clone() }),
+        None => Some(change.clone())
+    }
+}
+
+/// Internal system to record changes of reactive data into history.
+pub(crate) fn record_reactive_history(
+    mut events: EventReader<ReactiveDataChanged>,
+    mut history: ResMut<RHistory>,
+    r_data: Res<RData>,
+    time: Res<Time>
+) {
+    let replayed = std::mem::take(&mut history.replayed);
+    let changes: Vec<ReactiveDataChanged> = events
+        .read()
+        // computed keys follow their dependencies, internal keys aren't user data
+        .filter(|e| !r_data.computed.contains_key(&e.key) && !is_internal_key(&e.key))
+        .filter_map(|e| unreplayed_change(e, &replayed))
+        .collect();
+
+    history.record(changes, time.elapsed_secs_f64());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(key: &str, old: i32, new: i32) -> ReactiveDataChanged {
+        ReactiveDataChanged {
+            key: key.to_string(),
+            old: RVal::Num(old),
+            new: RVal::Num(new)
+        }
+    }
+
+    fn data(count: i32) -> RData {
+        let mut r_data = RData::default();
+        r_data.data.insert("count".to_string(), RVal::Num(count));
+        r_data
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = RHistory::new(10);
+        let mut r_data = data(2);
+        history.record(vec![change("count", 0, 1)], 1.0);
+        history.record(vec![change("count", 1, 2)], 2.0);
+
+        assert!(history.undo(&mut r_data));
+        assert_eq!(r_data.data["count"], RVal::Num(1));
+        assert_eq!(r_data.changed_keys, vec!["count".to_string()]);
+
+        assert!(history.undo(&mut r_data));
+        assert!(!history.undo(&mut r_data));
+        assert_eq!(r_data.data["count"], RVal::Num(0));
+
+        assert!(history.redo(&mut r_data));
+        assert_eq!(r_data.data["count"], RVal::Num(1));
+
+        // new change drops undone steps
+        history.record(vec![change("count", 1, 5)], 3.0);
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_user_change_after_undo_is_kept() {
+        let mut history = RHistory::new(10);
+        let mut r_data = data(2);
+        history.record(vec![change("count", 0, 1)], 1.0);
+        history.record(vec![change("count", 1, 2)], 2.0);
+        history.undo(&mut r_data);
+
+        let replayed = std::mem::take(&mut history.replayed);
+        assert_eq!(None, unreplayed_change(&change("count", 2, 1), &replayed));
+        // user set count to 7 in the same frame as the undo
+        assert_eq!(Some(change("count", 1, 7)), unreplayed_change(&change("count", 2, 7), &replayed));
+        assert_eq!(Some(change("other", 0, 3)), unreplayed_change(&change("other", 0, 3), &replayed));
+    }
+
+    #[test]
+    fn test_transaction_groups_frames() {
+        let mut history = RHistory::new(10);
+        let mut r_data = data(3);
+        history.begin_transaction("drag");
+        history.record(vec![change("count", 0, 1)], 1.0);
+        history.record(vec![change("count", 1, 2)], 2.0);
+        history.end_transaction();
+        history.record(vec![change("count", 2, 3)], 3.0);
+
+        assert_eq!(history.entries().count(), 1);
+        assert_eq!(history.entries().next().unwrap().name.as_deref(), Some("drag"));
+
+        history.undo(&mut r_data);
+        assert_eq!(r_data.data["count"], RVal::Num(0));
+    }
+
+    #[test]
+    fn test_bounded_history_and_checkpoints() {
+        let mut history = RHistory::new(2);
+        let mut r_data = data(3);
+        history.checkpoint("start");
+        history.record(vec![change("count", 0, 1)], 1.0);
+        history.record(vec![change("count", 1, 2)], 2.0);
+        history.record(vec![change("count", 2, 3)], 3.0);
+
+        assert_eq!(history.entries().count(), 2);
+        // "start" is before the first step, which was dropped
+        assert!(!history.travel_to("start", &mut r_data));
+        assert_eq!(r_data.data["count"], RVal::Num(3));
+
+        let mut history = RHistory::new(10);
+        history.record(vec![change("count", 0, 1)], 1.0);
+        history.checkpoint("saved");
+        history.record(vec![change("count", 1, 2)], 2.0);
+        history.record(vec![change("count", 2, 3)], 3.0);
+
+        assert!(history.travel_to("saved", &mut r_data));
+        assert_eq!(r_data.data["count"], RVal::Num(1));
+        assert!(history.timeline().contains("----- current, checkpoints: saved"));
+    }
+}
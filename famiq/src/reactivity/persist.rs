@@ -0,0 +1,279 @@
+//! Save reactive data between runs.
+
+use bevy::prelude::*;
+use bevy::platform::collections::HashMap;
+
+use crate::errors::{FamiqError, FamiqErrorEvent, PersistErr};
+use super::{RData, RVal, ReactiveDataChanged, get_reactive_value, get_reactive_value_mut, is_internal_key, is_related_path};
+
+/// Persistent keys and their saved values, see `FamiqPlugin::with_persistence`.
+///
+/// Values are saved as JSON to `path`, or to local storage under `path` on web.
+#[derive(Resource, Debug)]
+pub struct RPersist {
+    pub path: String,
+    /// keys or paths marked as persistent
+    pub keys: Vec<String>,
+    /// values loaded from and saved to `path`, keys not marked in this run are kept
+    pub saved: HashMap<String, RVal>,
+    /// latest error while loading or saving
+    pub last_error: Option<PersistErr>
+}
+
+impl RPersist {
+    /// Load saved values from `path`. Missing file means nothing is saved yet.
+    pub fn load(path: &str) -> Self {
+        let mut persist = Self {
+            path: path.to_string(),
+            keys: Vec::new(),
+            saved: HashMap::new(),
+            last_error: None
+        };
+        match read_saved(path) {
+            Ok(Some(contents)) => match serde_json::from_str(&contents) {
+                Ok(saved) => persist.saved = saved,
+                Err(e) => persist.last_error = Some(PersistErr::Parse(e.to_string()))
+            },
+            Ok(None) => {}
+            Err(e) => persist.last_error = Some(e)
+        }
+        persist
+    }
+
+    /// Mark `key` as persistent and restore its saved value into `r_data`, if any.
+    /// Returns true if a value was restored. Internal keys are never persisted.
+    pub fn persist(&mut self, key: &str, r_data: &mut RData) -> bool {
+        if is_internal_key(key) {
+            return false;
+        }
+        if !self.keys.iter().any(|k| k == key) {
+            self.keys.push(key.to_string());
+        }
+        let Some(saved) = self.saved.get(key) else {
+            return false;
+        };
+        if get_reactive_value(&r_data.data, key) == Some(saved) {
+            return false;
+        }
+        r_data.mark_changed(key);
+
+        match get_reactive_value_mut(&mut r_data.data, key) {
+            Some(value) => *value = saved.clone(),
+            None if !key.contains('.') => {
+                r_data.data.insert(key.to_string(), saved.clone());
+            }
+            None => return false
+        }
+        true
+    }
+
+    /// Copy current values of persistent keys and write them to `path`.
+    pub fn save(&mut self, data: &HashMap<String, RVal>) -> Result<(), PersistErr> {
+        for key in self.keys.iter() {
+            if let Some(value) = get_reactive_value(data, key) {
+                self.saved.insert(key.clone(), value.clone());
+            }
+        }
+        let contents = serde_json::to_string_pretty(&self.saved)
+            .map_err(|e| PersistErr::Parse(e.to_string()))?;
+        write_saved(&self.path, &contents)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_saved(path: &str) -> Result<Option<String>, PersistErr> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(PersistErr::Read(e.to_string()))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_saved(path: &str, contents: &str) -> Result<(), PersistErr> {
+    std::fs::write(path, contents).map_err(|e| PersistErr::Write(e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Result<web_sys::Storage, PersistErr> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .ok_or_else(|| PersistErr::Read("local storage is not available".to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_saved(path: &str) -> Result<Option<String>, PersistErr> {
+    local_storage()?
+        .get_item(path)
+        .map_err(|e| PersistErr::Read(format!("{:?}", e)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write_saved(path: &str, contents: &str) -> Result<(), PersistErr> {
+    local_storage()?
+        .set_item(path, contents)
+        .map_err(|e| PersistErr::Write(format!("{:?}", e)))
+}
+
+/// Internal system to save persistent keys when any of them changes.
+pub(crate) fn save_persistent_data(
+    mut events: EventReader<ReactiveDataChanged>,
+    mut persist: ResMut<RPersist>,
+    mut errors: EventWriter<FamiqErrorEvent>,
+    r_data: Res<RData>
+) {
+    let changed = events
+        .read()
+        .any(|e| persist.keys.iter().any(|k| is_related_path(&e.key, k)));
+
+    if !changed {
+        return;
+    }
+    if let Err(e) = persist.save(&r_data.data) {
+        errors.write(FamiqErrorEvent(FamiqError::from(e.clone())));
+        persist.last_error = Some(e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::FamiqPlugin;
+    use crate::utils::create_test_app;
+    use crate::widgets::{FaQuery, FamiqBuilder, SetupWidget, builder_mut};
+    use crate::resources::FamiqResource;
+    use crate::widgets::text::{TextBuilder, IsFamiqText};
+    use crate::text;
+
+    fn setup_test_persisted_volume(mut fa_query: FaQuery, mut famiq_res: ResMut<FamiqResource>) {
+        fa_query.insert_num("volume", 50);
+        fa_query.persist("volume");
+
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        text!(text: "Volume: $[volume]");
+    }
+
+    fn run_mute(mut fa_query: FaQuery) {
+        fa_query.mutate_num("volume", 0);
+    }
+
+    fn test_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("famiq_persist_{}_{}.json", name, std::process::id()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    #[test]
+    fn test_rval_serde_round_trip() {
+        let mut stats = HashMap::new();
+        stats.insert("hp".to_string(), RVal::Num(80));
+        let value = RVal::Array(vec![
+            RVal::Map(stats),
+            RVal::FNum(0.5),
+            RVal::List(vec!["a".to_string()]),
+            RVal::None
+        ]);
+
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<RVal>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_save_and_restore() {
+        let path = test_path("restore");
+        let mut r_data = RData::default();
+        r_data.data.insert("volume".to_string(), RVal::Num(80));
+        r_data.data.insert("name".to_string(), RVal::Str("Kim".to_string()));
+
+        let mut persist = RPersist::load(&path);
+        assert!(persist.saved.is_empty());
+        persist.persist("volume", &mut r_data);
+        persist.save(&r_data.data).unwrap();
+
+        let mut r_data = RData::default();
+        r_data.data.insert("volume".to_string(), RVal::Num(50));
+
+        let mut persist = RPersist::load(&path);
+        assert!(persist.persist("volume", &mut r_data));
+        assert_eq!(r_data.data["volume"], RVal::Num(80));
+        assert!(persist.saved.get("name").is_none());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_invalid_file() {
+        let path = test_path("invalid");
+        std::fs::write(&path, "not json").unwrap();
+
+        let persist = RPersist::load(&path);
+        assert!(matches!(persist.last_error, Some(PersistErr::Parse(_))));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_save_failure_sends_error_event() {
+        let dir = test_path("missing_dir");
+        let path = format!("{dir}/saved.json");
+
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new().with_persistence(&path));
+        app.add_systems(Startup, setup_test_persisted_volume);
+        app.update();
+
+        app.add_systems(Update, run_mute);
+        app.update();
+
+        let events = app.world().resource::<Events<FamiqErrorEvent>>();
+        let errors: Vec<&FamiqErrorEvent> = events.iter_current_update_events().collect();
+        assert!(matches!(errors.as_slice(), [FamiqErrorEvent(FamiqError::Persist(PersistErr::Write(_)))]));
+        assert!(matches!(app.world().resource::<RPersist>().last_error, Some(PersistErr::Write(_))));
+    }
+
+    #[test]
+    fn test_load_failure_sends_error_event() {
+        let path = test_path("invalid_app");
+        std::fs::write(&path, "not json").unwrap();
+
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new().with_persistence(&path));
+        app.update();
+
+        let events = app.world().resource::<Events<FamiqErrorEvent>>();
+        let mut cursor = events.get_cursor();
+        assert!(matches!(
+            cursor.read(events).next(),
+            Some(FamiqErrorEvent(FamiqError::Persist(PersistErr::Parse(_))))
+        ));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_widgets_start_with_restored_value() {
+        let path = test_path("widgets");
+        std::fs::write(&path, r#"{ "volume": { "Num": 80 } }"#).unwrap();
+
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new().with_persistence(&path));
+        app.add_systems(Startup, setup_test_persisted_volume);
+        app.update();
+
+        let text = app.world_mut()
+            .query_filtered::<&Text, With<IsFamiqText>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(text.0, "Volume: 80");
+
+        app.add_systems(Update, run_mute);
+        app.update();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        let saved: HashMap<String, RVal> = serde_json::from_str(&saved).unwrap();
+        assert_eq!(saved["volume"], RVal::Num(0));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
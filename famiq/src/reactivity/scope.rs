@@ -0,0 +1,31 @@
+//! Scopes, reactive keys local to a widget subtree.
+//!
+//! Local keys of scope `p1` are stored in `RData` as a map under `__scope_p1`.
+//! Widgets built inside `container!(scope: "p1", ..)` resolve `$[hp]` to `__scope_p1.hp`
+//! if `hp` is local to `p1`, otherwise to the global `hp`. Each scope has its own paths,
+//! so subscribers of one scope aren't rebuilt when another scope changes.
+
+use bevy::platform::collections::HashMap;
+
+use super::RVal;
+
+/// Top-level reactive key holding local keys of `scope`.
+pub fn scope_key(scope: &str) -> String {
+    format!("__scope_{}", scope)
+}
+
+/// Reactive path of local `key` of `scope`, eg ("p1", "hp") -> "__scope_p1.hp".
+pub fn scoped_path(scope: &str, key: &str) -> String {
+    format!("{}.{}", scope_key(scope), key)
+}
+
+/// Template aliases pointing local keys of `scope` to their paths.
+pub fn scope_aliases(data: &HashMap<String, RVal>, scope: &str) -> Vec<(String, String)> {
+    match data.get(&scope_key(scope)) {
+        Some(RVal::Map(locals)) => locals
+            .keys()
+            .map(|key| (key.clone(), scoped_path(scope, key)))
+            .collect(),
+        _ => Vec::new()
+    }
+}
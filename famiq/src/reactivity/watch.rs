@@ -0,0 +1,273 @@
+//! Watchers, run one-shot systems when reactive data changes.
+
+use bevy::prelude::*;
+use bevy::ecs::system::SystemId;
+use bevy::platform::collections::HashMap;
+use std::time::Duration;
+
+use super::{RVal, is_related_path};
+
+/// Sent for every reactive key whose value changed.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ReactiveDataChanged {
+    pub key: String,
+    pub old: RVal,
+    pub new: RVal
+}
+
+/// When a watcher runs after its key changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WatchMode {
+    /// Run on every change.
+    Immediate,
+    /// Run once the key hasn't changed for the given duration.
+    Debounce(Duration),
+    /// Run at most once per given duration.
+    Throttle(Duration)
+}
+
+pub type WatcherSystemId = SystemId<In<ReactiveDataChanged>>;
+
+#[derive(Debug)]
+pub struct Watcher {
+    pub system: WatcherSystemId,
+    pub mode: WatchMode,
+    /// latest change of each key not yet passed to the system
+    pending: Vec<ReactiveDataChanged>,
+    last_change: f64,
+    last_run: Option<f64>
+}
+
+impl Watcher {
+    pub fn new(system: WatcherSystemId, mode: WatchMode) -> Self {
+        Self {
+            system,
+            mode,
+            pending: Vec::new(),
+            last_change: 0.0,
+            last_run: None
+        }
+    }
+
+    fn push_change(&mut self, change: &ReactiveDataChanged, now: f64) {
+        // changes of a key are coalesced, keeping the oldest value so the system sees
+        // the whole change. Different sub paths of a watched key are passed separately.
+        match self.pending.iter_mut().find(|pending| pending.key == change.key) {
+            Some(pending) => pending.new = change.new.clone(),
+            None => self.pending.push(change.clone())
+        }
+        self.last_change = now;
+    }
+
+    fn take_ready(&mut self, now: f64) -> Vec<ReactiveDataChanged> {
+        if self.pending.is_empty() {
+            return Vec::new();
+        }
+
+        let ready = match self.mode {
+            WatchMode::Immediate => true,
+            WatchMode::Debounce(wait) => now - self.last_change >= wait.as_secs_f64(),
+            WatchMode::Throttle(wait) => match self.last_run {
+                Some(last_run) => now - last_run >= wait.as_secs_f64(),
+                None => true
+            }
+        };
+        if !ready {
+            return Vec::new();
+        }
+        self.last_run = Some(now);
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Registered watchers, key is reactive key or path.
+#[derive(Resource, Default, Debug)]
+pub struct RWatchers {
+    pub data: HashMap<String, Vec<Watcher>>
+}
+
+impl RWatchers {
+    pub fn add(&mut self, key: &str, system: WatcherSystemId, mode: WatchMode) {
+        self.data
+            .entry(key.to_string())
+            .or_default()
+            .push(Watcher::new(system, mode));
+    }
+
+    /// Remove watcher by its system id.
+    pub fn remove(&mut self, system: WatcherSystemId) {
+        for watchers in self.data.values_mut() {
+            watchers.retain(|w| w.system != system);
+        }
+        self.data.retain(|_, watchers| !watchers.is_empty());
+    }
+}
+
+/// Register a watcher from `World`.
+pub fn add_watcher<M>(
+    world: &mut World,
+    key: &str,
+    mode: WatchMode,
+    system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+) -> WatcherSystemId {
+    let system_id = world.register_system(system);
+    world.get_resource_or_init::<RWatchers>().add(key, system_id, mode);
+    system_id
+}
+
+/// Watch reactive data from `App`.
+///
+/// ```rust, no_run
+/// use bevy::prelude::*;
+/// use famiq::prelude::*;
+///
+/// fn on_volume_changed(In(change): In<ReactiveDataChanged>) {
+///     println!("volume changed from {:?} to {:?}", change.old, change.new);
+/// }
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(FamiqPlugin::new())
+///     .watch("volume", on_volume_changed)
+///     .run();
+/// ```
+pub trait ReactiveWatchExt {
+    /// Run `system` every time `key` changes.
+    fn watch<M>(
+        &mut self,
+        key: &str,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+    ) -> &mut Self;
+
+    /// Run `system` once `key` stops changing for `wait`.
+    fn watch_debounced<M>(
+        &mut self,
+        key: &str,
+        wait: Duration,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+    ) -> &mut Self;
+
+    /// Run `system` at most once per `wait` while `key` keeps changing.
+    fn watch_throttled<M>(
+        &mut self,
+        key: &str,
+        wait: Duration,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+    ) -> &mut Self;
+}
+
+impl ReactiveWatchExt for App {
+    fn watch<M>(
+        &mut self,
+        key: &str,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+    ) -> &mut Self {
+        add_watcher(self.world_mut(), key, WatchMode::Immediate, system);
+        self
+    }
+
+    fn watch_debounced<M>(
+        &mut self,
+        key: &str,
+        wait: Duration,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+    ) -> &mut Self {
+        add_watcher(self.world_mut(), key, WatchMode::Debounce(wait), system);
+        self
+    }
+
+    fn watch_throttled<M>(
+        &mut self,
+        key: &str,
+        wait: Duration,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + 'static
+    ) -> &mut Self {
+        add_watcher(self.world_mut(), key, WatchMode::Throttle(wait), system);
+        self
+    }
+}
+
+/// Internal system to pass changes to watchers and run the ones that are ready.
+pub(crate) fn run_reactive_watchers(
+    mut commands: Commands,
+    mut events: EventReader<ReactiveDataChanged>,
+    mut watchers: ResMut<RWatchers>,
+    time: Res<Time>
+) {
+    let now = time.elapsed_secs_f64();
+
+    for e in events.read() {
+        for (key, key_watchers) in watchers.data.iter_mut() {
+            if !is_related_path(&e.key, key) {
+                continue;
+            }
+            key_watchers.iter_mut().for_each(|w| w.push_change(e, now));
+        }
+    }
+
+    for key_watchers in watchers.data.values_mut() {
+        for watcher in key_watchers.iter_mut() {
+            for change in watcher.take_ready(now) {
+                commands.run_system_with(watcher.system, change);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_watcher(mode: WatchMode) -> Watcher {
+        let mut world = World::new();
+        let system = world.register_system(|_: In<ReactiveDataChanged>| {});
+        Watcher::new(system, mode)
+    }
+
+    fn change(old: i32, new: i32) -> ReactiveDataChanged {
+        ReactiveDataChanged {
+            key: "count".to_string(),
+            old: RVal::Num(old),
+            new: RVal::Num(new)
+        }
+    }
+
+    #[test]
+    fn test_debounce_watcher() {
+        let mut watcher = test_watcher(WatchMode::Debounce(Duration::from_millis(500)));
+
+        watcher.push_change(&change(0, 1), 1.0);
+        watcher.push_change(&change(1, 2), 1.2);
+        assert_eq!(watcher.take_ready(1.5), vec![]);
+        assert_eq!(watcher.take_ready(1.7), vec![change(0, 2)]);
+        assert_eq!(watcher.take_ready(2.5), vec![]);
+    }
+
+    #[test]
+    fn test_throttle_watcher() {
+        let mut watcher = test_watcher(WatchMode::Throttle(Duration::from_millis(500)));
+
+        watcher.push_change(&change(0, 1), 1.0);
+        assert_eq!(watcher.take_ready(1.0), vec![change(0, 1)]);
+
+        watcher.push_change(&change(1, 2), 1.1);
+        watcher.push_change(&change(2, 3), 1.2);
+        assert_eq!(watcher.take_ready(1.2), vec![]);
+        assert_eq!(watcher.take_ready(1.5), vec![change(1, 3)]);
+    }
+
+    #[test]
+    fn test_debounce_watcher_keeps_changes_of_each_path() {
+        let mut watcher = test_watcher(WatchMode::Debounce(Duration::from_millis(500)));
+        let path_change = |key: &str, old: RVal, new: RVal| ReactiveDataChanged { key: key.to_string(), old, new };
+
+        watcher.push_change(&path_change("player.hp", RVal::Num(100), RVal::Num(90)), 1.0);
+        watcher.push_change(&path_change("player.name", RVal::Str("Kim".into()), RVal::Str("Hong".into())), 1.1);
+        watcher.push_change(&path_change("player.hp", RVal::Num(90), RVal::Num(80)), 1.2);
+
+        assert_eq!(watcher.take_ready(1.7), vec![
+            path_change("player.hp", RVal::Num(100), RVal::Num(80)),
+            path_change("player.name", RVal::Str("Kim".into()), RVal::Str("Hong".into()))
+        ]);
+    }
+}
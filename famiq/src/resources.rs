@@ -138,6 +138,9 @@ pub struct JsonStyleAssetLoader;
 pub enum JsonStyleAssetLoaderError {
     #[error("Could not load asset: {0}")]
     Io(#[from] std::io::Error),
+
+    #[error("Invalid styles: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 impl AssetLoader for JsonStyleAssetLoader {
@@ -153,7 +156,7 @@ impl AssetLoader for JsonStyleAssetLoader {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
 
-        let json_asset = serde_json::from_slice::<JsonStyleAsset>(&bytes).unwrap();
+        let json_asset = serde_json::from_slice::<JsonStyleAsset>(&bytes)?;
         Ok(json_asset)
     }
 
@@ -166,6 +169,8 @@ impl AssetLoader for JsonStyleAssetLoader {
 pub struct JsonStyleAssetState {
     pub initial_loaded: bool,
     pub fully_loaded: bool,
+    /// styles asset failed to load, reported already
+    pub failed: bool,
     pub style_handle: Handle<JsonStyleAsset>,
     pub font_handle: Handle<Font>
 }
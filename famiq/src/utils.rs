@@ -2,6 +2,10 @@ use bevy::picking::backend::PointerHits;
 use bevy::platform::collections::{HashMap, HashSet};
 use bevy::ui::UiStack;
 use bevy::window::WindowResized;
+#[cfg(test)]
+use bevy::input::{keyboard::{Key, KeyboardInput}, ButtonState, InputPlugin};
+#[cfg(test)]
+use bevy::ecs::system::ScheduleSystem;
 use bevy::asset::{io::AssetSourceId, AssetPath, AssetPlugin};
 use bevy::prelude::*;
 use cosmic_text::Color as CosmicColor;
@@ -10,15 +14,14 @@ use std::fs::File;
 use std::io::Read;
 use regex::Regex;
 use once_cell::sync::Lazy;
-use std::any::Any;
 
 use crate::plugin::{CursorIcons, CursorType};
 use crate::widgets::{style_parse::*, ReactiveModelKey, WidgetAttributes};
 use crate::widgets::{WidgetStyle, DefaultWidgetConfig, WidgetColor, WidgetSize};
 use crate::widgets::color::*;
-use crate::reactivity::RVal;
+use crate::reactivity::{RVal, IntoRVal, ReactiveBinding, BindingTarget, eval_expression, expression_keys};
 use crate::errors::*;
-use crate::widgets::{WidgetId, WidgetClasses, TooltipEntity, IsFamiqTooltip};
+use crate::widgets::{WidgetId, WidgetClasses, TooltipEntity, IsFamiqTooltip, resolve_template_alias};
 
 pub(crate) fn read_styles_json_file(path: &str) -> Result<HashMap<String, WidgetStyle>, StylesFileError> {
     let mut file = match File::open(path) {
@@ -120,6 +123,31 @@ pub fn create_test_app() -> App {
     app
 }
 
+/// Test app with famiq & input plugins, `setup` is run on the first update.
+#[cfg(test)]
+pub(crate) fn create_famiq_test_app<M>(setup: impl IntoScheduleConfigs<ScheduleSystem, M>) -> App {
+    let mut app = create_test_app();
+    app.add_plugins(InputPlugin);
+    app.add_plugins(crate::FamiqPlugin::new());
+    app.add_systems(Startup, setup);
+    app.update();
+    app
+}
+
+/// Send a key press to the test app & run one update.
+#[cfg(test)]
+pub(crate) fn press_key(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER
+    });
+    app.update();
+}
+
 pub fn mask_string(input: &str) -> String {
     "*".repeat(input.len())
 }
@@ -361,6 +389,28 @@ pub(crate) fn _change_cursor_icon(
     });
 }
 
+/// Observer showing pointer cursor while hovering an entity.
+pub(crate) fn on_pointer_cursor_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Pointer);
+    trigger.propagate(false);
+}
+
+/// Observer restoring default cursor, pair of [`on_pointer_cursor_over`].
+pub(crate) fn on_pointer_cursor_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut commands: Commands,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Default);
+    trigger.propagate(false);
+}
+
 /// Turn WigetColor to actual color
 pub(crate) fn get_color(color: &WidgetColor) -> Color {
     match color {
@@ -495,11 +545,14 @@ pub static REACTIVE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\$\[(.*?)\]")
 
 /// Extract reactive key(s) from string.
 /// Eg, "Hello $[some_thing]" -> "some_thing".
+///
+/// Paths are returned as is, "HP: $[player.stats.hp]" -> "player.stats.hp".
+/// For expressions, keys used by them are returned, "$[hp / max_hp * 100]" -> "hp", "max_hp".
 pub fn get_reactive_key(text: &str) -> Vec<String> {
     let mut keys = HashSet::new();
     for capture in REACTIVE_REGEX.captures_iter(text) {
         if let Some(matched) = capture.get(1) {
-            keys.insert(matched.as_str().to_string());
+            keys.extend(expression_keys(matched.as_str()));
         }
     }
     keys.into_iter().collect()
@@ -507,22 +560,35 @@ pub fn get_reactive_key(text: &str) -> Vec<String> {
 
 /// Example, key "color", value: "blue", text: "This is $[color]".
 /// result: "This is blue".
+///
+/// Keys can be paths into `RVal::Map` or `RVal::Array`, eg "$[items.0.name]",
+/// or expressions using them, eg "$[price * qty | fixed(2)]".
+/// Only expressions using any of `reactive_keys` (or no key at all) are replaced,
+/// expressions failing to evaluate are kept as is.
 pub fn replace_reactive_keys(
     old_text: &str,
-    reactive_keys: &Vec<String>,
+    reactive_keys: &[String],
     reactive_data: &HashMap<String, RVal>
 ) -> String {
-    let mut new_text = old_text.to_string();
-
-    for key in reactive_keys {
-        let placeholder = format!("$[{}]", key);
-        if let Some(value) = reactive_data.get(key) {
-            new_text = new_text.replace(&placeholder, &value.to_string());
-        }
-    }
-    new_text
+    REACTIVE_REGEX
+        .replace_all(old_text, |caps: &regex::Captures| {
+            let keys = expression_keys(&caps[1]);
+            if !keys.is_empty() && !keys.iter().any(|k| reactive_keys.contains(k)) {
+                return caps[0].to_string();
+            }
+            match eval_expression(&caps[1], reactive_data) {
+                Ok(value) => value.to_string(),
+                Err(_) => caps[0].to_string()
+            }
+        })
+        .into_owned()
 }
 
+/// Replace reactive keys in common attributes.
+///
+/// Keys of `class`, `width`, `height` and `display`, and of `color` if `attr.bind_color`,
+/// are recorded as bindings in `attr.bindings`, the rest are pushed to `all_reactive_keys`
+/// which rebuild the whole widget.
 pub fn replace_reactive_keys_common_attrs(
     attr: &mut WidgetAttributes,
     reactive_data: &HashMap<String, RVal>,
@@ -530,8 +596,10 @@ pub fn replace_reactive_keys_common_attrs(
 ) {
     if let Some(class) = attr.class.as_mut() {
         let class_r_keys = get_reactive_key(class);
+        if !class_r_keys.is_empty() {
+            attr.bindings.push(ReactiveBinding::new(BindingTarget::Class, class, None));
+        }
         *class = replace_reactive_keys(class, &class_r_keys, reactive_data);
-        all_reactive_keys.extend(class_r_keys);
     }
 
     if let Some(id) = attr.id.as_mut() {
@@ -542,8 +610,10 @@ pub fn replace_reactive_keys_common_attrs(
 
     if let Some(width) = attr.width.as_mut() {
         let width_r_keys = get_reactive_key(width);
+        if !width_r_keys.is_empty() {
+            attr.bindings.push(ReactiveBinding::new(BindingTarget::Width, width, None));
+        }
         *width = replace_reactive_keys(width, &width_r_keys, reactive_data);
-        all_reactive_keys.extend(width_r_keys);
 
         if let Some(parsed_width) = parse_val(&width) {
             attr.node.width = parsed_width;
@@ -552,8 +622,10 @@ pub fn replace_reactive_keys_common_attrs(
 
     if let Some(height) = attr.height.as_mut() {
         let height_r_keys = get_reactive_key(height);
+        if !height_r_keys.is_empty() {
+            attr.bindings.push(ReactiveBinding::new(BindingTarget::Height, height, None));
+        }
         *height = replace_reactive_keys(height, &height_r_keys, reactive_data);
-        all_reactive_keys.extend(height_r_keys);
 
         if let Some(parsed_height) = parse_val(&height) {
             attr.node.height = parsed_height;
@@ -562,8 +634,10 @@ pub fn replace_reactive_keys_common_attrs(
 
     if let Some(display) = attr.display.as_mut() {
         let display_r_keys = get_reactive_key(display);
+        if !display_r_keys.is_empty() {
+            attr.bindings.push(ReactiveBinding::new(BindingTarget::Display, display, None));
+        }
         *display = replace_reactive_keys(display, &display_r_keys, reactive_data);
-        all_reactive_keys.extend(display_r_keys);
 
         if let Some(parsed_display) = parse_display(&display) {
             attr.node.display = parsed_display;
@@ -572,8 +646,14 @@ pub fn replace_reactive_keys_common_attrs(
 
     if let WidgetColor::Custom(ref mut c) = attr.color {
         let color_r_keys = get_reactive_key(c);
+        let bind_color = attr.bind_color && !color_r_keys.is_empty();
+        if bind_color {
+            attr.bindings.push(ReactiveBinding::new(BindingTarget::Color, c, None));
+        }
         *c = replace_reactive_keys(c, &color_r_keys, reactive_data);
-        all_reactive_keys.extend(color_r_keys);
+        if !bind_color {
+            all_reactive_keys.extend(color_r_keys);
+        }
     }
 
     if attr.has_tooltip {
@@ -583,51 +663,15 @@ pub fn replace_reactive_keys_common_attrs(
     }
 }
 
-pub trait TypeName {
-    fn type_name() -> &'static str;
-}
-
-impl TypeName for f32 {
-    fn type_name() -> &'static str { "f32" }
-}
-
-impl TypeName for bool {
-    fn type_name() -> &'static str { "boolean" }
-}
-
-impl TypeName for &str {
-    fn type_name() -> &'static str { "&str" }
-}
-
-impl TypeName for Vec<String> {
-    fn type_name() -> &'static str { "Vec<String>" }
-}
-
-impl TypeName for [&str] {
-    fn type_name() -> &'static str { "[&str]" }
-}
-
-pub fn check_val_type<T: TypeName>(_val: &T) -> &'static str {
-    T::type_name()
-}
-
-pub fn to_rval<T: Any + ToString + TypeName>(value: T) -> Result<RVal, ToRValErr> {
-    match check_val_type(&value) {
-        "boolean" => {
-            let any = &value as &dyn Any;
-            match any.downcast_ref::<bool>() {
-                Some(val) => Ok(RVal::Bool(*val)),
-                None => Err(ToRValErr::ConvertToRValFail)
-            }
-        }
-        "f32" => {
-            let any = &value as &dyn Any;
-            match any.downcast_ref::<f32>() {
-                Some(val) => Ok(RVal::FNum(*val)),
-                None => Err(ToRValErr::ConvertToRValFail)
-            }
-        }
-        "&str" => Ok(RVal::Str(value.to_string())),
+/// Convert value of a widget attribute into `RVal`.
+///
+/// Attributes accept `bool`, `f32` and strings, which may be reactive templates.
+pub fn to_rval<T: IntoRVal>(value: T) -> Result<RVal, ToRValErr> {
+    match value.into_rval() {
+        RVal::Str(v) => Ok(RVal::Str(resolve_template_alias(&v))),
+        v @ (RVal::Bool(_) | RVal::FNum(_)) => Ok(v),
+        // unsuffixed float literals are f64
+        RVal::FNum64(v) => Ok(RVal::FNum(v as f32)),
         _ => Err(ToRValErr::UnsupportedType)
     }
 }
@@ -29,7 +29,7 @@ impl ButtonBuilder {
         let mut attributes = WidgetAttributes::default();
         attributes.font_handle = Some(font_handle.clone());
         Self {
-            value,
+            value: resolve_template_alias(&value),
             attributes,
             all_reactive_keys: Vec::new(),
             cloned_attrs: WidgetAttributes::default(),
@@ -40,13 +40,22 @@ impl ButtonBuilder {
     pub fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) -> String {
         let reactive_keys = get_reactive_key(&self.value);
         let parsed_text = replace_reactive_keys(&self.value, &reactive_keys, r_data);
-        self.all_reactive_keys.extend_from_slice(&reactive_keys);
 
         self.cloned_attrs = self.attributes.clone();
         self.cloned_attrs.node = default_button_node();
+        self.cloned_attrs.bind_color = true;
         replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
         parsed_text
     }
+
+    /// Bind button's text to its reactive keys, only the text gets patched when they change.
+    pub(crate) fn bind_text(&mut self, text_entity: Entity) {
+        if !get_reactive_key(&self.value).is_empty() {
+            self.cloned_attrs.bindings.push(
+                ReactiveBinding::new(BindingTarget::Text, &self.value, Some(text_entity))
+            );
+        }
+    }
 }
 
 impl SetupWidget for ButtonBuilder {
@@ -63,6 +72,7 @@ impl SetupWidget for ButtonBuilder {
         let mut text = FaBaseText::new_with_attributes(&parsed_text,  &self.cloned_attrs);
         let text_entity = text.build(r_data, commands);
         self.old_text_entity = Some(text_entity);
+        self.bind_text(text_entity);
 
         let mut button = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
         let button_entity = button.build(r_data, commands);
@@ -103,6 +113,7 @@ impl SetupWidget for ButtonBuilder {
         let parsed_text = self.prepare_attrs(r_data);
         let mut text = FaBaseText::new_with_attributes(&parsed_text,  &self.cloned_attrs);
         text.rebuild(r_data, self.old_text_entity.unwrap(), world);
+        self.bind_text(self.old_text_entity.unwrap());
 
         let mut button = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
         button.rebuild(r_data, old_entity, world);
@@ -126,7 +137,7 @@ impl SetupWidget for ButtonBuilder {
 /// Macro for creating a button.
 #[macro_export]
 macro_rules! button {
-    ( text: $text:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+    ( @widget text: $text:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let btn_builder = &mut ButtonBuilder::new($text.to_string(), &famiq_builder.get_font_handle());
         $(
@@ -137,6 +148,11 @@ macro_rules! button {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(button [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -191,7 +191,7 @@ impl CheckboxBuilder {
                 let reactive_keys = get_reactive_key(&v);
 
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::Bool(state) => self.set_flex_direction(*state),
                             _ => {}
@@ -272,7 +272,7 @@ impl SetupWidget for CheckboxBuilder {
 /// Macro for creating a checkbox.
 #[macro_export]
 macro_rules! checkbox {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let c_builder = &mut CheckboxBuilder::new(&famiq_builder.get_font_handle());
         $(
@@ -283,6 +283,11 @@ macro_rules! checkbox {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(checkbox [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
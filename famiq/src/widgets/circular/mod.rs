@@ -59,7 +59,7 @@ impl CircularBuilder {
                 let reactive_keys = get_reactive_key(&v);
 
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::FNum(size) => self.cloned_attrs.size = WidgetSize::Custom(*size),
                             _ => {}
@@ -153,7 +153,7 @@ impl SetupWidget for CircularBuilder {
 /// Macro for creating a spinning circular.
 #[macro_export]
 macro_rules! circular {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let c_builder = &mut CircularBuilder::new();
         $(
@@ -164,6 +164,11 @@ macro_rules! circular {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(circular [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
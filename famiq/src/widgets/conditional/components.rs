@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use std::sync::Arc;
+use std::fmt;
+
+/// Marker component for identifying a Famiq conditional.
+#[derive(Component)]
+pub struct IsFamiqConditional;
+
+/// Closure building a branch of a conditional.
+#[derive(Clone)]
+pub struct BranchTemplate(pub Arc<dyn Fn() -> Entity + Send + Sync>);
+
+impl fmt::Debug for BranchTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BranchTemplate")
+    }
+}
+
+/// Lives on an invisible anchor entity, the shown branch is spawned right after it
+/// within the same parent so conditionals don't add a layout node of their own.
+#[derive(Component, Debug)]
+#[component(on_remove = despawn_conditional_branch)]
+pub struct FaConditional {
+    /// reactive key or expression, eg. `logged_in` or `!is_loading`
+    pub condition: String,
+    pub then_branch: BranchTemplate,
+    pub else_branch: Option<BranchTemplate>,
+    /// root entity of the branch currently shown
+    pub branch: Option<Entity>,
+    pub(crate) shown: Option<bool>,
+    pub(crate) aliases: Vec<(String, String)>
+}
+
+/// The branch is a sibling of the anchor, despawn it along with the anchor.
+fn despawn_conditional_branch(mut world: DeferredWorld, context: HookContext) {
+    let branch = world
+        .get::<FaConditional>(context.entity)
+        .and_then(|conditional| conditional.branch);
+
+    if let Some(branch) = branch {
+        world.commands().entity(branch).try_despawn();
+    }
+}
@@ -0,0 +1,104 @@
+pub mod components;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use std::sync::Arc;
+
+use crate::widgets::*;
+
+#[derive(Clone, Debug)]
+pub struct ConditionalBuilder {
+    pub condition: String,
+    pub then_branch: BranchTemplate,
+    pub else_branch: Option<BranchTemplate>,
+    /// aliases of outer lists, branches are built later with them
+    pub(crate) aliases: Vec<(String, String)>
+}
+
+impl ConditionalBuilder {
+    pub fn new(condition: &str, then_branch: impl Fn() -> Entity + Send + Sync + 'static) -> Self {
+        Self {
+            condition: resolve_expression_alias(condition),
+            then_branch: BranchTemplate(Arc::new(then_branch)),
+            else_branch: None,
+            aliases: current_template_aliases()
+        }
+    }
+
+    pub fn set_else(&mut self, else_branch: impl Fn() -> Entity + Send + Sync + 'static) {
+        self.else_branch = Some(BranchTemplate(Arc::new(else_branch)));
+    }
+
+    /// Spawn the anchor. The branch is spawned by `update_conditional_system`,
+    /// once the anchor has its parent.
+    pub fn build(&mut self, commands: &mut Commands) -> Entity {
+        commands
+            .spawn((
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+                IsFamiqConditional,
+                FaConditional {
+                    condition: self.condition.clone(),
+                    then_branch: self.then_branch.clone(),
+                    else_branch: self.else_branch.clone(),
+                    branch: None,
+                    shown: None,
+                    aliases: self.aliases.clone()
+                }
+            ))
+            .id()
+    }
+}
+
+/// Macro for spawning one of two subtrees depending on a reactive key or expression.
+/// The hidden branch is despawned, not just hidden.
+///
+/// ```text
+/// if_else!(
+///     condition: "logged_in",
+///     then: || text!(text: "Welcome $[name]"),
+///     else: || button!(text: "Log in")
+/// );
+/// ```
+#[macro_export]
+macro_rules! if_else {
+    ( condition: $condition:expr, then: $then:expr $(, else: $otherwise:expr )? $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let c_builder = &mut $crate::widgets::conditional::ConditionalBuilder::new($condition, $then);
+        $(
+            c_builder.set_else($otherwise);
+        )?
+        c_builder.build(&mut famiq_builder.ui_root_node.commands())
+    }};
+}
+
+/// Used by widget macros to support `show_if` attribute. Looks for `show_if`
+/// among the attributes, if found the widget gets built inside `if_else!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! show_if_widget {
+    ( $widget:ident [ $( $done:tt )* ] show_if : $condition:tt $(, $( $rest:tt )* )? ) => {
+        $crate::if_else!(
+            condition: $condition,
+            then: move || $crate::$widget!(@widget $( $done )* $( $( $rest )* )?)
+        )
+    };
+
+    ( $widget:ident [ $( $done:tt )* ] $next:tt $( $rest:tt )* ) => {
+        $crate::show_if_widget!($widget [ $( $done )* $next ] $( $rest )*)
+    };
+
+    ( $widget:ident [ $( $done:tt )* ] ) => {
+        $crate::$widget!(@widget $( $done )*)
+    };
+}
+
+pub fn can_run_conditional_systems(q: Query<&IsFamiqConditional>) -> bool {
+    !q.is_empty()
+}
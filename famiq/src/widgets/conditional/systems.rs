@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use crate::reactivity::*;
+use crate::widgets::*;
+use super::*;
+
+/// Internal system to spawn or despawn branches of conditionals when their condition changes.
+pub(crate) fn update_conditional_system(
+    mut fa_query: FaQuery,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut conditional_q: Query<(Entity, &mut FaConditional, Option<&ChildOf>)>,
+    children_q: Query<&Children>
+) {
+    let has_new_conditional = conditional_q.iter().any(|(_, c, _)| c.shown.is_none());
+    if !fa_query.reactive_data.is_changed() && !has_new_conditional {
+        return;
+    }
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+
+    for (anchor, mut conditional, parent) in conditional_q.iter_mut() {
+        let show = eval_condition(&conditional.condition, &fa_query.reactive_data.data);
+        if conditional.shown == Some(show) {
+            continue;
+        }
+        conditional.shown = Some(show);
+
+        if let Some(branch) = conditional.branch.take() {
+            let mut removed: Vec<Entity> = children_q.iter_descendants(branch).collect();
+            removed.push(branch);
+
+            fa_query.commands.entity(branch).despawn();
+            fa_query.reactive_subscriber.remove_entities(&removed);
+        }
+
+        let template = if show {
+            Some(conditional.then_branch.clone())
+        } else {
+            conditional.else_branch.clone()
+        };
+        let Some(template) = template else {
+            continue;
+        };
+        let branch = with_template_aliases(&conditional.aliases, || (template.0)());
+
+        if let Some(parent) = parent {
+            let index = children_q
+                .get(parent.parent())
+                .ok()
+                .and_then(|children| children.iter().position(|c| c == anchor))
+                .map(|i| i + 1)
+                .unwrap_or(0);
+
+            fa_query.commands.entity(parent.parent()).insert_children(index, &[branch]);
+        }
+        conditional.branch = Some(branch);
+    }
+}
@@ -0,0 +1,185 @@
+#![cfg(test)]
+
+use crate::utils::create_test_app;
+use crate::FamiqPlugin;
+use crate::widgets::container::*;
+use crate::widgets::for_each::*;
+use crate::widgets::text::*;
+use crate::widgets::{FamiqResource, builder_mut};
+use crate::{container, for_each, text};
+use super::*;
+
+fn setup_test_if_else(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_bool("logged_in", false);
+    fa_query.insert_str("name", "Kim");
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    container!(children: [
+        crate::if_else!(
+            condition: "logged_in",
+            then: || text!(text: "Welcome $[name]"),
+            else: || text!(text: "Please log in")
+        )
+    ]);
+}
+
+fn setup_test_show_if(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_bool("flag", false);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    container!(children: [
+        text!(text: "first"),
+        text!(text: "second", show_if: "flag"),
+        text!(show_if: "!flag", text: "not second"),
+        text!(text: "third")
+    ]);
+}
+
+fn setup_test_show_if_in_for_each(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    let todo = |name: &str, done: bool| {
+        let mut map = HashMap::new();
+        map.insert("name".to_string(), RVal::Str(name.to_string()));
+        map.insert("done".to_string(), RVal::Bool(done));
+        RVal::Map(map)
+    };
+    fa_query.insert_array("todos", vec![todo("one", true), todo("two", false)]);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    container!(children: [
+        for_each!(
+            list: "todos",
+            row: |_| text!(text: "$[item.name]", show_if: "item.done")
+        )
+    ]);
+}
+
+fn run_log_in(mut fa_query: FaQuery) {
+    fa_query.mutate_bool("logged_in", true);
+}
+
+fn run_toggle_flag(mut fa_query: FaQuery) {
+    fa_query.mutate_bool("flag", true);
+}
+
+fn run_finish_second_todo(mut fa_query: FaQuery) {
+    fa_query.mutate_data("todos.1.done", RVal::Bool(true));
+}
+
+fn run_remove_todos(mut fa_query: FaQuery) {
+    fa_query.mutate_data("todos", RVal::Array(Vec::new()));
+}
+
+/// Texts shown inside the first container, in order.
+fn shown_texts(app: &mut App) -> Vec<String> {
+    let children = app.world_mut()
+        .query_filtered::<&Children, With<IsFamiqContainer>>()
+        .single(app.world())
+        .map(|c| c.to_vec())
+        .unwrap_or_default();
+
+    let mut texts = Vec::new();
+    let mut to_visit = children;
+    to_visit.reverse();
+
+    while let Some(entity) = to_visit.pop() {
+        if let Some(text) = app.world().get::<Text>(entity) {
+            texts.push(text.0.clone());
+        }
+        if let Some(children) = app.world().get::<Children>(entity) {
+            to_visit.extend(children.iter().rev());
+        }
+    }
+    texts
+}
+
+#[test]
+fn test_if_else() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_if_else);
+    app.update();
+
+    assert_eq!(vec!["Please log in".to_string()], shown_texts(&mut app));
+
+    let else_entity = app.world_mut()
+        .query::<&FaConditional>()
+        .single(app.world())
+        .unwrap()
+        .branch
+        .unwrap();
+
+    app.add_systems(Update, run_log_in);
+    app.update();
+    app.update();
+
+    assert_eq!(vec!["Welcome Kim".to_string()], shown_texts(&mut app));
+    assert!(app.world().get_entity(else_entity).is_err());
+
+    let subscriber = app.world().resource::<RSubscriber>();
+    assert!(subscriber.data.values().all(|s| !s.contains_key(&else_entity)));
+}
+
+#[test]
+fn test_show_if_keeps_position() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_show_if);
+    app.update();
+
+    assert_eq!(
+        vec!["first".to_string(), "not second".to_string(), "third".to_string()],
+        shown_texts(&mut app)
+    );
+
+    app.add_systems(Update, run_toggle_flag);
+    app.update();
+
+    assert_eq!(
+        vec!["first".to_string(), "second".to_string(), "third".to_string()],
+        shown_texts(&mut app)
+    );
+}
+
+#[test]
+fn test_show_if_in_for_each() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_show_if_in_for_each);
+    app.update();
+    app.update();
+
+    assert_eq!(vec!["one".to_string()], shown_texts(&mut app));
+
+    app.add_systems(Update, run_finish_second_todo);
+    app.update();
+
+    assert_eq!(vec!["one".to_string(), "two".to_string()], shown_texts(&mut app));
+}
+
+#[test]
+fn test_show_if_branch_despawned_with_row() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_show_if_in_for_each);
+    app.update();
+    app.update();
+
+    app.add_systems(Update, run_remove_todos);
+    app.update();
+
+    assert!(shown_texts(&mut app).is_empty());
+    let texts = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqText>>()
+        .iter(app.world())
+        .count();
+    assert_eq!(0, texts);
+}
@@ -54,6 +54,7 @@ impl ContainerBuilder {
             self.cloned_attrs.color = WidgetColor::Transparent;
         }
         self.cloned_attrs.default_visibility = Visibility::Visible;
+        self.cloned_attrs.bind_color = true;
         replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
     }
 }
@@ -119,14 +120,27 @@ impl SetupWidget for ContainerBuilder {
 /// Macro for creating a container.
 #[macro_export]
 macro_rules! container {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
 
-        #[allow(unused_mut)]
-        let mut children_vec: Vec<Entity> = Vec::new();
+        #[allow(unused_mut, unused_assignments)]
+        let mut scope: Option<String> = None;
         $(
-            $crate::extract_children!(children_vec, $key : $value);
+            $crate::extract_scope!(scope, $key : $value);
         )*
+        let scope_aliases = scope
+            .map(|s| $crate::reactivity::scope_aliases(&famiq_builder.reactive_data.data, &s))
+            .unwrap_or_default();
+
+        // children resolve local keys of the scope first
+        let children_vec = $crate::widgets::with_template_aliases(&scope_aliases, || {
+            #[allow(unused_mut)]
+            let mut children_vec: Vec<Entity> = Vec::new();
+            $(
+                $crate::extract_children!(children_vec, $key : $value);
+            )*
+            children_vec
+        });
         let root_entity = famiq_builder.resource.root_node_entity.unwrap();
         let c_builder = &mut ContainerBuilder::new(root_entity);
 
@@ -141,6 +155,11 @@ macro_rules! container {
         // famiq_builder.containable_children.insert(entity, children_vec);
         entity
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(container [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -148,6 +167,9 @@ macro_rules! container_attributes {
     // skip children
     ($c_builder:ident, children: $children_vec:tt) => {{}};
 
+    // skip scope
+    ($c_builder:ident, scope: $scope:expr) => {{}};
+
     ($c_builder:ident, $key:ident : $value:expr) => {{
         $crate::common_attributes!($c_builder, $key : $value);
     }};
@@ -3,6 +3,7 @@
 use crate::utils;
 use crate::FamiqPlugin;
 use crate::widgets::button::*;
+use crate::widgets::text::*;
 use crate::widgets::{FamiqResource, WidgetId, WidgetClasses, builder_mut};
 use super::*;
 
@@ -80,3 +81,58 @@ fn test_create_container_with_children() {
 
     assert_eq!(2 as usize, container_q.unwrap().0.len());
 }
+
+fn setup_test_scoped_containers(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("team", "Red");
+    fa_query.insert_num("hp", 0);
+    fa_query.insert_scope("p1", [("hp", RVal::Num(100))]);
+    fa_query.insert_scope("p2", [("hp", RVal::Num(70))]);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    crate::container!(
+        scope: "p1",
+        children: [crate::text!(text: "$[team]: $[hp]", id: "#p1-hp")]
+    );
+    crate::container!(
+        scope: "p2",
+        children: [crate::text!(text: "$[team]: $[hp]", id: "#p2-hp")]
+    );
+}
+
+fn run_hit_p2(mut fa_query: FaQuery) {
+    fa_query.mutate_scoped("p2", "hp", RVal::Num(50));
+}
+
+fn get_text_by_id(app: &mut App, id: &str) -> String {
+    app.world_mut()
+        .query::<(&Text, &WidgetId)>()
+        .iter(app.world())
+        .find(|(_, widget_id)| widget_id.0 == id)
+        .map(|(text, _)| text.0.clone())
+        .unwrap()
+}
+
+#[test]
+fn test_scoped_containers() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_scoped_containers);
+    app.update();
+
+    assert_eq!(get_text_by_id(&mut app, "#p1-hp"), "Red: 100");
+    assert_eq!(get_text_by_id(&mut app, "#p2-hp"), "Red: 70");
+
+    app.add_systems(Update, run_hit_p2);
+    app.update();
+
+    assert_eq!(get_text_by_id(&mut app, "#p1-hp"), "Red: 100");
+    assert_eq!(get_text_by_id(&mut app, "#p2-hp"), "Red: 50");
+
+    let subscribers = &app.world().resource::<RSubscriber>().bindings;
+    assert_eq!(subscribers.get("__scope_p1.hp").map(|s| s.len()), Some(1));
+    assert_eq!(subscribers.get("__scope_p2.hp").map(|s| s.len()), Some(1));
+    assert!(subscribers.get("hp").is_none_or(|s| s.is_empty()));
+}
@@ -91,7 +91,7 @@ impl DialogBuilder {
                 let reactive_keys = get_reactive_key(&v);
 
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::Bool(state) => self.set_clear_bg(*state),
                             _ => {}
@@ -168,7 +168,7 @@ impl SetupWidget for DialogBuilder {
 /// Macro for creating a modal.
 #[macro_export]
 macro_rules! dialog {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
 
         #[allow(unused_mut)]
@@ -191,6 +191,11 @@ macro_rules! dialog {
         // famiq_builder.containable_children.insert(bg_entity, children_vec);
         bg_entity
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(dialog [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -12,7 +12,7 @@ pub fn detect_dialog_reactive_model_change(
                 continue;
             }
             let key = model_key.unwrap();
-            let r_value = reactive_data.data.get(&key.0);
+            let r_value = get_reactive_value(&reactive_data.data, &key.0);
 
             if r_value.is_none() {
                 continue;
@@ -33,7 +33,7 @@ pub fn detect_dialog_reactive_model_change(
 
 // Internal system to hide or display via `FaModalState` resource.
 pub fn hide_or_display_dialog_system(
-    mut dialog_q: Query<(&mut Node, &mut AnimationProgress, &Children, Entity)>,
+    mut dialog_q: Query<(&mut Node, &mut AnimationProgress, &Children, Entity), With<IsFamiqDialog>>,
     mut children_q: Query<&mut Transform>,
     mut dialog_res: ResMut<FaDialogState>,
     time: Res<Time>,
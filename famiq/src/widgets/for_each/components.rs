@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use std::sync::Arc;
+use std::fmt;
+
+use crate::reactivity::{RData, RSubscriber, RVal};
+use crate::widgets::conditional::FaConditional;
+use super::systems::clean_up_row;
+
+/// Marker component for identifying a Famiq for_each list.
+#[derive(Component)]
+pub struct IsFamiqForEach;
+
+/// Closure building one row from its item.
+#[derive(Clone)]
+pub struct RowTemplate(pub Arc<dyn Fn(&RVal) -> Entity + Send + Sync>);
+
+impl fmt::Debug for RowTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RowTemplate")
+    }
+}
+
+/// A rendered row of a list.
+#[derive(Debug, Clone)]
+pub struct ForEachRow {
+    /// key used for reconciliation
+    pub key: String,
+    pub entity: Entity,
+    /// reactive data key holding a copy of the row's item
+    pub data_key: String
+}
+
+#[derive(Component, Debug)]
+#[component(on_remove = clean_up_for_each_rows)]
+pub struct FaForEach {
+    pub list_key: String,
+    /// field of map items used as row key
+    pub key_field: Option<String>,
+    /// name used to refer to the item inside row template
+    pub alias: String,
+    pub row: RowTemplate,
+    pub rows: Vec<ForEachRow>,
+    pub(crate) aliases: Vec<(String, String)>,
+    pub(crate) initialized: bool,
+    pub(crate) next_row_id: u64
+}
+
+/// Rows of a despawned list, eg. within a hidden `if_else!` branch,
+/// leave their data & subscribers behind, remove them along with the list.
+fn clean_up_for_each_rows(mut world: DeferredWorld, context: HookContext) {
+    let Some(rows) = world.get::<FaForEach>(context.entity).map(|list| list.rows.clone()) else {
+        return;
+    };
+    if rows.is_empty() {
+        return;
+    }
+    // rows aren't despawned yet, collect them & their descendants now
+    let row_entities: Vec<Vec<Entity>> = rows.iter().map(|row| descendants_of(&world, row.entity)).collect();
+
+    world.commands().queue(move |world: &mut World| {
+        world.resource_scope(|world, mut r_data: Mut<RData>| {
+            let mut subscriber = world.resource_mut::<RSubscriber>();
+
+            for (row, entities) in rows.iter().zip(row_entities.iter()) {
+                clean_up_row(&mut r_data, &mut subscriber, row, entities);
+            }
+        });
+    });
+}
+
+/// Entity & all its descendants, with branches of conditionals placed among them.
+fn descendants_of(world: &World, entity: Entity) -> Vec<Entity> {
+    let mut entities = vec![entity];
+    let mut index = 0;
+
+    while index < entities.len() {
+        let current = entities[index];
+        entities.extend(world.get::<FaConditional>(current).and_then(|c| c.branch));
+
+        if let Some(children) = world.get::<Children>(current) {
+            entities.extend(children.iter());
+        }
+        index += 1;
+    }
+    entities
+}
@@ -0,0 +1,180 @@
+pub mod components;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+use std::sync::Arc;
+
+use crate::widgets::*;
+use crate::widgets::container::*;
+
+pub fn default_for_each_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::FlexStart,
+        justify_content: JustifyContent::FlexStart,
+        height: Val::Auto,
+        ..default()
+    }
+}
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct ForEachBuilder {
+    pub list_key: String,
+    pub key_field: Option<String>,
+    pub alias: String,
+    pub row: RowTemplate,
+    pub all_reactive_keys: Vec<String>,
+    /// aliases of outer lists, rows are built later with them
+    pub(crate) aliases: Vec<(String, String)>
+}
+
+impl ForEachBuilder {
+    pub fn new(list_key: &str, row: impl Fn(&RVal) -> Entity + Send + Sync + 'static) -> Self {
+        Self {
+            list_key: resolve_key_alias(list_key),
+            key_field: None,
+            alias: "item".to_string(),
+            row: RowTemplate(Arc::new(row)),
+            all_reactive_keys: Vec::new(),
+            aliases: current_template_aliases(),
+            attributes: WidgetAttributes::default(),
+            cloned_attrs: WidgetAttributes::default()
+        }
+    }
+
+    pub fn set_key_field(&mut self, key_field: &str) {
+        self.key_field = Some(key_field.to_string());
+    }
+
+    pub fn set_alias(&mut self, alias: &str) {
+        self.alias = alias.to_string();
+    }
+
+    fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_for_each_node();
+
+        if self.cloned_attrs.color == WidgetColor::Default {
+            self.cloned_attrs.color = WidgetColor::Transparent;
+        }
+        self.cloned_attrs.default_visibility = Visibility::Visible;
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+    }
+}
+
+impl SetupWidget for ForEachBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqForEach, MainWidget, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+        let mut base_container = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let list_entity = base_container.build(r_data, commands);
+
+        // rows are spawned by reconcile_for_each_system
+        commands
+            .entity(list_entity)
+            .insert(self.components())
+            .insert(FaForEach {
+                list_key: self.list_key.clone(),
+                key_field: self.key_field.clone(),
+                alias: self.alias.clone(),
+                row: self.row.clone(),
+                rows: Vec::new(),
+                aliases: self.aliases.clone(),
+                initialized: false,
+                next_row_id: 0
+            });
+
+        insert_class_id(commands, list_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                list_entity,
+                WidgetBuilder {
+                    builder: BuilderType::ForEach(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        list_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+        let mut base_container = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        base_container.rebuild(r_data, old_entity, world);
+
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::ForEach(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for rendering one row per item of a reactive list.
+///
+/// ```text
+/// for_each!(
+///     list: "todos",
+///     row: |_item| text!(text: "$[item.name]"),
+///     key: "id"
+/// );
+/// ```
+#[macro_export]
+macro_rules! for_each {
+    ( @widget list: $list:expr, row: $row:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let f_builder = &mut ForEachBuilder::new($list, $row);
+        $(
+            $crate::for_each_attributes!(f_builder, $key : $value);
+        )*
+        f_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(for_each [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! for_each_attributes {
+    ($f_builder:ident, key: $key_field:expr) => {{
+        $f_builder.set_key_field($key_field);
+    }};
+
+    ($f_builder:ident, item: $alias:expr) => {{
+        $f_builder.set_alias($alias);
+    }};
+
+    ($f_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($f_builder, $key : $value);
+    }};
+}
+
+pub fn can_run_for_each_systems(q: Query<&IsFamiqForEach>) -> bool {
+    !q.is_empty()
+}
@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use bevy::platform::collections::HashMap;
+
+use crate::reactivity::*;
+use crate::widgets::*;
+use crate::widgets::conditional::FaConditional;
+use super::*;
+
+/// Items of a reactive list, string lists are turned into `RVal::Str` items.
+fn list_items(r_data: &HashMap<String, RVal>, list_key: &str) -> Vec<RVal> {
+    match get_reactive_value(r_data, list_key) {
+        Some(RVal::Array(items)) => items.clone(),
+        Some(RVal::List(items)) => items.iter().map(|s| RVal::Str(s.clone())).collect(),
+        _ => Vec::new()
+    }
+}
+
+/// Row key of each item. Map items are keyed by `key_field` if given,
+/// other map or array items by index and plain values by their value.
+fn row_keys(items: &[RVal], key_field: Option<&str>) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let key = match (item, key_field) {
+                (RVal::Map(map), Some(field)) => map
+                    .get(field)
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| index.to_string()),
+                (RVal::Map(_), None) | (RVal::Array(_), _) => index.to_string(),
+                (value, _) => value.to_string()
+            };
+            // duplicated keys still get their own row
+            let count = seen.entry(key.clone()).or_insert(0);
+            *count += 1;
+
+            if *count > 1 {
+                format!("{key}#{count}")
+            } else {
+                key
+            }
+        })
+        .collect()
+}
+
+/// Changes made through `$[item...]` of rows, to be written back into the list.
+fn rows_to_write_back(r_data: &RData, list: &FaForEach) -> Vec<(String, RVal)> {
+    // list itself was replaced, it wins over row changes
+    if r_data.changed_keys.iter().any(|k| k == &list.list_key || is_sub_path(&list.list_key, k)) {
+        return Vec::new();
+    }
+    let mut changes = Vec::new();
+
+    for (index, row) in list.rows.iter().enumerate() {
+        if !r_data.changed_keys.iter().any(|k| is_related_path(k, &row.data_key)) {
+            continue;
+        }
+        let Some(value) = r_data.data.get(&row.data_key) else {
+            continue;
+        };
+        let item_path = format!("{}.{}", list.list_key, index);
+
+        if get_reactive_value(&r_data.data, &item_path).is_some_and(|item| item != value) {
+            changes.push((item_path, value.clone()));
+        }
+    }
+    changes
+}
+
+/// Remove data and subscribers of a removed row. `entities` are the row & its descendants,
+/// they may subscribe to keys outside of the row, eg. `$[currency]`.
+pub(crate) fn clean_up_row(r_data: &mut RData, subscriber: &mut RSubscriber, row: &ForEachRow, entities: &[Entity]) {
+    let is_row_key = |key: &String| key == &row.data_key || is_sub_path(key, &row.data_key);
+
+    r_data.data.remove(&row.data_key);
+    r_data.changed_keys.retain(|k| !is_row_key(k));
+    subscriber.data.retain(|k, _| !is_row_key(k));
+    subscriber.bindings.retain(|k, _| !is_row_key(k));
+    subscriber.remove_entities(entities);
+}
+
+/// Rows with a branch of their conditional placed right after them,
+/// see `FaConditional`.
+fn with_branches(rows: &[Entity], conditional_q: &Query<&FaConditional>) -> Vec<Entity> {
+    let mut entities = Vec::with_capacity(rows.len());
+
+    for row in rows.iter() {
+        entities.push(*row);
+        if let Some(branch) = conditional_q.get(*row).ok().and_then(|c| c.branch) {
+            entities.push(branch);
+        }
+    }
+    entities
+}
+
+/// Row, the branch of its conditional and all their descendants.
+fn row_entities(row: Entity, children_q: &Query<&Children>, conditional_q: &Query<&FaConditional>) -> Vec<Entity> {
+    let mut entities = with_branches(&[row], conditional_q);
+    let descendants: Vec<Entity> = entities.iter().flat_map(|e| children_q.iter_descendants(*e)).collect();
+    entities.extend(descendants);
+    entities
+}
+
+fn reconcile_list(
+    list_entity: Entity,
+    list: &mut FaForEach,
+    fa_query: &mut FaQuery,
+    children_q: &Query<&Children>,
+    conditional_q: &Query<&FaConditional>
+) {
+    let items = list_items(&fa_query.reactive_data.data, &list.list_key);
+    let keys = row_keys(&items, list.key_field.as_deref());
+
+    let old_entities: Vec<Entity> = list.rows.iter().map(|r| r.entity).collect();
+    let mut old_rows: HashMap<String, ForEachRow> = list.rows
+        .drain(..)
+        .map(|row| (row.key.clone(), row))
+        .collect();
+    let mut new_rows: Vec<ForEachRow> = Vec::with_capacity(items.len());
+
+    for (item, key) in items.into_iter().zip(keys) {
+        if let Some(row) = old_rows.remove(&key) {
+            let r_data = &mut fa_query.reactive_data;
+
+            if r_data.data.get(&row.data_key) != Some(&item) {
+                r_data.mark_changed(&row.data_key);
+                r_data.data.insert(row.data_key.clone(), item);
+            }
+            new_rows.push(row);
+            continue;
+        }
+        let data_key = format!("__fa_row_{}_{}", list_entity.to_bits(), list.next_row_id);
+        list.next_row_id += 1;
+        fa_query.reactive_data.data.insert(data_key.clone(), item.clone());
+
+        let row_template = list.row.clone();
+        let entity = with_template_aliases(&list.aliases, || {
+            with_template_alias(&list.alias, &data_key, || (row_template.0)(&item))
+        });
+        new_rows.push(ForEachRow { key, entity, data_key });
+    }
+
+    for row in old_rows.into_values() {
+        let entities = row_entities(row.entity, children_q, conditional_q);
+        fa_query.commands.entity(row.entity).despawn();
+        clean_up_row(&mut fa_query.reactive_data, &mut fa_query.reactive_subscriber, &row, &entities);
+    }
+
+    let new_entities: Vec<Entity> = new_rows.iter().map(|r| r.entity).collect();
+    if new_entities != old_entities {
+        let children = with_branches(&new_entities, conditional_q);
+        fa_query.commands.entity(list_entity).replace_children(&children);
+    }
+    list.rows = new_rows;
+    list.initialized = true;
+}
+
+/// Internal system to keep rows of `for_each` lists in sync with their reactive list.
+/// Runs before `detect_reactive_data_change` so row changes are patched in the same frame.
+pub(crate) fn reconcile_for_each_system(
+    mut fa_query: FaQuery,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut list_q: Query<(Entity, &mut FaForEach)>,
+    children_q: Query<&Children>,
+    conditional_q: Query<&FaConditional>
+) {
+    let has_new_list = list_q.iter().any(|(_, list)| !list.initialized);
+
+    // read only, `RData` is changed only when a row or list actually changes
+    if fa_query.reactive_data.changed_keys.is_empty() && !has_new_list {
+        return;
+    }
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+
+    if !fa_query.reactive_data.changed_keys.is_empty() {
+        fa_query.reactive_data.recompute_computed_keys();
+    }
+
+    // updating a row may change nested lists, repeat until nothing new is changed
+    loop {
+        let changed_keys = fa_query.reactive_data.changed_keys.clone();
+
+        for (list_entity, mut list) in list_q.iter_mut() {
+            for (item_path, value) in rows_to_write_back(&fa_query.reactive_data, &list) {
+                let r_data = &mut fa_query.reactive_data;
+                r_data.mark_changed(&item_path);
+
+                if let Some(item) = get_reactive_value_mut(&mut r_data.data, &item_path) {
+                    *item = value;
+                }
+            }
+            let list_changed = fa_query.reactive_data.changed_keys
+                .iter()
+                .any(|k| is_related_path(k, &list.list_key));
+
+            if list_changed || !list.initialized {
+                reconcile_list(list_entity, &mut list, &mut fa_query, &children_q, &conditional_q);
+            }
+        }
+        if fa_query.reactive_data.changed_keys == changed_keys {
+            break;
+        }
+    }
+}
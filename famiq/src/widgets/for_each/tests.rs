@@ -0,0 +1,298 @@
+#![cfg(test)]
+
+use crate::utils::create_test_app;
+use crate::FamiqPlugin;
+use crate::widgets::container::*;
+use crate::widgets::text::*;
+use crate::widgets::{FamiqResource, builder_mut};
+use crate::{container, text};
+use super::*;
+
+fn todo(id: i32, name: &str) -> RVal {
+    let mut map = HashMap::new();
+    map.insert("id".to_string(), RVal::Num(id));
+    map.insert("name".to_string(), RVal::Str(name.to_string()));
+    RVal::Map(map)
+}
+
+fn setup_test_for_each(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_array("todos", vec![todo(1, "one"), todo(2, "two")]);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    crate::for_each!(
+        list: "todos",
+        row: |_| text!(text: "$[item.name]"),
+        key: "id",
+        id: "#test-list"
+    );
+}
+
+fn setup_test_for_each_with_global_key(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("currency", "$");
+    fa_query.insert_array("todos", vec![todo(1, "one"), todo(2, "two")]);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    crate::for_each!(
+        list: "todos",
+        row: |_| text!(text: "$[item.name] $[currency]"),
+        key: "id"
+    );
+}
+
+fn setup_test_for_each_with_alias(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str_list("fruits", vec!["apple".to_string(), "banana".to_string()]);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    crate::for_each!(
+        list: "fruits",
+        row: |_| text!(text: "Fruit: $[fruit]"),
+        item: "fruit"
+    );
+}
+
+fn setup_test_for_each_in_if_else(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_bool("show_list", true);
+    fa_query.insert_array("todos", vec![todo(1, "one"), todo(2, "two")]);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    container!(children: [
+        crate::if_else!(
+            condition: "show_list",
+            then: || crate::for_each!(list: "todos", row: |_| text!(text: "$[item.name]"))
+        )
+    ]);
+}
+
+fn run_hide_list(mut fa_query: FaQuery) {
+    fa_query.mutate_bool("show_list", false);
+}
+
+fn run_push_and_reorder(mut fa_query: FaQuery) {
+    fa_query.mutate_data("todos", RVal::Array(vec![todo(2, "two"), todo(3, "three"), todo(1, "one")]));
+}
+
+fn run_remove_first(mut fa_query: FaQuery) {
+    fa_query.mutate_data("todos", RVal::Array(vec![todo(2, "two")]));
+}
+
+fn run_rename_second(mut fa_query: FaQuery) {
+    fa_query.mutate_data("todos.1.name", RVal::Str("second".to_string()));
+}
+
+fn run_mutate_second_row(mut fa_query: FaQuery, list_q: Query<&FaForEach>) {
+    let list = list_q.single().unwrap();
+    let path = format!("{}.name", list.rows[1].data_key);
+    fa_query.mutate_data(&path, RVal::Str("second".to_string()));
+}
+
+fn rows(app: &mut App) -> Vec<(Entity, String)> {
+    let children = app.world_mut()
+        .query_filtered::<&Children, With<IsFamiqForEach>>()
+        .single(app.world())
+        .map(|c| c.to_vec())
+        .unwrap_or_default();
+
+    children
+        .into_iter()
+        .map(|e| (e, app.world().get::<Text>(e).unwrap().0.clone()))
+        .collect()
+}
+
+#[test]
+fn test_create_for_each() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+
+    let texts: Vec<String> = rows(&mut app).into_iter().map(|(_, t)| t).collect();
+    assert_eq!(vec!["one".to_string(), "two".to_string()], texts);
+}
+
+#[test]
+fn test_for_each_with_item_alias() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each_with_alias);
+    app.update();
+
+    let texts: Vec<String> = rows(&mut app).into_iter().map(|(_, t)| t).collect();
+    assert_eq!(vec!["Fruit: apple".to_string(), "Fruit: banana".to_string()], texts);
+}
+
+#[test]
+fn test_for_each_keeps_rows_on_push_and_reorder() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+
+    let before = rows(&mut app);
+
+    app.add_systems(Update, run_push_and_reorder);
+    app.update();
+
+    let after = rows(&mut app);
+    assert_eq!(3, after.len());
+    assert_eq!(before[1], after[0], "row of id 2 should only move");
+    assert_eq!(before[0], after[2], "row of id 1 should only move");
+    assert_eq!("three", after[1].1);
+}
+
+#[test]
+fn test_for_each_despawn_removed_rows() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+
+    let before = rows(&mut app);
+
+    app.add_systems(Update, run_remove_first);
+    app.update();
+
+    let after = rows(&mut app);
+    assert_eq!(vec![before[1].clone()], after);
+    assert!(app.world().get_entity(before[0].0).is_err());
+}
+
+#[test]
+fn test_for_each_removed_rows_leave_global_subscriptions() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each_with_global_key);
+    app.update();
+    app.update();
+
+    let subscribed = |app: &App, entity: Entity| {
+        let subscriber = app.world().resource::<RSubscriber>();
+        subscriber.data.get("currency").is_some_and(|s| s.contains_key(&entity))
+            || subscriber.bindings.get("currency").is_some_and(|b| b.keys().any(|(e, _)| *e == entity))
+    };
+    let before = rows(&mut app);
+    assert!(subscribed(&app, before[0].0));
+
+    app.add_systems(Update, run_remove_first);
+    app.update();
+
+    assert!(app.world().get_entity(before[0].0).is_err());
+    assert!(!subscribed(&app, before[0].0));
+    assert!(subscribed(&app, before[1].0));
+}
+
+#[test]
+fn test_for_each_row_binding() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+
+    let before = rows(&mut app);
+
+    app.add_systems(Update, run_rename_second);
+    app.update();
+
+    let after = rows(&mut app);
+    assert_eq!(before[1].0, after[1].0);
+    assert_eq!("second", after[1].1);
+}
+
+#[test]
+fn test_for_each_writes_row_changes_back() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+
+    app.add_systems(Update, run_mutate_second_row);
+    app.update();
+
+    let r_data = app.world().resource::<RData>();
+    assert_eq!(
+        Some(&RVal::Str("second".to_string())),
+        get_reactive_value(&r_data.data, "todos.1.name")
+    );
+    assert_eq!("second", rows(&mut app)[1].1);
+}
+
+#[test]
+fn test_for_each_idle_frames_keep_reactive_data_unchanged() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+
+    let mut r_data = app.world_mut().resource_mut::<RData>();
+    r_data.mark_changed("todos.1.name");
+    *get_reactive_value_mut(&mut r_data.data, "todos.1.name").unwrap() = RVal::Str("second".to_string());
+    app.update();
+    app.update();
+
+    let changed_tick = app.world().get_resource_change_ticks::<RData>().unwrap().changed;
+    app.update();
+    app.update();
+    assert_eq!(changed_tick, app.world().get_resource_change_ticks::<RData>().unwrap().changed);
+}
+
+#[test]
+fn test_for_each_cleans_up_rows_when_despawned() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_for_each_in_if_else);
+    // branch, rows & their subscriptions are each added a frame later
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let is_row_key = |key: &String| key.starts_with("__fa_row_");
+    assert_eq!(2, app.world().resource::<RData>().data.keys().filter(|k| is_row_key(k)).count());
+    let subscriber = app.world().resource::<RSubscriber>();
+    assert!(subscriber.data.keys().chain(subscriber.bindings.keys()).any(is_row_key));
+
+    app.add_systems(Update, run_hide_list);
+    app.update();
+    app.update();
+
+    assert!(app.world_mut().query::<&FaForEach>().iter(app.world()).next().is_none());
+    assert!(!app.world().resource::<RData>().data.keys().any(is_row_key));
+    let subscriber = app.world().resource::<RSubscriber>();
+    assert!(!subscriber.data.keys().chain(subscriber.bindings.keys()).any(is_row_key));
+}
+
+#[test]
+fn test_for_each_row_keys_stay_internal() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new().with_history(10));
+    app.add_systems(Startup, setup_test_for_each);
+    app.update();
+    app.update();
+
+    app.add_systems(Update, run_mutate_second_row);
+    app.update();
+
+    let events = app.world().resource::<Events<ReactiveDataChanged>>();
+    let keys: Vec<&str> = events.iter_current_update_events().map(|e| e.key.as_str()).collect();
+    assert!(!keys.is_empty());
+    assert!(!keys.iter().any(|k| k.starts_with("__fa_row_")), "{keys:?}");
+
+    let history = app.world().resource::<RHistory>();
+    assert!(history.entries().flat_map(|e| e.changes.iter()).all(|c| !c.key.starts_with("__fa_row_")));
+
+    let r_data = app.world().resource::<RData>();
+    assert!(r_data.data.keys().any(|k| k.starts_with("__fa_row_")));
+    let json = serde_json::to_string(r_data).unwrap();
+    assert!(!json.contains("__fa_row_"));
+    assert!(json.contains("second"));
+}
@@ -117,7 +117,7 @@ impl FpsBuilder {
             RVal::Str(v) => {
                 let reactive_keys = get_reactive_key(&v);
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::Bool(state) => self.set_node_right_side(*state),
                             _ => {}
@@ -170,7 +170,7 @@ impl SetupWidget for FpsBuilder {
             RVal::Str(v) => {
                 let reactive_keys = get_reactive_key(&v);
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::Bool(state) => {
                                 commands.entity(count_entity).insert(CanChangeColor(*state));
@@ -217,7 +217,7 @@ impl SetupWidget for FpsBuilder {
             RVal::Str(v) => {
                 let reactive_keys = get_reactive_key(&v);
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::Bool(state) => {
                                 world.entity_mut(self.count_text_entity.unwrap()).insert(CanChangeColor(*state));
@@ -249,7 +249,7 @@ impl SetupWidget for FpsBuilder {
 /// Macro for creating fps count.
 #[macro_export]
 macro_rules! fps {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let root_entity = famiq_builder.resource.root_node_entity.unwrap();
         let f_builder = &mut FpsBuilder::new(root_entity, &famiq_builder.get_font_handle());
@@ -261,6 +261,11 @@ macro_rules! fps {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(fps [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -111,7 +111,7 @@ impl SetupWidget for ImageBuilder {
 /// Macro for creating an image.
 #[macro_export]
 macro_rules! image {
-    ( path: $path:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+    ( @widget path: $path:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let i_builder = &mut ImageBuilder::new($path.to_string());
         $(
@@ -122,6 +122,11 @@ macro_rules! image {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(image [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
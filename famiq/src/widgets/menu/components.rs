@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use bevy::ecs::component::HookContext;
+use bevy::ecs::world::DeferredWorld;
+
+use super::FaMenuState;
+
+/// Marker component for identifying an entity as a Famiq menu, including submenus
+/// and dropdowns of menu bars.
+#[derive(Component)]
+pub struct IsFamiqMenu;
+
+/// Marker component for identifying an entity as a Famiq menu bar.
+#[derive(Component)]
+pub struct IsFamiqMenuBar;
+
+/// Item of a menu. Eg, `MenuItem::new("Copy").shortcut("Ctrl+C")`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MenuItem {
+    pub label: String,
+    pub id: Option<String>,
+    /// hint shown at the right side of the item
+    pub shortcut: Option<String>,
+    pub checkable: bool,
+    pub checked: bool,
+    pub disabled: bool,
+    pub separator: bool,
+    pub submenu: Vec<MenuItem>
+}
+
+impl MenuItem {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            ..default()
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..default()
+        }
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: &str) -> Self {
+        self.shortcut = Some(shortcut.to_string());
+        self
+    }
+
+    pub fn checkable(mut self, checked: bool) -> Self {
+        self.checkable = true;
+        self.checked = checked;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn submenu(mut self, items: Vec<MenuItem>) -> Self {
+        self.submenu = items;
+        self
+    }
+
+    /// id of the item, or its label if it has no id.
+    pub fn key(&self) -> String {
+        self.id.clone().unwrap_or_else(|| self.label.clone())
+    }
+}
+
+/// Selector of widgets opening the menu on right click, `#id` or `.class`.
+#[derive(Component, Clone, Debug)]
+pub struct MenuContextOf(pub String);
+
+/// Item row of a menu. `owner` is the menu or menu bar widget, events of the item are sent with it.
+#[derive(Component, Clone, Debug)]
+pub struct MenuItemState {
+    pub menu: Entity,
+    pub owner: Entity,
+    pub key: String,
+    pub checkable: bool,
+    pub checked: bool,
+    pub disabled: bool,
+    pub submenu: Option<Entity>,
+    pub check_entity: Entity
+}
+
+/// Item of a menu bar, opening its dropdown below it. Items without dropdown are clicked like menu items.
+#[derive(Component)]
+pub struct MenuBarItem {
+    pub bar: Entity,
+    pub key: String,
+    pub dropdown: Option<Entity>
+}
+
+#[derive(Component)]
+pub struct MenuBarDropdowns(pub Vec<Entity>);
+
+/// Submenus & dropdowns of a menu or menu bar. They live on root node,
+/// not under their owner, so they're despawned along with it.
+#[derive(Component, Debug)]
+#[component(on_remove = despawn_menu_popups)]
+pub struct MenuPopups(pub Vec<Entity>);
+
+fn despawn_menu_popups(mut world: DeferredWorld, context: HookContext) {
+    let Some(popups) = world.get::<MenuPopups>(context.entity).map(|popups| popups.0.clone()) else {
+        return;
+    };
+    if let Some(mut menu_state) = world.get_resource_mut::<FaMenuState>()
+        && menu_state.open.iter().any(|(menu, _)| *menu == context.entity || popups.contains(menu))
+    {
+        menu_state.open.retain(|(menu, _)| *menu != context.entity && !popups.contains(menu));
+    }
+    for popup in popups {
+        world.commands().entity(popup).try_despawn();
+    }
+}
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use super::*;
+
+pub(crate) const CHECK_MARK: &str = "√";
+
+/// Text style of menu items & attributes of their popups.
+#[derive(Clone, Debug)]
+pub(crate) struct MenuStyle {
+    pub font: TextFont,
+    pub text_color: Color,
+    /// attributes of submenus & dropdowns, without id, class & model
+    pub popup_attrs: WidgetAttributes
+}
+
+fn menu_text(text: &str, node: Node, style: &MenuStyle, color: Color) -> impl Bundle {
+    (node, Text::new(text), style.font.clone(), TextColor(color), Pickable::IGNORE)
+}
+
+/// Spawn a hidden popup on root node, used by submenus & dropdowns of menu bars.
+pub(crate) fn spawn_menu_popup(
+    style: &MenuStyle,
+    root_node: Entity,
+    r_data: &HashMap<String, RVal>,
+    commands: &mut Commands
+) -> Entity {
+    let mut popup = FaBaseContainer::new_with_attributes(&style.popup_attrs);
+    let popup_entity = popup.build(r_data, commands);
+    commands
+        .entity(popup_entity)
+        .insert((IsFamiqMenu, GlobalZIndex(MENU_Z_INDEX)));
+    commands.entity(root_node).add_child(popup_entity);
+    popup_entity
+}
+
+/// Spawn item rows of a menu. Submenus are spawned as other popups on root node,
+/// all of them are returned.
+pub(crate) fn build_menu_items(
+    items: &[MenuItem],
+    menu: Entity,
+    owner: Entity,
+    style: &MenuStyle,
+    root_node: Entity,
+    r_data: &HashMap<String, RVal>,
+    commands: &mut Commands
+) -> Vec<Entity> {
+    let mut popups = Vec::new();
+
+    for item in items.iter() {
+        if item.separator {
+            let separator_entity = commands
+                .spawn((default_menu_separator_node(), BackgroundColor(SEPARATOR_COLOR), Pickable::IGNORE))
+                .id();
+            commands.entity(menu).add_child(separator_entity);
+            continue;
+        }
+        let submenu = (!item.submenu.is_empty()).then(|| {
+            let submenu_entity = spawn_menu_popup(style, root_node, r_data, commands);
+            popups.push(submenu_entity);
+            popups.extend(build_menu_items(&item.submenu, submenu_entity, owner, style, root_node, r_data, commands));
+            submenu_entity
+        });
+        let text_color = if item.disabled { style.text_color.with_alpha(0.4) } else { style.text_color };
+        let check = if item.checkable && item.checked { CHECK_MARK } else { "" };
+
+        let check_entity = commands.spawn(menu_text(check, default_menu_check_node(), style, text_color)).id();
+        let label_entity = commands.spawn(menu_text(&item.label, default_menu_label_node(), style, text_color)).id();
+        let mut children = vec![check_entity, label_entity];
+
+        if let Some(shortcut) = item.shortcut.as_ref() {
+            let hint_color = text_color.with_alpha(text_color.alpha() * 0.6);
+            children.push(commands.spawn(menu_text(shortcut, Node::default(), style, hint_color)).id());
+        }
+        if submenu.is_some() {
+            children.push(commands.spawn(menu_text("►", Node::default(), style, text_color)).id());
+        }
+
+        let mut item_commands = commands.spawn((
+            default_menu_item_node(),
+            BackgroundColor(ITEM_NORMAL_BG_COLOR),
+            MenuItemState {
+                menu,
+                owner,
+                key: item.key(),
+                checkable: item.checkable,
+                checked: item.checked,
+                disabled: item.disabled,
+                submenu,
+                check_entity
+            }
+        ));
+        item_commands
+            .observe(on_item_over)
+            .observe(on_item_out)
+            .observe(on_item_down)
+            .add_children(&children);
+
+        if !item.disabled {
+            item_commands.observe(on_pointer_cursor_over).observe(on_pointer_cursor_out);
+        }
+        let item_entity = item_commands.id();
+        commands.entity(menu).add_child(item_entity);
+    }
+    popups
+}
+
+/// Selector is `#id` of the widget or `.class` of one of its classes.
+pub(crate) fn matches_selector(selector: &str, id: Option<&WidgetId>, classes: Option<&WidgetClasses>) -> bool {
+    if selector.starts_with('#') {
+        return id.is_some_and(|id| id.0 == selector);
+    }
+    match (selector.strip_prefix('.'), classes) {
+        (Some(class_name), Some(classes)) => classes.0.split_whitespace().any(|class| class == class_name),
+        _ => false
+    }
+}
+
+/// Rect of a node in logical pixels of the window.
+pub(crate) fn node_rect(transform: &GlobalTransform, computed: &ComputedNode) -> Rect {
+    let scale = computed.inverse_scale_factor();
+    Rect::from_center_size(transform.translation().truncate() * scale, computed.size() * scale)
+}
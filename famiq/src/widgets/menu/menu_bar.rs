@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+
+use super::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct MenuBarBuilder {
+    /// top level items, their submenus are dropdowns of the bar
+    pub items: Vec<MenuItem>,
+    pub root_node: Entity,
+    pub all_reactive_keys: Vec<String>
+}
+
+impl MenuBarBuilder {
+    pub fn new(font_handle: &Handle<Font>, root_node: Entity) -> Self {
+        Self {
+            items: Vec::new(),
+            root_node,
+            all_reactive_keys: Vec::new(),
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default()
+        }
+    }
+
+    pub fn set_items(&mut self, items: impl Into<Vec<MenuItem>>) {
+        self.items = items.into();
+    }
+
+    fn style(&self) -> MenuStyle {
+        let mut popup_attrs = WidgetAttributes {
+            id: None,
+            class: None,
+            model_key: None,
+            ..self.cloned_attrs.clone()
+        };
+        popup_attrs.node = default_menu_node();
+
+        MenuStyle {
+            font: TextFont {
+                font: self.cloned_attrs.font_handle.clone().unwrap(),
+                font_size: get_text_size(&self.cloned_attrs.size),
+                ..default()
+            },
+            text_color: get_text_color(&self.cloned_attrs.color),
+            popup_attrs
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_menu_bar_node();
+        self.cloned_attrs.overrided_border_color = Some(SEPARATOR_COLOR);
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+        self._process_built_in_size_class();
+    }
+
+    /// Spawn items of the bar, returns its dropdowns & all popups, including submenus of dropdowns.
+    fn build_bar_items(
+        &self,
+        bar_entity: Entity,
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) -> (Vec<Entity>, Vec<Entity>) {
+        let style = self.style();
+        let mut dropdowns = Vec::new();
+        let mut popups = Vec::new();
+
+        for item in self.items.iter().filter(|item| !item.separator) {
+            let dropdown = (!item.submenu.is_empty()).then(|| {
+                let dropdown_entity = spawn_menu_popup(&style, self.root_node, r_data, commands);
+                popups.push(dropdown_entity);
+                popups.extend(
+                    build_menu_items(&item.submenu, dropdown_entity, bar_entity, &style, self.root_node, r_data, commands)
+                );
+                dropdown_entity
+            });
+            dropdowns.extend(dropdown);
+
+            let label_entity = commands
+                .spawn((Text::new(&item.label), style.font.clone(), TextColor(style.text_color), Pickable::IGNORE))
+                .id();
+            let item_entity = commands
+                .spawn((
+                    default_menu_bar_item_node(),
+                    BackgroundColor(ITEM_NORMAL_BG_COLOR),
+                    MenuBarItem {
+                        bar: bar_entity,
+                        key: item.key(),
+                        dropdown
+                    }
+                ))
+                .observe(on_bar_item_over)
+                .observe(on_bar_item_out)
+                .observe(on_bar_item_down)
+                .observe(on_pointer_cursor_over)
+                .observe(on_pointer_cursor_out)
+                .add_child(label_entity)
+                .id();
+            commands.entity(bar_entity).add_child(item_entity);
+        }
+        (dropdowns, popups)
+    }
+}
+
+impl SetupWidget for MenuBarBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqMenuBar, MainWidget, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+
+        let mut bar = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let bar_entity = bar.build(r_data, commands);
+        let (dropdowns, popups) = self.build_bar_items(bar_entity, r_data, commands);
+
+        commands
+            .entity(bar_entity)
+            .insert((self.components(), MenuBarDropdowns(dropdowns), MenuPopups(popups)));
+
+        insert_class_id(commands, bar_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, bar_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                bar_entity,
+                WidgetBuilder {
+                    builder: BuilderType::MenuBar(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        bar_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+
+        let mut bar = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        bar.rebuild(r_data, old_entity, world);
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::MenuBar(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a menu bar, submenus of its items are opened as dropdowns.
+///
+/// ```text
+/// menu_bar!(
+///     items: [
+///         MenuItem::new("File").submenu(vec![
+///             MenuItem::new("Open").shortcut("Ctrl+O"),
+///             MenuItem::new("Save").shortcut("Ctrl+S")
+///         ]),
+///         MenuItem::new("Help").id("help")
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! menu_bar {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let root_entity = famiq_builder.resource.root_node_entity.unwrap();
+        let m_builder = &mut MenuBarBuilder::new(&famiq_builder.get_font_handle(), root_entity);
+        $(
+            $crate::menu_bar_attributes!(m_builder, $key : $value);
+        )*
+        m_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(menu_bar [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! menu_bar_attributes {
+    ($m_builder:ident, items: $items:expr) => {{
+        $m_builder.set_items($items);
+    }};
+    ($m_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($m_builder, $key : $value);
+    }};
+}
@@ -0,0 +1,231 @@
+pub mod components;
+pub mod helper;
+pub mod menu_bar;
+pub mod styling;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub use menu_bar::*;
+pub(crate) use helper::*;
+pub(crate) use styling::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+
+use crate::widgets::container::base_container::*;
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::utils::*;
+use crate::widgets::*;
+
+/// Open menus, from the top level menu to its deepest open submenu, with their positions.
+#[derive(Resource, Default, Debug)]
+pub struct FaMenuState {
+    pub(crate) open: Vec<(Entity, Vec2)>,
+    /// a menu was opened this frame, the press opening it doesn't close it
+    pub(crate) just_opened: bool
+}
+
+impl FaMenuState {
+    /// Open a menu at a position in logical pixels of the window, closing other menus.
+    pub fn open_at(&mut self, menu: Entity, position: Vec2) {
+        self.open = vec![(menu, position)];
+        self.just_opened = true;
+    }
+
+    pub fn close_all(&mut self) {
+        self.open.clear();
+    }
+
+    pub fn is_open(&self, menu: Entity) -> bool {
+        self.open.iter().any(|(open_menu, _)| *open_menu == menu)
+    }
+
+    /// Open menus once submenus of `menu` are closed & `submenu` is opened.
+    /// None if `menu` itself isn't open.
+    pub(crate) fn with_submenu(&self, menu: Entity, submenu: Option<(Entity, Vec2)>) -> Option<Vec<(Entity, Vec2)>> {
+        let depth = self.open.iter().position(|(open_menu, _)| *open_menu == menu)?;
+        let mut open = self.open[..=depth].to_vec();
+        open.extend(submenu);
+        Some(open)
+    }
+}
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct MenuBuilder {
+    pub items: Vec<MenuItem>,
+    /// selector of widgets opening the menu on right click
+    pub context_of: Option<String>,
+    pub root_node: Entity,
+    pub all_reactive_keys: Vec<String>
+}
+
+impl MenuBuilder {
+    pub fn new(font_handle: &Handle<Font>, root_node: Entity) -> Self {
+        Self {
+            items: Vec::new(),
+            context_of: None,
+            root_node,
+            all_reactive_keys: Vec::new(),
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default()
+        }
+    }
+
+    pub fn set_items(&mut self, items: impl Into<Vec<MenuItem>>) {
+        self.items = items.into();
+    }
+
+    pub fn set_context_of(&mut self, selector: &str) {
+        self.context_of = Some(selector.to_string());
+    }
+
+    fn style(&self) -> MenuStyle {
+        MenuStyle {
+            font: TextFont {
+                font: self.cloned_attrs.font_handle.clone().unwrap(),
+                font_size: get_text_size(&self.cloned_attrs.size),
+                ..default()
+            },
+            text_color: get_text_color(&self.cloned_attrs.color),
+            popup_attrs: WidgetAttributes {
+                id: None,
+                class: None,
+                model_key: None,
+                ..self.cloned_attrs.clone()
+            }
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_menu_node();
+        self.cloned_attrs.overrided_border_color = Some(SEPARATOR_COLOR);
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+        self._process_built_in_size_class();
+    }
+}
+
+impl SetupWidget for MenuBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqMenu, MainWidget, ReactiveWidget, GlobalZIndex(MENU_Z_INDEX))
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+
+        let mut menu = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let menu_entity = menu.build(r_data, commands);
+
+        commands.entity(menu_entity).insert(self.components());
+        commands.entity(self.root_node).add_child(menu_entity);
+        let popups = build_menu_items(&self.items, menu_entity, menu_entity, &self.style(), self.root_node, r_data, commands);
+        commands.entity(menu_entity).insert(MenuPopups(popups));
+
+        if let Some(selector) = self.context_of.as_ref() {
+            commands.entity(menu_entity).insert(MenuContextOf(selector.clone()));
+        }
+        insert_class_id(commands, menu_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, menu_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                menu_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Menu(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        menu_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+
+        let mut menu = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        menu.rebuild(r_data, old_entity, world);
+
+        // node is replaced, open menus are shown at their position again
+        world.resource_mut::<FaMenuState>().set_changed();
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Menu(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a popup menu, opened on right click of
+/// widgets matching `context_of` or with `FaMenuState::open_at`.
+///
+/// ```text
+/// menu!(
+///     id: "#editor-menu",
+///     context_of: ".editor",
+///     items: [
+///         MenuItem::new("Copy").shortcut("Ctrl+C"),
+///         MenuItem::new("Paste").shortcut("Ctrl+V").disabled(),
+///         MenuItem::separator(),
+///         MenuItem::new("Word wrap").checkable(true),
+///         MenuItem::new("Encoding").submenu(vec![MenuItem::new("UTF-8"), MenuItem::new("ASCII")])
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! menu {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let root_entity = famiq_builder.resource.root_node_entity.unwrap();
+        let m_builder = &mut MenuBuilder::new(&famiq_builder.get_font_handle(), root_entity);
+        $(
+            $crate::menu_attributes!(m_builder, $key : $value);
+        )*
+        m_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(menu [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! menu_attributes {
+    ($m_builder:ident, items: $items:expr) => {{
+        $m_builder.set_items($items);
+    }};
+    ($m_builder:ident, context_of: $selector:expr) => {{
+        $m_builder.set_context_of($selector);
+    }};
+    ($m_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($m_builder, $key : $value);
+    }};
+}
+
+/// Determines if menu internal system(s) can run.
+///
+/// True only if there is a menu or menu bar created.
+pub fn can_run_menu_systems(menu_q: Query<&IsFamiqMenu>) -> bool {
+    !menu_q.is_empty()
+}
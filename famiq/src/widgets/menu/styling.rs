@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+/// Above dialogs & fps text.
+pub(crate) const MENU_Z_INDEX: i32 = 7;
+
+pub const ITEM_ON_HOVER_BG_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.1);
+pub const ITEM_NORMAL_BG_COLOR: Color = Color::NONE; // transparent
+pub const SEPARATOR_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.15);
+
+pub fn default_menu_node() -> Node {
+    Node {
+        width: Val::Auto,
+        min_width: Val::Px(160.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Column,
+        position_type: PositionType::Absolute,
+        display: Display::None,
+        border: UiRect::all(Val::Px(1.0)),
+        padding: UiRect::vertical(Val::Px(4.0)),
+        ..default()
+    }
+}
+
+pub fn default_menu_item_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        padding: UiRect {
+            top: Val::Px(4.0),
+            right: Val::Px(10.0),
+            left: Val::Px(4.0),
+            bottom: Val::Px(4.0),
+        },
+        column_gap: Val::Px(6.0),
+        ..default()
+    }
+}
+
+pub fn default_menu_separator_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Px(1.0),
+        margin: UiRect::vertical(Val::Px(4.0)),
+        ..default()
+    }
+}
+
+/// Column of check marks, kept for every item so labels are aligned.
+pub fn default_menu_check_node() -> Node {
+    Node {
+        width: Val::Px(14.0),
+        flex_shrink: 0.0,
+        ..default()
+    }
+}
+
+pub fn default_menu_label_node() -> Node {
+    Node {
+        flex_grow: 1.0,
+        margin: UiRect::right(Val::Px(16.0)),
+        ..default()
+    }
+}
+
+pub fn default_menu_bar_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        border: UiRect::bottom(Val::Px(1.0)),
+        ..default()
+    }
+}
+
+pub fn default_menu_bar_item_node() -> Node {
+    Node {
+        padding: UiRect {
+            top: Val::Px(4.0),
+            right: Val::Px(10.0),
+            left: Val::Px(10.0),
+            bottom: Val::Px(4.0),
+        },
+        ..default()
+    }
+}
@@ -0,0 +1,254 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+
+use super::*;
+
+/// Hovering an item highlights it & opens its submenu, closing other submenus of its menu.
+pub(crate) fn on_item_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut item_q: Query<(&MenuItemState, &mut BackgroundColor, &GlobalTransform, &ComputedNode)>,
+    mut menu_state: ResMut<FaMenuState>
+) {
+    if let Ok((item, mut bg_color, transform, computed)) = item_q.get_mut(trigger.target()) {
+        if !item.disabled {
+            bg_color.0 = ITEM_ON_HOVER_BG_COLOR;
+        }
+        let submenu = match item.submenu {
+            Some(submenu) if !item.disabled => {
+                let rect = node_rect(transform, computed);
+                Some((submenu, Vec2::new(rect.max.x, rect.min.y)))
+            }
+            _ => None
+        };
+        if let Some(open) = menu_state.with_submenu(item.menu, submenu)
+            && open != menu_state.open
+        {
+            menu_state.open = open;
+        }
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_item_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut item_q: Query<&mut BackgroundColor, With<MenuItemState>>
+) {
+    if let Ok(mut bg_color) = item_q.get_mut(trigger.target()) {
+        bg_color.0 = ITEM_NORMAL_BG_COLOR;
+    }
+    trigger.propagate(false);
+}
+
+/// Clicking an item sends `FaMenuEvent` and closes menus, checkable items are toggled.
+pub(crate) fn on_item_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    mut item_q: Query<&mut MenuItemState>,
+    id_q: Query<&WidgetId>,
+    mut text_q: Query<&mut Text>,
+    mut menu_state: ResMut<FaMenuState>,
+    mut writer: EventWriter<FaMenuEvent>
+) {
+    trigger.propagate(false);
+
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let Ok(mut item) = item_q.get_mut(trigger.target()) else { return };
+
+    if item.disabled || item.submenu.is_some() {
+        return;
+    }
+    let checked = item.checkable.then(|| {
+        item.checked = !item.checked;
+        item.checked
+    });
+    if let Some(checked) = checked
+        && let Ok(mut text) = text_q.get_mut(item.check_entity)
+    {
+        text.0 = if checked { CHECK_MARK.to_string() } else { String::new() };
+    }
+    FaMenuEvent::send_event(&mut writer, item.owner, id_q.get(item.owner).ok(), &item.key, checked);
+    menu_state.close_all();
+}
+
+/// Clicking an item of a menu bar opens its dropdown below it, or closes it if it's open.
+pub(crate) fn on_bar_item_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    bar_item_q: Query<(&MenuBarItem, &GlobalTransform, &ComputedNode)>,
+    id_q: Query<&WidgetId>,
+    mut menu_state: ResMut<FaMenuState>,
+    mut writer: EventWriter<FaMenuEvent>
+) {
+    trigger.propagate(false);
+
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let Ok((bar_item, transform, computed)) = bar_item_q.get(trigger.target()) else { return };
+
+    match bar_item.dropdown {
+        Some(dropdown) if menu_state.is_open(dropdown) => menu_state.close_all(),
+        Some(dropdown) => {
+            let rect = node_rect(transform, computed);
+            menu_state.open_at(dropdown, Vec2::new(rect.min.x, rect.max.y));
+        }
+        None => {
+            FaMenuEvent::send_event(&mut writer, bar_item.bar, id_q.get(bar_item.bar).ok(), &bar_item.key, None);
+            menu_state.close_all();
+        }
+    }
+}
+
+/// While a dropdown of the menu bar is open, hovering another item opens its dropdown instead.
+pub(crate) fn on_bar_item_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut bar_item_q: Query<(&MenuBarItem, &mut BackgroundColor, &GlobalTransform, &ComputedNode)>,
+    bar_q: Query<&MenuBarDropdowns>,
+    mut menu_state: ResMut<FaMenuState>
+) {
+    if let Ok((bar_item, mut bg_color, transform, computed)) = bar_item_q.get_mut(trigger.target()) {
+        bg_color.0 = ITEM_ON_HOVER_BG_COLOR;
+
+        let bar_is_open = bar_q
+            .get(bar_item.bar)
+            .is_ok_and(|dropdowns| menu_state.open.first().is_some_and(|(menu, _)| dropdowns.0.contains(menu)));
+
+        if bar_is_open
+            && let Some(dropdown) = bar_item.dropdown
+            && !menu_state.is_open(dropdown)
+        {
+            let rect = node_rect(transform, computed);
+            menu_state.open_at(dropdown, Vec2::new(rect.min.x, rect.max.y));
+        }
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_bar_item_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut bar_item_q: Query<&mut BackgroundColor, With<MenuBarItem>>
+) {
+    if let Ok(mut bg_color) = bar_item_q.get_mut(trigger.target()) {
+        bg_color.0 = ITEM_NORMAL_BG_COLOR;
+    }
+    trigger.propagate(false);
+}
+
+/// Right click opens the context menu of the closest widget, or one of its ancestors,
+/// matching `context_of` of a menu.
+pub(crate) fn on_context_menu_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    menu_q: Query<(Entity, &MenuContextOf)>,
+    widget_q: Query<(Option<&WidgetId>, Option<&WidgetClasses>)>,
+    parent_q: Query<&ChildOf>,
+    mut menu_state: ResMut<FaMenuState>
+) {
+    let event = trigger.event();
+
+    // handled once for the original target, not again while bubbling up
+    if event.button != PointerButton::Secondary || trigger.target() != event.target {
+        return;
+    }
+    let menu = std::iter::once(event.target)
+        .chain(parent_q.iter_ancestors(event.target))
+        .find_map(|entity| {
+            let (id, classes) = widget_q.get(entity).ok()?;
+            menu_q
+                .iter()
+                .find(|(_, context)| matches_selector(&context.0, id, classes))
+                .map(|(menu, _)| menu)
+        });
+
+    if let Some(menu) = menu {
+        menu_state.open_at(menu, event.pointer_location.position);
+    }
+}
+
+/// Close menus on Escape, or when a mouse button is pressed outside of them.
+pub(crate) fn handle_menu_closing(
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window>,
+    menu_q: Query<(&GlobalTransform, &ComputedNode), With<IsFamiqMenu>>,
+    mut menu_state: ResMut<FaMenuState>
+) {
+    let escape = evr_kbd
+        .read()
+        .any(|e| e.state == ButtonState::Pressed && e.key_code == KeyCode::Escape);
+
+    if menu_state.open.is_empty() {
+        return;
+    }
+    // menus opened by this press aren't laid out yet
+    if menu_state.just_opened {
+        menu_state.just_opened = false;
+        return;
+    }
+    let cursor_on_menu = window.cursor_position().is_some_and(|cursor| {
+        menu_state.open.iter().any(|(menu, _)| {
+            menu_q
+                .get(*menu)
+                .is_ok_and(|(transform, computed)| node_rect(transform, computed).contains(cursor))
+        })
+    });
+    let pressed_outside = mouse.get_just_pressed().next().is_some() && !cursor_on_menu;
+
+    if escape || pressed_outside {
+        menu_state.close_all();
+    }
+}
+
+/// Internal system to show open menus at their position & hide the others.
+pub(crate) fn update_menu_visibility(
+    menu_state: Res<FaMenuState>,
+    mut menu_q: Query<(Entity, &mut Node), With<IsFamiqMenu>>,
+    mut item_q: Query<(&MenuItemState, &mut BackgroundColor)>
+) {
+    if !menu_state.is_changed() {
+        return;
+    }
+    for (entity, mut node) in menu_q.iter_mut() {
+        match menu_state.open.iter().find(|(menu, _)| *menu == entity) {
+            Some((_, position)) => {
+                node.display = Display::Flex;
+                node.left = Val::Px(position.x);
+                node.top = Val::Px(position.y);
+            }
+            None if node.display != Display::None => node.display = Display::None,
+            None => {}
+        }
+    }
+    // items hovered while their menu was closed
+    for (item, mut bg_color) in item_q.iter_mut() {
+        if !menu_state.is_open(item.menu) && bg_color.0 != ITEM_NORMAL_BG_COLOR {
+            bg_color.0 = ITEM_NORMAL_BG_COLOR;
+        }
+    }
+}
+
+/// Move open menus crossing the right or bottom edge of the window back inside it.
+pub(crate) fn keep_menus_in_window(
+    window: Single<&Window>,
+    mut menu_q: Query<(&mut Node, &ComputedNode), With<IsFamiqMenu>>
+) {
+    for (mut node, computed) in menu_q.iter_mut() {
+        if node.display == Display::None {
+            continue;
+        }
+        let size = computed.size() * computed.inverse_scale_factor();
+        let max_left = (window.width() - size.x).max(0.0);
+        let max_top = (window.height() - size.y).max(0.0);
+
+        if let Val::Px(left) = node.left
+            && left > max_left
+        {
+            node.left = Val::Px(max_left);
+        }
+        if let Val::Px(top) = node.top
+            && top > max_top
+        {
+            node.top = Val::Px(max_top);
+        }
+    }
+}
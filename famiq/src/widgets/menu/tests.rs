@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use crate::widgets::FamiqResource;
+use crate::widgets::container::ContainerBuilder;
+use crate::{container, menu, menu_bar};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::ecs::system::RunSystemOnce;
+use super::*;
+
+fn setup_test_menu(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    container!(id: "#editor", class: "panel dark");
+    menu!(
+        id: "#test-menu",
+        context_of: ".panel",
+        items: [
+            MenuItem::new("Copy").shortcut("Ctrl+C"),
+            MenuItem::separator(),
+            MenuItem::new("Word wrap").id("wrap").checkable(true),
+            MenuItem::new("Encoding").submenu(vec![MenuItem::new("UTF-8"), MenuItem::new("ASCII")])
+        ]
+    );
+    menu_bar!(
+        items: [
+            MenuItem::new("File").submenu(vec![MenuItem::new("Open")]),
+            MenuItem::new("Help")
+        ]
+    );
+}
+
+fn setup_test_hidden_menu(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_bool("show_menus", true);
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    menu!(
+        show_if: "show_menus",
+        context_of: ".panel",
+        items: [MenuItem::new("Encoding").submenu(vec![MenuItem::new("UTF-8").submenu(vec![MenuItem::new("BOM")])])]
+    );
+    menu_bar!(
+        show_if: "show_menus",
+        items: [MenuItem::new("File").submenu(vec![MenuItem::new("Recent").submenu(vec![MenuItem::new("a.txt")])])]
+    );
+}
+
+fn menu_entity(app: &mut App) -> Entity {
+    app.world_mut()
+        .query_filtered::<Entity, (With<IsFamiqMenu>, With<MenuContextOf>)>()
+        .single(app.world())
+        .unwrap()
+}
+
+fn item_keys(app: &mut App, menu: Entity) -> Vec<String> {
+    app.world_mut()
+        .query::<&MenuItemState>()
+        .iter(app.world())
+        .filter(|item| item.menu == menu)
+        .map(|item| item.key.clone())
+        .collect()
+}
+
+#[test]
+fn test_menu_items_and_submenus() {
+    let mut app = create_famiq_test_app(setup_test_menu);
+    let menu = menu_entity(&mut app);
+    assert_eq!(vec!["Copy", "wrap", "Encoding"], item_keys(&mut app, menu));
+
+    let submenu = app.world_mut()
+        .query::<&MenuItemState>()
+        .iter(app.world())
+        .find(|item| item.key == "Encoding")
+        .and_then(|item| item.submenu)
+        .unwrap();
+    assert_eq!(vec!["UTF-8", "ASCII"], item_keys(&mut app, submenu));
+
+    // items of submenus & dropdowns send events with their menu or menu bar
+    let owners: Vec<Entity> = app.world_mut()
+        .query::<&MenuItemState>()
+        .iter(app.world())
+        .map(|item| item.owner)
+        .collect();
+    let bar = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqMenuBar>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(5, owners.iter().filter(|owner| **owner == menu).count());
+    assert_eq!(1, owners.iter().filter(|owner| **owner == bar).count());
+    assert_eq!(1, app.world().get::<MenuBarDropdowns>(bar).unwrap().0.len());
+}
+
+#[test]
+fn test_menu_open_and_close_on_escape() {
+    let mut app = create_famiq_test_app(setup_test_menu);
+    let menu = menu_entity(&mut app);
+    assert_eq!(Display::None, app.world().get::<Node>(menu).unwrap().display);
+
+    app.world_mut().resource_mut::<FaMenuState>().open_at(menu, Vec2::new(40.0, 30.0));
+    app.update();
+    let node = app.world().get::<Node>(menu).unwrap();
+    assert_eq!(Display::Flex, node.display);
+    assert_eq!(Val::Px(40.0), node.left);
+    assert_eq!(Val::Px(30.0), node.top);
+
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::Escape,
+        logical_key: Key::Escape,
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER
+    });
+    app.update();
+    assert!(!app.world().resource::<FaMenuState>().is_open(menu));
+    assert_eq!(Display::None, app.world().get::<Node>(menu).unwrap().display);
+}
+
+#[test]
+fn test_menu_submenu_state_and_selectors() {
+    let (menu, submenu, other) = (Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3));
+    let mut state = FaMenuState::default();
+    assert_eq!(None, state.with_submenu(menu, None));
+
+    state.open_at(menu, Vec2::ZERO);
+    state.open = state.with_submenu(menu, Some((submenu, Vec2::ONE))).unwrap();
+    assert!(state.is_open(submenu));
+
+    // hovering another item of the menu closes its submenu
+    state.open = state.with_submenu(menu, Some((other, Vec2::ONE))).unwrap();
+    assert!(!state.is_open(submenu));
+    assert_eq!(vec![menu, other], state.open.iter().map(|(m, _)| *m).collect::<Vec<_>>());
+
+    let id = WidgetId("#editor".to_string());
+    let classes = WidgetClasses("panel dark".to_string());
+    assert!(matches_selector("#editor", Some(&id), None));
+    assert!(matches_selector(".dark", None, Some(&classes)));
+    assert!(!matches_selector(".pane", Some(&id), Some(&classes)));
+    assert!(!matches_selector("editor", Some(&id), Some(&classes)));
+}
+
+#[test]
+fn test_menu_popups_despawned_with_show_if() {
+    let mut app = create_famiq_test_app(setup_test_hidden_menu);
+    app.update();
+
+    let count = |app: &mut App| {
+        let menus = app.world_mut().query::<&IsFamiqMenu>().iter(app.world()).count();
+        let items = app.world_mut().query::<&MenuItemState>().iter(app.world()).count();
+        (menus, items)
+    };
+    // menu & its 2 submenus, dropdown of the bar & its submenu
+    assert_eq!((5, 5), count(&mut app));
+
+    let menu = menu_entity(&mut app);
+    let submenu = app.world().get::<MenuPopups>(menu).unwrap().0[0];
+    app.world_mut().resource_mut::<FaMenuState>().open_at(menu, Vec2::ZERO);
+    app.world_mut().resource_mut::<FaMenuState>().open.push((submenu, Vec2::ONE));
+
+    let set_shown = |app: &mut App, shown: bool| {
+        app.world_mut()
+            .run_system_once(move |mut fa_query: FaQuery| fa_query.mutate_bool("show_menus", shown))
+            .unwrap();
+        app.update();
+        app.update();
+    };
+    set_shown(&mut app, false);
+    assert_eq!((0, 0), count(&mut app));
+    assert!(app.world().resource::<FaMenuState>().open.is_empty());
+
+    // shown again, without popups left from before
+    set_shown(&mut app, true);
+    assert_eq!((5, 5), count(&mut app));
+}
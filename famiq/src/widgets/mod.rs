@@ -3,18 +3,29 @@
 pub mod scroll;
 pub mod button;
 pub mod color;
+pub mod conditional;
 pub mod container;
+pub mod for_each;
 pub mod fps;
 pub mod selection;
 pub mod style;
 pub mod style_parse;
 pub mod text;
 pub mod text_input;
+pub mod textarea;
 pub mod circular;
 pub mod dialog;
 pub mod image;
 pub mod progress_bar;
 pub mod checkbox;
+pub mod slider;
+pub mod radio;
+pub mod switch;
+pub mod tabs;
+pub mod table;
+pub mod tree;
+pub mod menu;
+pub mod notification;
 pub mod tests;
 pub mod base_components;
 
@@ -23,6 +34,7 @@ pub use base_components::*;
 
 use crate::resources::*;
 use crate::reactivity::*;
+use crate::errors::{ComputedKeyErr, FamiqError};
 use crate::utils::*;
 
 use bevy::ecs::system::{EntityCommands, SystemParam};
@@ -104,7 +116,11 @@ pub struct WidgetAttributes {
     pub(crate) default_z_index: ZIndex,
     pub(crate) overrided_background_color: Option<Color>,
     pub(crate) overrided_border_color: Option<Color>,
-    pub(crate) override_text_size: Option<f32>
+    pub(crate) override_text_size: Option<f32>,
+    /// Patch reactive `color` directly, for widgets colored only by their main entity.
+    pub(crate) bind_color: bool,
+    /// Fields patched directly when their reactive keys change.
+    pub bindings: Vec<ReactiveBinding>
 }
 
 pub trait SetWidgetAttributes: Sized {
@@ -113,20 +129,21 @@ pub trait SetWidgetAttributes: Sized {
     fn cloned_attrs(&mut self) -> &mut WidgetAttributes;
 
     fn set_model(&mut self, model_key: &str) {
-        self.attributes().model_key = Some(model_key.to_string());
+        self.attributes().model_key = Some(resolve_key_alias(model_key));
     }
 
     fn set_id(&mut self, id: &str) {
-        self.attributes().id = Some(id.to_string());
+        self.attributes().id = Some(resolve_template_alias(id));
     }
 
     fn set_class(&mut self, class: &str) {
-        self.attributes().class = Some(class.to_string());
+        let class = resolve_template_alias(class);
         self.attributes().class_split = class.split_whitespace().map(|s| s.to_string()).collect();
+        self.attributes().class = Some(class);
     }
 
     fn set_color(&mut self, color: &str) {
-        self.attributes().color = WidgetColor::Custom(color.to_string());
+        self.attributes().color = WidgetColor::Custom(resolve_template_alias(color));
     }
 
     fn set_size(&mut self, size: f32) {
@@ -134,20 +151,20 @@ pub trait SetWidgetAttributes: Sized {
     }
 
     fn set_width(&mut self, width: &str) {
-        self.attributes().width = Some(width.to_string());
+        self.attributes().width = Some(resolve_template_alias(width));
     }
 
     fn set_height(&mut self, height: &str) {
-        self.attributes().height = Some(height.to_string());
+        self.attributes().height = Some(resolve_template_alias(height));
     }
 
     fn set_display(&mut self, display: &str) {
-        self.attributes().display = Some(display.to_string());
+        self.attributes().display = Some(resolve_template_alias(display));
     }
 
     fn set_tooltip(&mut self, text: &str) {
         self.attributes().has_tooltip = true;
-        self.attributes().tooltip_text = text.to_string();
+        self.attributes().tooltip_text = resolve_template_alias(text);
     }
 
     fn _process_built_in_color_class(&mut self) {
@@ -338,7 +355,16 @@ pub enum WidgetType {
     ProgressBar,
     Dialog, // globalzindex 5
     Image,
-    BackgroudImage
+    BackgroudImage,
+    Slider,
+    Radio,
+    Switch,
+    TextArea,
+    Tabs,
+    Table,
+    Tree,
+    Menu, // globalzindex 7
+    MenuBar
 }
 
 /// Root builder, allows access to AssetServer, root_node, FamiqResource and RData.
@@ -608,6 +634,7 @@ pub struct FaQuery<'w, 's> {
     pub commands: Commands<'w, 's>,
     pub asset_server: Res<'w, AssetServer>,
     pub reactive_subscriber: ResMut<'w, RSubscriber>,
+    pub persist: Option<ResMut<'w, RPersist>>,
     // pub containable_children: ResMut<'w, ContainableChildren>
 }
 
@@ -661,9 +688,9 @@ impl<'w, 's> FaQuery<'w, 's> {
         }
     }
 
-    /// Insert new key-value into reactive data.
-    pub fn insert_data(&mut self, key: &str, value: RVal) {
-        self.reactive_data.data.insert(key.to_string(), value);
+    /// Insert new key-value into reactive data, value can be `RVal` or anything implementing `IntoRVal`.
+    pub fn insert_data(&mut self, key: &str, value: impl IntoRVal) {
+        self.reactive_data.data.insert(key.to_string(), value.into_rval());
     }
 
     /// Insert into reactive data as RVal::Str
@@ -696,16 +723,76 @@ impl<'w, 's> FaQuery<'w, 's> {
         self.insert_data(key, RVal::List(value));
     }
 
-    /// Explicitly mutates specific key.
-    pub fn mutate_data(&mut self, key: &str, new_val: RVal) {
-        let old_val = self.reactive_data.data.get(key);
-        if old_val.is_none() {
-            panic!("\n[FamiqError]: mutate_data, key {:?} not found\n", key);
+    /// Insert into reactive data as `RVal::Map`
+    pub fn insert_map(&mut self, key: &str, value: HashMap<String, RVal>) {
+        self.insert_data(key, RVal::Map(value));
+    }
+
+    /// Insert into reactive data as `RVal::Array`
+    pub fn insert_array(&mut self, key: &str, value: Vec<RVal>) {
+        self.insert_data(key, RVal::Array(value));
+    }
+
+    /// Insert a computed key, its value is derived from `deps` using `compute`
+    /// and it's recomputed whenever any of `deps` changes.
+    ///
+    /// Returns error if the dependencies form a cycle.
+    pub fn insert_computed(
+        &mut self,
+        key: &str,
+        deps: &[&str],
+        compute: impl Fn(&HashMap<String, RVal>) -> RVal + Send + Sync + 'static
+    ) -> Result<(), ComputedKeyErr> {
+        self.reactive_data.insert_computed(key, deps, compute)
+    }
+
+    /// Run `system` when `key` changes, see `WatchMode` for when it runs.
+    pub fn watch<M>(
+        &mut self,
+        key: &str,
+        mode: WatchMode,
+        system: impl IntoSystem<In<ReactiveDataChanged>, (), M> + Send + 'static
+    ) {
+        let key = key.to_string();
+        self.commands.queue(move |w: &mut World| {
+            add_watcher(w, &key, mode, system);
+        });
+    }
+
+    /// Keep `key` between runs, requires `FamiqPlugin::with_persistence`.
+    /// Its saved value replaces the current one, call it after inserting the key
+    /// and before building widgets so they start with the restored value.
+    ///
+    /// Panics if persistence is not enabled, see `try_persist`.
+    pub fn persist(&mut self, key: &str) {
+        if let Err(e) = self.try_persist(key) {
+            panic!("\n[FamiqError]: persist, {}\n", e);
         }
-        if !self.reactive_data.changed_keys.contains(&key.to_string()) {
-            self.reactive_data.changed_keys.push(key.to_string());
+    }
+
+    /// Same as `persist`, returns error if persistence is not enabled.
+    pub fn try_persist(&mut self, key: &str) -> Result<(), FamiqError> {
+        let Some(persist) = self.persist.as_mut() else {
+            return Err(FamiqError::PersistenceDisabled);
+        };
+        persist.persist(key, &mut self.reactive_data);
+        Ok(())
+    }
+
+    /// Explicitly mutates specific key or path. Eg, "player.stats.hp".
+    ///
+    /// Only widgets subscribed to the path, its parents or its sub paths get rebuilt.
+    ///
+    /// Panics if the key doesn't exist, see `try_mutate`.
+    pub fn mutate_data(&mut self, key: &str, new_val: impl IntoRVal) {
+        if self.try_mutate(key, new_val).is_err() {
+            panic!("\n[FamiqError]: mutate_data, key {:?} not found\n", key);
         }
-        self.reactive_data.data.insert(key.to_string(), new_val);
+    }
+
+    /// Same as `mutate_data`, returns error if the key doesn't exist.
+    pub fn try_mutate(&mut self, key: &str, new_val: impl IntoRVal) -> Result<(), FamiqError> {
+        self.reactive_data.try_mutate(key, new_val)
     }
 
     /// Explicitly mutates specific key as RVal::Str
@@ -738,23 +825,52 @@ impl<'w, 's> FaQuery<'w, 's> {
         self.mutate_data(key, RVal::List(new_list));
     }
 
-    /// Get value of provided key.
+    /// Get value of provided key or path.
     pub fn get_data(&self, key: &str) -> Option<&RVal> {
-        if let Some(val) = self.reactive_data.data.get(key) {
-            return Some(val);
-        }
-        None
+        get_reactive_value(&self.reactive_data.data, key)
+    }
+
+    /// Same as `get_data`, returns error if the key doesn't exist.
+    pub fn try_get_data(&self, key: &str) -> Result<&RVal, FamiqError> {
+        self.get_data(key).ok_or_else(|| FamiqError::KeyNotFound(key.to_string()))
+    }
+
+    /// Get value of provided key or path converted into `T`, eg. `fa_query.get::<u32>("score")`.
+    /// None if the key doesn't exist or can't be converted.
+    pub fn get<T: FromRVal>(&self, key: &str) -> Option<T> {
+        self.try_get(key).ok()
+    }
+
+    /// Same as `get`, returns `KeyNotFound` or `TypeMismatch` error instead of None.
+    pub fn try_get<T: FromRVal>(&self, key: &str) -> Result<T, FamiqError> {
+        T::from_rval(self.try_get_data(key)?)
     }
 
-    /// Get mutable value of provided key.
+    /// Get mutable value of provided key or path.
     pub fn get_data_mut(&mut self, key: &str) -> Option<&mut RVal> {
-        if self.get_data(key).is_none() {
-            return None;
-        }
-        if !self.reactive_data.changed_keys.contains(&key.to_string()) {
-            self.reactive_data.changed_keys.push(key.to_string());
-        }
-        self.reactive_data.data.get_mut(key)
+        self.get_data(key)?;
+        self.reactive_data.mark_changed(key);
+        get_reactive_value_mut(&mut self.reactive_data.data, key)
+    }
+
+    /// Insert local keys of `scope`. Widgets inside `container!(scope: ...)`
+    /// use them instead of global keys with the same name.
+    pub fn insert_scope<'k>(&mut self, scope: &str, locals: impl IntoIterator<Item = (&'k str, RVal)>) {
+        let locals = locals
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+        self.insert_data(&scope_key(scope), RVal::Map(locals));
+    }
+
+    /// Get value of local key of `scope`.
+    pub fn get_scoped(&self, scope: &str, key: &str) -> Option<&RVal> {
+        self.get_data(&scoped_path(scope, key))
+    }
+
+    /// Mutate local key of `scope`, other scopes with the same key are untouched.
+    pub fn mutate_scoped(&mut self, scope: &str, key: &str, new_val: impl IntoRVal) {
+        self.mutate_data(&scoped_path(scope, key), new_val);
     }
 }
 
@@ -788,6 +904,15 @@ macro_rules! extract_children {
     ($vec:ident,) => {{}};
 }
 
+/// Macro to extract scope name from scope attribute.
+#[macro_export]
+macro_rules! extract_scope {
+    ($scope:ident, scope: $val:expr) => {{
+        $scope = Some($val.to_string());
+    }};
+    ($scope:ident, $key:ident : $val:tt) => {{}};
+}
+
 /// Macro for setting common attributes to a widget.
 #[macro_export]
 macro_rules! common_attributes {
@@ -813,12 +938,23 @@ pub enum BuilderType {
     Checkbox(checkbox::CheckboxBuilder),
     Circular(circular::CircularBuilder),
     Container(container::ContainerBuilder),
+    ForEach(for_each::ForEachBuilder),
     Fps(fps::FpsBuilder),
     Image(image::ImageBuilder),
     Dialog(dialog::DialogBuilder),
     ProgressBar(progress_bar::ProgressBarBuilder),
     Selection(selection::SelectionBuilder),
-    Scroll(scroll::ScrollBuilder)
+    Scroll(scroll::ScrollBuilder),
+    Radio(radio::RadioBuilder),
+    Slider(slider::SliderBuilder),
+    Switch(switch::SwitchBuilder),
+    TextInput(text_input::TextInputBuilder),
+    TextArea(textarea::TextAreaBuilder),
+    Tabs(tabs::TabsBuilder),
+    Table(table::TableBuilder),
+    Tree(tree::TreeBuilder),
+    Menu(menu::MenuBuilder),
+    MenuBar(menu::MenuBarBuilder)
 }
 
 #[derive(Clone, Debug)]
@@ -826,8 +962,142 @@ pub struct WidgetBuilder {
     pub builder: BuilderType
 }
 
+impl WidgetBuilder {
+    /// Rebuild the widget with latest reactive data.
+    pub fn rebuild(self, r_data: &HashMap<String, RVal>, entity: Entity, world: &mut World) {
+        match self.builder {
+            BuilderType::Button(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Text(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Checkbox(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Circular(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::ProgressBar(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Slider(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Radio(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Switch(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Fps(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Image(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Scroll(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Selection(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Container(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::ForEach(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Dialog(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::TextInput(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::TextArea(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Tabs(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Table(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Tree(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::Menu(mut builder) => builder.rebuild(r_data, entity, world),
+            BuilderType::MenuBar(mut builder) => builder.rebuild(r_data, entity, world),
+        }
+    }
+
+    /// Field bindings recorded while the widget was built.
+    pub fn bindings(&self) -> &[ReactiveBinding] {
+        match &self.builder {
+            BuilderType::Button(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Text(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Checkbox(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Circular(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::ProgressBar(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Slider(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Radio(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Switch(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Fps(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Image(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Scroll(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Selection(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Container(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::ForEach(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Dialog(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::TextInput(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::TextArea(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Tabs(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Table(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Tree(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::Menu(builder) => &builder.cloned_attrs.bindings,
+            BuilderType::MenuBar(builder) => &builder.cloned_attrs.bindings,
+        }
+    }
+}
+
 thread_local! {
     static GLOBAL_BUILDER: RefCell<Option<*mut ()>> = RefCell::new(None);
+
+    /// (alias, path) used while building widgets, innermost last.
+    static TEMPLATE_ALIASES: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Build widgets with `alias` pointing to `path`. Any `$[alias]` or `$[alias.field]`
+/// template, and `alias` model key, given to widgets created inside `f` point to `path` instead.
+pub fn with_template_alias<T>(alias: &str, path: &str, f: impl FnOnce() -> T) -> T {
+    with_template_aliases(&[(alias.to_string(), path.to_string())], f)
+}
+
+/// Aliases in use right now. Widgets building their children later
+/// (eg. `for_each!` rows) keep them to build with the same aliases.
+pub(crate) fn current_template_aliases() -> Vec<(String, String)> {
+    TEMPLATE_ALIASES.with(|cell| cell.borrow().clone())
+}
+
+/// Build widgets with all given aliases, see `with_template_alias`.
+pub fn with_template_aliases<T>(aliases: &[(String, String)], f: impl FnOnce() -> T) -> T {
+    let len = TEMPLATE_ALIASES.with(|cell| {
+        let mut cell = cell.borrow_mut();
+        let len = cell.len();
+        cell.extend(aliases.iter().cloned());
+        len
+    });
+    let result = f();
+    TEMPLATE_ALIASES.with(|cell| {
+        cell.borrow_mut().truncate(len);
+    });
+    result
+}
+
+fn resolve_alias_path(key: &str, aliases: &[(String, String)]) -> Option<String> {
+    for (alias, path) in aliases.iter().rev() {
+        if key == alias {
+            return Some(path.clone());
+        }
+        if is_sub_path(key, alias) {
+            return Some(format!("{}{}", path, &key[alias.len()..]));
+        }
+    }
+    None
+}
+
+/// Resolve alias of a reactive key or path, eg "item.name" -> "todos.0.name".
+pub fn resolve_key_alias(key: &str) -> String {
+    TEMPLATE_ALIASES.with(|cell| {
+        resolve_alias_path(key, &cell.borrow()).unwrap_or_else(|| key.to_string())
+    })
+}
+
+/// Resolve alias of keys inside an expression, eg "item.price * 2" -> "todos.0.price * 2".
+pub fn resolve_expression_alias(expression: &str) -> String {
+    TEMPLATE_ALIASES.with(|cell| {
+        let aliases = cell.borrow();
+        if aliases.is_empty() {
+            return expression.to_string();
+        }
+        if let Some(path) = resolve_alias_path(expression.trim(), &aliases) {
+            return path;
+        }
+        map_expression_keys(expression, |key| resolve_alias_path(key, &aliases))
+            .unwrap_or_else(|| expression.to_string())
+    })
+}
+
+/// Resolve alias of keys inside template, eg "$[item.name]" -> "$[todos.0.name]".
+pub fn resolve_template_alias(template: &str) -> String {
+    if !template.contains("$[") {
+        return template.to_string();
+    }
+    REACTIVE_REGEX
+        .replace_all(template, |caps: &regex::Captures| {
+            format!("$[{}]", resolve_expression_alias(&caps[1]))
+        })
+        .into_owned()
 }
 
 /// Inject root builder for global access.
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+/// Marker component for identifying the overlay node holding toasts.
+#[derive(Component)]
+pub struct IsFamiqNotificationLayer;
+
+/// A toast of `Notifications`, dismissed once its timer finishes.
+#[derive(Component, Debug)]
+pub struct Toast {
+    /// paused while hovered
+    pub timer: Timer,
+    pub hovered: bool,
+    /// animating out, despawned once hidden
+    pub leaving: bool,
+    pub bg_color: Color,
+    pub text_color: Color,
+    pub text_entity: Entity
+}
@@ -0,0 +1,83 @@
+pub mod components;
+pub mod styling;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub(crate) use styling::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::widgets::dialog::AnimationProgress;
+use crate::resources::FamiqResource;
+use crate::utils::*;
+use crate::widgets::*;
+
+/// Corner of the window where toasts are stacked.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub enum NotificationCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight
+}
+
+impl NotificationCorner {
+    fn is_right(&self) -> bool {
+        matches!(self, NotificationCorner::TopRight | NotificationCorner::BottomRight)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct PendingNotification {
+    pub level: WidgetColor,
+    pub text: String,
+    pub duration: Duration
+}
+
+/// Transient notifications (toasts), shown above every widget.
+///
+/// ```text
+/// fn on_save(mut notifications: ResMut<Notifications>) {
+///     notifications.notify(WidgetColor::Success, "Saved", Duration::from_secs(3));
+/// }
+/// ```
+#[derive(Resource, Debug)]
+pub struct Notifications {
+    /// max toasts visible at once, others wait until one is dismissed
+    pub max_visible: usize,
+    pub corner: NotificationCorner,
+    pub(crate) pending: VecDeque<PendingNotification>
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self {
+            max_visible: 4,
+            corner: NotificationCorner::default(),
+            pending: VecDeque::new()
+        }
+    }
+}
+
+impl Notifications {
+    /// Show a toast colored by `level`, eg. `WidgetColor::Danger`, dismissed after `duration`.
+    pub fn notify(&mut self, level: WidgetColor, text: &str, duration: Duration) {
+        self.pending.push_back(PendingNotification {
+            level,
+            text: text.to_string(),
+            duration
+        });
+    }
+}
+
+/// Determines if notification internal system(s) can run.
+///
+/// True only if there is a toast shown or waiting to be shown.
+pub fn can_run_notification_systems(notifications: Res<Notifications>, toast_q: Query<&Toast>) -> bool {
+    !notifications.pending.is_empty() || !toast_q.is_empty()
+}
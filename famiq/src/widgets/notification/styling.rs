@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use super::NotificationCorner;
+
+/// Above menus.
+pub(crate) const NOTIFICATION_Z_INDEX: i32 = 8;
+
+/// Distance toasts slide in from the side of their corner.
+pub(crate) const TOAST_SLIDE_DISTANCE: f32 = 40.0;
+
+pub fn default_notification_layer_node(corner: NotificationCorner) -> Node {
+    let (flex_direction, align_items) = match corner {
+        NotificationCorner::TopLeft => (FlexDirection::Column, AlignItems::FlexStart),
+        NotificationCorner::TopRight => (FlexDirection::Column, AlignItems::FlexEnd),
+        NotificationCorner::BottomLeft => (FlexDirection::ColumnReverse, AlignItems::FlexStart),
+        NotificationCorner::BottomRight => (FlexDirection::ColumnReverse, AlignItems::FlexEnd),
+    };
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        position_type: PositionType::Absolute,
+        flex_direction,
+        align_items,
+        justify_content: JustifyContent::FlexStart,
+        padding: UiRect::all(Val::Px(16.0)),
+        row_gap: Val::Px(8.0),
+        ..default()
+    }
+}
+
+pub fn default_toast_node() -> Node {
+    Node {
+        min_width: Val::Px(200.0),
+        max_width: Val::Px(360.0),
+        padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+        ..default()
+    }
+}
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+
+use super::*;
+
+pub(crate) fn on_toast_over(mut trigger: Trigger<Pointer<Over>>, mut toast_q: Query<&mut Toast>) {
+    if let Ok(mut toast) = toast_q.get_mut(trigger.target()) {
+        toast.hovered = true;
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_toast_out(mut trigger: Trigger<Pointer<Out>>, mut toast_q: Query<&mut Toast>) {
+    if let Ok(mut toast) = toast_q.get_mut(trigger.target()) {
+        toast.hovered = false;
+    }
+    trigger.propagate(false);
+}
+
+/// Spawn pending notifications as toasts while fewer than `max_visible` are shown.
+/// The overlay layer is spawned along with the first toast.
+pub(crate) fn spawn_notifications(
+    mut notifications: ResMut<Notifications>,
+    layer_q: Query<Entity, With<IsFamiqNotificationLayer>>,
+    toast_q: Query<&Toast>,
+    famiq_res: Res<FamiqResource>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands
+) {
+    if notifications.pending.is_empty() {
+        return;
+    }
+    let mut visible = toast_q.iter().filter(|toast| !toast.leaving).count();
+
+    if visible >= notifications.max_visible {
+        return;
+    }
+    let layer = layer_q.iter().next().unwrap_or_else(|| {
+        commands
+            .spawn((
+                default_notification_layer_node(notifications.corner),
+                IsFamiqNotificationLayer,
+                GlobalZIndex(NOTIFICATION_Z_INDEX),
+                Pickable::IGNORE
+            ))
+            .id()
+    });
+    let font = TextFont {
+        font: asset_server.load(&famiq_res.font_path),
+        font_size: get_text_size(&WidgetSize::Default),
+        ..default()
+    };
+    let slide = if notifications.corner.is_right() { TOAST_SLIDE_DISTANCE } else { -TOAST_SLIDE_DISTANCE };
+
+    while visible < notifications.max_visible {
+        let Some(pending) = notifications.pending.pop_front() else { break };
+        let bg_color = get_color(&pending.level);
+        let text_color = get_text_color(&pending.level);
+
+        // hidden until animated in
+        let text_entity = commands
+            .spawn((Text::new(pending.text), font.clone(), TextColor(text_color.with_alpha(0.0)), Pickable::IGNORE))
+            .id();
+        let toast_entity = commands
+            .spawn((
+                Node {
+                    left: Val::Px(slide),
+                    ..default_toast_node()
+                },
+                BackgroundColor(bg_color.with_alpha(0.0)),
+                BorderRadius::all(Val::Px(6.0)),
+                AnimationProgress(0.0),
+                Toast {
+                    timer: Timer::new(pending.duration, TimerMode::Once),
+                    hovered: false,
+                    leaving: false,
+                    bg_color,
+                    text_color,
+                    text_entity
+                }
+            ))
+            .observe(on_toast_over)
+            .observe(on_toast_out)
+            .add_child(text_entity)
+            .id();
+        commands.entity(layer).add_child(toast_entity);
+        visible += 1;
+    }
+}
+
+/// Internal system to move the overlay layer when `corner` is changed.
+pub(crate) fn update_notification_layer(
+    notifications: Res<Notifications>,
+    mut layer_q: Query<&mut Node, With<IsFamiqNotificationLayer>>
+) {
+    if notifications.is_changed() {
+        for mut node in layer_q.iter_mut() {
+            *node = default_notification_layer_node(notifications.corner);
+        }
+    }
+}
+
+/// Dismiss toasts once their timer finishes. Timers start once toasts are fully shown
+/// & are paused while toasts are hovered.
+pub(crate) fn tick_notification_timers(mut toast_q: Query<(&mut Toast, &AnimationProgress)>, time: Res<Time>) {
+    for (mut toast, progress) in toast_q.iter_mut() {
+        if toast.hovered || toast.leaving || progress.0 < 1.0 {
+            continue;
+        }
+        if toast.timer.tick(time.delta()).finished() {
+            toast.leaving = true;
+        }
+    }
+}
+
+/// Slide & fade toasts in, or out before despawning them once dismissed.
+pub(crate) fn animate_notifications(
+    mut toast_q: Query<(Entity, &Toast, &mut AnimationProgress, &mut Node, &mut BackgroundColor)>,
+    mut text_q: Query<&mut TextColor>,
+    notifications: Res<Notifications>,
+    time: Res<Time>,
+    mut commands: Commands
+) {
+    let delta = time.delta_secs() * 7.0;
+    let slide = if notifications.corner.is_right() { TOAST_SLIDE_DISTANCE } else { -TOAST_SLIDE_DISTANCE };
+
+    for (entity, toast, mut progress, mut node, mut bg_color) in toast_q.iter_mut() {
+        let old_progress = progress.0;
+
+        if toast.leaving {
+            progress.0 = (progress.0 - delta).max(0.0);
+
+            if progress.0 == 0.0 {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        } else {
+            progress.0 = (progress.0 + delta).min(1.0);
+        }
+        if old_progress == progress.0 {
+            continue;
+        }
+        node.left = Val::Px(slide * (1.0 - progress.0));
+        bg_color.0 = toast.bg_color.with_alpha(toast.bg_color.alpha() * progress.0);
+
+        if let Ok(mut text_color) = text_q.get_mut(toast.text_entity) {
+            text_color.0 = toast.text_color.with_alpha(toast.text_color.alpha() * progress.0);
+        }
+    }
+}
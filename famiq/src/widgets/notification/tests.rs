@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::FamiqPlugin;
+use crate::widgets::color::SUCCESS_COLOR;
+use super::*;
+
+fn toasts(app: &mut App) -> Vec<(Entity, String)> {
+    let mut toast_q = app.world_mut().query::<(Entity, &Toast)>();
+    let toasts: Vec<(Entity, Entity)> = toast_q
+        .iter(app.world())
+        .map(|(entity, toast)| (entity, toast.text_entity))
+        .collect();
+
+    toasts
+        .into_iter()
+        .map(|(entity, text)| (entity, app.world().get::<Text>(text).unwrap().0.clone()))
+        .collect()
+}
+
+fn notify(app: &mut App, level: WidgetColor, text: &str, duration: Duration) {
+    app.world_mut().resource_mut::<Notifications>().notify(level, text, duration);
+}
+
+#[test]
+fn test_notifications_on_overlay_layer() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.update();
+    notify(&mut app, WidgetColor::Success, "Saved", Duration::from_secs(3));
+    app.update();
+
+    let (toast, text) = toasts(&mut app).pop().unwrap();
+    assert_eq!("Saved", text);
+    assert_eq!(SUCCESS_COLOR, app.world().get::<Toast>(toast).unwrap().bg_color);
+
+    // layer is a separate node above the root node
+    let layer = app.world().get::<ChildOf>(toast).unwrap().parent();
+    assert!(app.world().get::<IsFamiqNotificationLayer>(layer).is_some());
+    assert!(app.world().get::<ChildOf>(layer).is_none());
+    assert_eq!(NOTIFICATION_Z_INDEX, app.world().get::<GlobalZIndex>(layer).unwrap().0);
+}
+
+#[test]
+fn test_notifications_max_visible() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.update();
+    app.world_mut().resource_mut::<Notifications>().max_visible = 2;
+
+    for text in ["first", "second", "third"] {
+        notify(&mut app, WidgetColor::Info, text, Duration::from_secs(5));
+    }
+    app.update();
+    let shown: Vec<String> = toasts(&mut app).into_iter().map(|(_, text)| text).collect();
+    assert_eq!(2, shown.len());
+    assert!(!shown.contains(&"third".to_string()));
+    assert_eq!(1, app.world().resource::<Notifications>().pending.len());
+
+    // a dismissed toast frees its slot while animating out
+    let (first, _) = toasts(&mut app).into_iter().find(|(_, text)| text == "first").unwrap();
+    app.world_mut().get_mut::<Toast>(first).unwrap().leaving = true;
+    app.update();
+    assert!(toasts(&mut app).iter().any(|(_, text)| text == "third"));
+    assert!(app.world().resource::<Notifications>().pending.is_empty());
+}
+
+#[test]
+fn test_notifications_dismiss_and_hover_pause() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.update();
+    notify(&mut app, WidgetColor::Danger, "Connection lost", Duration::from_secs(5));
+    notify(&mut app, WidgetColor::Warning, "Low battery", Duration::from_secs(5));
+    app.update();
+
+    let all = toasts(&mut app);
+    let (lost, _) = *all.iter().find(|(_, text)| text == "Connection lost").unwrap();
+    let (battery, _) = *all.iter().find(|(_, text)| text == "Low battery").unwrap();
+
+    // both timers finish, the hovered toast keeps its timer paused
+    for toast_entity in [lost, battery] {
+        app.world_mut().get_mut::<AnimationProgress>(toast_entity).unwrap().0 = 1.0;
+        app.world_mut().get_mut::<Toast>(toast_entity).unwrap().timer = Timer::new(Duration::ZERO, TimerMode::Once);
+    }
+    app.world_mut().get_mut::<Toast>(battery).unwrap().hovered = true;
+    app.update();
+    assert!(app.world().get::<Toast>(lost).unwrap().leaving);
+    assert!(!app.world().get::<Toast>(battery).unwrap().leaving);
+
+    // despawned once animated out
+    app.world_mut().get_mut::<AnimationProgress>(lost).unwrap().0 = 0.0;
+    app.update();
+    assert!(app.world().get_entity(lost).is_err());
+    assert_eq!(1, toasts(&mut app).len());
+}
@@ -105,7 +105,7 @@ impl ProgressBarBuilder {
             RVal::Str(v) => {
                 let reactive_keys = get_reactive_key(&v);
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::FNum(size) => self.cloned_attrs.size = WidgetSize::Custom(*size),
                             _ => {}
@@ -194,7 +194,7 @@ impl SetupWidget for ProgressBarBuilder {
 /// Macro for creating a progress bar.
 #[macro_export]
 macro_rules! progress_bar {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let p_builder = &mut ProgressBarBuilder::new();
         $(
@@ -205,6 +205,11 @@ macro_rules! progress_bar {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(progress_bar [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -15,7 +15,7 @@ pub fn detect_new_progress_bar(
     bar_q.iter_mut().for_each(|(computed_node, value_entity, model_key)| {
         if let Ok((mut node, value_color, mut percent)) = value_q.get_mut(value_entity.0) {
             if let Some(key) = model_key {
-                if let Some(r_value) = reactive_data.data.get(&key.0) {
+                if let Some(r_value) = get_reactive_value(&reactive_data.data, &key.0) {
                     match r_value {
                         RVal::FNum(v) => {
                             percent.0 = Some(v.to_owned());
@@ -76,7 +76,7 @@ pub fn detect_reactive_model_change(
     if reactive_data.is_changed() && !reactive_data.is_added() {
         for (value_entity, model_key) in bar_q.iter() {
             if let Some(key) = model_key {
-                if let Some(r_value) = reactive_data.data.get(&key.0) {
+                if let Some(r_value) = get_reactive_value(&reactive_data.data, &key.0) {
 
                     if let Ok((mut percent, mut node)) = value_q.get_mut(value_entity.0) {
                         match r_value {
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Marker component for identifying an entity as a Famiq radio group.
+#[derive(Component)]
+pub struct IsFamiqRadio;
+
+#[derive(Component)]
+pub struct IsFamiqRadioItem;
+
+#[derive(Component)]
+pub struct IsFamiqRadioItemCircle;
+
+/// Selected choice of a radio group.
+#[derive(Component, Default, Debug, Clone, PartialEq)]
+pub struct RadioSelected(pub Option<String>);
+
+#[derive(Component)]
+pub struct RadioMainContainerEntity(pub Entity);
+
+#[derive(Component)]
+pub struct RadioItemText(pub String);
+
+#[derive(Component)]
+pub struct RadioItemDotEntity(pub Entity);
+
+#[derive(Component)]
+pub struct RadioItemDisabled(pub bool);
+
+/// Color of the dot of selected choice.
+#[derive(Component)]
+pub struct RadioSelectedColor(pub Color);
@@ -0,0 +1,411 @@
+pub mod styling;
+pub mod components;
+pub mod systems;
+pub mod tests;
+
+pub(crate) use components::*;
+pub(crate) use styling::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+
+use crate::plugin::{CursorType, CursorIcons};
+use crate::widgets::text::base_text::*;
+use crate::widgets::container::base_container::*;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::utils::*;
+use crate::widgets::*;
+use famiq_macros::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct RadioBuilder {
+    /// list of choices, or reactive string of a list key eg. `$[levels]`
+    pub choices: RVal,
+    /// choices that can't be selected, same format as choices
+    pub disabled: RVal,
+    pub all_reactive_keys: Vec<String>,
+    pub vertical: RVal, // align item vertically,
+    pub choice_text_entities: Vec<Entity>,
+    pub choice_circle_entities: Vec<Entity>,
+    pub choice_dot_entities: Vec<Entity>,
+    pub choice_wrapper_entities: Vec<Entity>
+}
+
+impl RadioBuilder {
+    pub fn new(font_handle: &Handle<Font>) -> Self {
+        Self {
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default(),
+            all_reactive_keys: Vec::new(),
+            choices: RVal::Array(Vec::new()),
+            disabled: RVal::Array(Vec::new()),
+            vertical: RVal::Bool(false),
+            choice_text_entities: Vec::new(),
+            choice_circle_entities: Vec::new(),
+            choice_dot_entities: Vec::new(),
+            choice_wrapper_entities: Vec::new()
+        }
+    }
+
+    pub fn set_choices(&mut self, choices: impl IntoRVal) {
+        self.choices = resolve_choices_alias(choices.into_rval());
+    }
+
+    pub fn set_disabled(&mut self, disabled: impl IntoRVal) {
+        self.disabled = resolve_choices_alias(disabled.into_rval());
+    }
+
+    /// Choices with reactive keys replaced. A reactive string is replaced by
+    /// the list it refers to.
+    fn resolve_choices(&mut self, choices: &RVal, r_data: &HashMap<String, RVal>) -> Vec<String> {
+        match choices {
+            RVal::Str(v) => {
+                let reactive_keys = get_reactive_key(v);
+                self.all_reactive_keys.extend_from_slice(&reactive_keys);
+
+                reactive_keys
+                    .iter()
+                    .filter_map(|key| get_reactive_value(r_data, key))
+                    .flat_map(|list| Vec::<String>::from_rval(list).unwrap_or_default())
+                    .collect()
+            }
+            _ => Vec::<String>::from_rval(choices)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|choice| {
+                    let reactive_keys = get_reactive_key(&choice);
+                    self.all_reactive_keys.extend_from_slice(&reactive_keys);
+                    replace_reactive_keys(&choice, &reactive_keys, r_data)
+                })
+                .collect()
+        }
+    }
+
+    fn circle_size(&self) -> f32 {
+        match self.cloned_attrs.size {
+            WidgetSize::Custom(v) => v,
+            _ => get_text_size(&self.cloned_attrs.size)
+        }
+    }
+
+    fn selected_color(&self) -> Color {
+        match self.cloned_attrs.color {
+            WidgetColor::Default => PRIMARY_COLOR,
+            _ => get_color(&self.cloned_attrs.color)
+        }
+    }
+
+    fn choice_text(
+        &mut self,
+        text: &str,
+        disabled: bool,
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) -> Entity {
+        let mut base_text = FaBaseText::new_with_attributes(text, &self.cloned_attrs);
+        base_text.use_get_color = true;
+        let entity = base_text.build(r_data, commands);
+
+        if disabled {
+            let color = get_color(&self.cloned_attrs.color).with_alpha(0.4);
+            commands.entity(entity).insert(TextColor(color));
+        }
+        self.choice_text_entities.push(entity);
+        entity
+    }
+
+    fn rebuild_choice_text(
+        &mut self,
+        text: &str,
+        disabled: bool,
+        old_entity: Entity,
+        r_data: &HashMap<String, RVal>,
+        world: &mut World
+    ) {
+        let mut base_text = FaBaseText::new_with_attributes(text, &self.cloned_attrs);
+        base_text.use_get_color = true;
+        base_text.rebuild(r_data, old_entity, world);
+
+        if disabled {
+            let color = get_color(&self.cloned_attrs.color).with_alpha(0.4);
+            world.entity_mut(old_entity).insert(TextColor(color));
+        }
+    }
+
+    fn circle_components(&self, disabled: bool) -> impl Bundle {
+        let mut border_color = get_color(&self.cloned_attrs.color);
+        if disabled {
+            border_color = border_color.with_alpha(0.4);
+        }
+        (
+            default_radio_circle_node(self.circle_size()),
+            IsFamiqRadioItemCircle,
+            BorderRadius::all(Val::Percent(50.0)),
+            BorderColor(border_color),
+            Pickable::IGNORE
+        )
+    }
+
+    fn dot_components(&self) -> impl Bundle {
+        (
+            default_radio_dot_node(self.circle_size()),
+            BorderRadius::all(Val::Percent(50.0)),
+            BackgroundColor(Color::NONE),
+            Pickable::IGNORE
+        )
+    }
+
+    fn choice_wrapper_components(
+        &self,
+        text: String,
+        disabled: bool,
+        radio_entity: Entity,
+        dot_entity: Entity
+    ) -> impl Bundle {
+        (
+            default_radio_item_node(),
+            RadioItemText(text),
+            RadioItemDisabled(disabled),
+            RadioMainContainerEntity(radio_entity),
+            RadioItemDotEntity(dot_entity),
+            IsFamiqRadioItem
+        )
+    }
+
+    fn build_choices(
+        &mut self,
+        radio_entity: Entity,
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) {
+        let choices = self.resolve_choices(&self.choices.clone(), r_data);
+        let disabled = self.resolve_choices(&self.disabled.clone(), r_data);
+        let mut all_choices: Vec<Entity> = Vec::new();
+
+        for choice in choices.into_iter() {
+            let is_disabled = disabled.contains(&choice);
+            let dot = commands.spawn(self.dot_components()).id();
+            let circle = commands.spawn(self.circle_components(is_disabled)).add_child(dot).id();
+            let text = self.choice_text(&choice, is_disabled, r_data, commands);
+            let wrapper = commands
+                .spawn(self.choice_wrapper_components(choice, is_disabled, radio_entity, dot))
+                .observe(on_mouse_out)
+                .observe(on_mouse_down)
+                .observe(on_mouse_over)
+                .add_children(&[circle, text])
+                .id();
+
+            self.choice_dot_entities.push(dot);
+            self.choice_circle_entities.push(circle);
+            self.choice_wrapper_entities.push(wrapper);
+            all_choices.push(wrapper);
+        }
+        commands.entity(radio_entity).add_children(&all_choices);
+    }
+
+    fn rebuild_choices(&mut self, radio_entity: Entity, r_data: &HashMap<String, RVal>, world: &mut World) {
+        let keys_len = self.all_reactive_keys.len();
+        let choices = self.resolve_choices(&self.choices.clone(), r_data);
+        let disabled = self.resolve_choices(&self.disabled.clone(), r_data);
+
+        // number of choices is changed by a reactive list, build them again
+        if choices.len() != self.choice_wrapper_entities.len() {
+            for wrapper in self.choice_wrapper_entities.drain(..) {
+                world.entity_mut(wrapper).despawn();
+            }
+            self.choice_text_entities.clear();
+            self.choice_circle_entities.clear();
+            self.choice_dot_entities.clear();
+
+            // reactive keys of choices are collected again while building
+            self.all_reactive_keys.truncate(keys_len);
+            let mut commands = world.commands();
+            self.build_choices(radio_entity, r_data, &mut commands);
+            world.flush();
+            return;
+        }
+
+        for (index, choice) in choices.into_iter().enumerate() {
+            let is_disabled = disabled.contains(&choice);
+            let dot = self.choice_dot_entities[index];
+            world.entity_mut(dot).insert(self.dot_components());
+            world.entity_mut(self.choice_circle_entities[index]).insert(self.circle_components(is_disabled));
+            self.rebuild_choice_text(&choice, is_disabled, self.choice_text_entities[index], r_data, world);
+            world
+                .entity_mut(self.choice_wrapper_entities[index])
+                .insert((RadioItemText(choice), RadioItemDisabled(is_disabled)));
+        }
+    }
+
+    pub(crate) fn set_flex_direction(&mut self, vertical: bool) {
+        if vertical {
+            self.cloned_attrs.node.flex_direction = FlexDirection::Column;
+        } else {
+            self.cloned_attrs.node.flex_direction = FlexDirection::Row;
+        }
+    }
+
+    pub(crate) fn handle_vertical_val(&mut self, r_data: &HashMap<String, RVal>) {
+        match self.vertical.to_owned() {
+            RVal::Bool(v) => self.set_flex_direction(v),
+            RVal::Str(v) => {
+                let reactive_keys = get_reactive_key(&v);
+
+                for key in reactive_keys.iter() {
+                    if let Some(RVal::Bool(state)) = get_reactive_value(r_data, key) {
+                        self.set_flex_direction(*state);
+                    }
+                }
+                self.all_reactive_keys.extend_from_slice(&reactive_keys);
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_radio_container_node();
+        self.handle_vertical_val(r_data);
+        self.cloned_attrs.overrided_border_color = Some(Color::NONE);
+        self.cloned_attrs.overrided_background_color = Some(Color::NONE);
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+        self._process_built_in_size_class();
+    }
+}
+
+/// Resolve template aliases of choices, see `container!(scope: ..)`.
+fn resolve_choices_alias(choices: RVal) -> RVal {
+    match choices {
+        RVal::Str(v) => RVal::Str(resolve_template_alias(&v)),
+        RVal::Array(items) => RVal::Array(
+            items
+                .into_iter()
+                .map(|item| match item {
+                    RVal::Str(v) => RVal::Str(resolve_template_alias(&v)),
+                    other => other
+                })
+                .collect()
+        ),
+        other => other
+    }
+}
+
+impl SetupWidget for RadioBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqRadio, MainWidget, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+
+        let mut radio = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let radio_entity = radio.build(r_data, commands);
+        self.build_choices(radio_entity, r_data, commands);
+        commands
+            .entity(radio_entity)
+            .insert((self.components(), RadioSelected::default(), RadioSelectedColor(self.selected_color())));
+
+        insert_model(commands, radio_entity, &self.cloned_attrs.model_key);
+        insert_class_id(commands, radio_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                radio_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Radio(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        radio_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+
+        let mut radio = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        radio.rebuild(r_data, old_entity, world);
+        self.rebuild_choices(old_entity, r_data, world);
+
+        // dots of new or rebuilt choices are updated by the selected choice
+        world.entity_mut(old_entity).insert(RadioSelectedColor(self.selected_color()));
+        if let Some(mut selected) = world.get_mut::<RadioSelected>(old_entity) {
+            selected.set_changed();
+        }
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Radio(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a radio group.
+#[macro_export]
+macro_rules! radio {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let r_builder = &mut RadioBuilder::new(&famiq_builder.get_font_handle());
+        $(
+            $crate::radio_attributes!(r_builder, $key : $value);
+        )*
+        r_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(radio [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! radio_attributes {
+    ($r_builder:ident, choices: [$( $choice:expr ),* $(,)?]) => {{
+        $r_builder.set_choices(vec![$( $choice.to_string() ),*]);
+    }};
+    ($r_builder:ident, choices: $choices:expr) => {{
+        $r_builder.set_choices($choices);
+    }};
+    ($r_builder:ident, disabled: [$( $choice:expr ),* $(,)?]) => {{
+        $r_builder.set_disabled(vec![$( $choice.to_string() ),*]);
+    }};
+    ($r_builder:ident, disabled: $disabled:expr) => {{
+        $r_builder.set_disabled($disabled);
+    }};
+    ($r_builder:ident, vertical: $vertical:expr) => {{
+        match to_rval($vertical) {
+            Ok(v) => $r_builder.vertical = v,
+            Err(_) => panic!("\nvertical attribute accepts only boolean and reactive string\n")
+        }
+    }};
+    ($r_builder:ident, model: $model:expr) => {{
+        $r_builder.set_model($model);
+    }};
+    ($r_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($r_builder, $key : $value);
+    }};
+}
+
+pub fn can_run_radio_systems(radio_q: Query<&IsFamiqRadio>) -> bool {
+    !radio_q.is_empty()
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+pub fn default_radio_container_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_wrap: FlexWrap::Wrap,
+        flex_direction: FlexDirection::Row,
+        margin: UiRect {
+            top: Val::Px(2.0),
+            right: Val::Px(0.0),
+            left: Val::Px(0.0),
+            bottom: Val::Px(2.0),
+        },
+        ..default()
+    }
+}
+
+pub fn default_radio_circle_node(size: f32) -> Node {
+    Node {
+        width: Val::Px(size),
+        height: Val::Px(size),
+        border: UiRect::all(Val::Px(1.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        margin: UiRect::right(Val::Px(4.0)),
+        ..default()
+    }
+}
+
+pub fn default_radio_dot_node(size: f32) -> Node {
+    Node {
+        width: Val::Px(size / 2.0),
+        height: Val::Px(size / 2.0),
+        ..default()
+    }
+}
+
+pub fn default_radio_item_node() -> Node {
+    Node {
+        width: Val::Auto,
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        margin: UiRect {
+            top: Val::Px(2.5),
+            right: Val::Px(5.0),
+            left: Val::Px(5.0),
+            bottom: Val::Px(2.5),
+        },
+        ..default()
+    }
+}
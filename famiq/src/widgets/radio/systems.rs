@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use super::*;
+
+type RadioSelectQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut RadioSelected, Option<&'static WidgetId>, Option<&'static ReactiveModelKey>),
+    With<IsFamiqRadio>
+>;
+
+pub(crate) fn on_mouse_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    item_q: Query<&RadioItemDisabled>,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    if let Ok(disabled) = item_q.get(trigger.target())
+        && !disabled.0
+    {
+        _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Pointer);
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut commands: Commands,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Default);
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    mut radio_q: RadioSelectQuery,
+    item_q: Query<(&RadioItemText, &RadioItemDisabled, &RadioMainContainerEntity)>,
+    mut writer: EventWriter<FaValueChangeEvent>,
+    mut fa_query: FaQuery,
+) {
+    if let Ok((item_text, disabled, main_entity)) = item_q.get(trigger.target()) {
+        if disabled.0 {
+            trigger.propagate(false);
+            return;
+        }
+        if let Ok((mut selected, id, model_key)) = radio_q.get_mut(main_entity.0)
+            && selected.0.as_ref() != Some(&item_text.0)
+        {
+            selected.0 = Some(item_text.0.clone());
+
+            if let Some(key) = model_key {
+                let _ = fa_query.try_mutate(&key.0, RVal::Str(item_text.0.clone()));
+            }
+            FaValueChangeEvent::send_event(
+                &mut writer,
+                WidgetType::Radio,
+                main_entity.0,
+                id,
+                RVal::Str(item_text.0.clone())
+            );
+        }
+    }
+    trigger.propagate(false);
+}
+
+pub fn detect_radio_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut radio_q: Query<(Ref<IsFamiqRadio>, &mut RadioSelected, Option<&ReactiveModelKey>)>
+) {
+    let data_changed = reactive_data.is_changed() && !reactive_data.is_added();
+
+    for (radio, mut selected, model_key) in radio_q.iter_mut() {
+        if !data_changed && !radio.is_added() {
+            continue;
+        }
+        if let Some(key) = model_key {
+            match get_reactive_value(&reactive_data.data, &key.0) {
+                Some(RVal::Str(v)) => {
+                    selected.set_if_neq(RadioSelected(Some(v.clone())));
+                }
+                Some(RVal::None) => {
+                    selected.set_if_neq(RadioSelected(None));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Show the dot of selected choice.
+pub fn update_radio_dots(
+    radio_q: Query<(&RadioSelected, &RadioSelectedColor, &Children), Changed<RadioSelected>>,
+    item_q: Query<(&RadioItemText, &RadioItemDotEntity)>,
+    mut dot_q: Query<&mut BackgroundColor>
+) {
+    for (selected, selected_color, children) in radio_q.iter() {
+        for child in children.iter() {
+            if let Ok((item_text, dot_entity)) = item_q.get(child)
+                && let Ok(mut bg_color) = dot_q.get_mut(dot_entity.0)
+            {
+                if selected.0.as_ref() == Some(&item_text.0) {
+                    bg_color.0 = selected_color.0;
+                } else {
+                    bg_color.0 = Color::NONE;
+                }
+            }
+        }
+    }
+}
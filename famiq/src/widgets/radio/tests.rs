@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use crate::FamiqPlugin;
+use crate::widgets::FamiqResource;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::radio;
+use bevy::ecs::system::RunSystemOnce;
+use super::*;
+
+fn setup_test_default_radio(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    radio!(id: "#test-radio", choices: ["Easy", "Normal", "Hard"], disabled: ["Hard"]);
+}
+
+fn setup_test_radio_with_model(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("level", "Normal");
+    fa_query.insert_str_list("levels", vec!["Easy".to_string(), "Normal".to_string()]);
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    radio!(choices: "$[levels]", vertical: true, model: "level");
+}
+
+fn items(app: &mut App) -> Vec<(String, bool, Entity)> {
+    let children = app.world_mut()
+        .query_filtered::<&Children, With<IsFamiqRadio>>()
+        .single(app.world())
+        .map(|c| c.to_vec())
+        .unwrap();
+
+    children
+        .into_iter()
+        .filter_map(|e| {
+            let text = app.world().get::<RadioItemText>(e)?;
+            let disabled = app.world().get::<RadioItemDisabled>(e)?;
+            let dot = app.world().get::<RadioItemDotEntity>(e)?;
+            Some((text.0.clone(), disabled.0, dot.0))
+        })
+        .collect()
+}
+
+fn dot_color(app: &App, dot: Entity) -> Color {
+    app.world().get::<BackgroundColor>(dot).unwrap().0
+}
+
+#[test]
+fn test_create_default_radio() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_default_radio);
+    app.update();
+
+    let radio_id = app.world_mut()
+        .query_filtered::<&WidgetId, With<IsFamiqRadio>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!("#test-radio".to_string(), radio_id.0);
+
+    let items = items(&mut app);
+    let texts: Vec<&str> = items.iter().map(|(text, _, _)| text.as_str()).collect();
+    assert_eq!(vec!["Easy", "Normal", "Hard"], texts);
+    assert!(!items[0].1);
+    assert!(items[2].1, "Hard should be disabled");
+}
+
+#[test]
+fn test_radio_model_selects_choice() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_radio_with_model);
+    app.update();
+
+    let items = items(&mut app);
+    assert_eq!(dot_color(&app, items[0].2), Color::NONE);
+    assert_eq!(dot_color(&app, items[1].2), PRIMARY_COLOR);
+
+    let _ = app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+        fa_query.mutate_str("level", "Easy");
+    });
+    app.update();
+
+    assert_eq!(dot_color(&app, items[0].2), PRIMARY_COLOR);
+    assert_eq!(dot_color(&app, items[1].2), Color::NONE);
+}
+
+#[test]
+fn test_radio_reactive_choices() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_radio_with_model);
+    app.update();
+
+    let _ = app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+        fa_query.mutate_str_list(
+            "levels",
+            vec!["Easy".to_string(), "Normal".to_string(), "Hard".to_string()]
+        );
+    });
+    app.update();
+    app.update();
+
+    let items = items(&mut app);
+    let texts: Vec<&str> = items.iter().map(|(text, _, _)| text.as_str()).collect();
+    assert_eq!(vec!["Easy", "Normal", "Hard"], texts);
+    assert_eq!(dot_color(&app, items[1].2), PRIMARY_COLOR, "selected choice is kept");
+}
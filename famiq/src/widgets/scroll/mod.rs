@@ -130,7 +130,7 @@ impl ScrollBuilder {
                 self.all_reactive_keys.extend_from_slice(&reactive_keys);
 
                 for key in reactive_keys.iter() {
-                    if let Some(r_v) = r_data.get(key) {
+                    if let Some(r_v) = get_reactive_value(r_data, key) {
                         match r_v {
                             RVal::FNum(size) =>  return *size,
                             _ => {}
@@ -219,7 +219,7 @@ impl SetupWidget for ScrollBuilder {
 /// Macro for creating a scrollable container.
 #[macro_export]
 macro_rules! scroll {
-    ( $( $key:ident : $value:tt ),* $(,)? ) => {{
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
         let famiq_builder = builder_mut();
 
         #[allow(unused_mut)]
@@ -239,6 +239,11 @@ macro_rules! scroll {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(scroll [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -34,7 +34,7 @@ impl SelectionBuilder {
             attributes,
             cloned_attrs: WidgetAttributes::default(),
             all_reactive_keys: Vec::new(),
-            placeholder,
+            placeholder: resolve_template_alias(&placeholder),
             choices: Vec::new(),
             panel_entity: None
         }
@@ -250,7 +250,7 @@ impl SetupWidget for SelectionBuilder {
 /// Macro for creating a single choice selector.
 #[macro_export]
 macro_rules! selection {
-    ( placeholder: $placeholder:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+    ( @widget placeholder: $placeholder:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let s_builder = &mut SelectionBuilder::new($placeholder.to_string(), &famiq_builder.get_font_handle());
         $(
@@ -261,6 +261,11 @@ macro_rules! selection {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(selection [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
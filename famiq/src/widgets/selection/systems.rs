@@ -96,7 +96,7 @@ pub fn set_placeholder_with_model(
     ph_text: &String,
     key: &String
 ) {
-    if let Some(r_value) = reactive_data.get(key) {
+    if let Some(r_value) = get_reactive_value(reactive_data, key) {
         if let Ok(mut text) = placeholder_q.get_mut(ph_entity) {
             match r_value {
                 RVal::Str(v) => {
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+/// Marker component for identifying an entity as a Famiq slider.
+#[derive(Component)]
+pub struct IsFamiqSlider;
+
+/// Marker component for identifying slider's thumbs.
+#[derive(Component)]
+pub struct IsFamiqSliderThumb;
+
+/// Marker component for identifying slider's filled part of the rail.
+#[derive(Component)]
+pub struct IsFamiqSliderFill;
+
+/// Range and orientation of a slider.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct SliderConfig {
+    pub min: f32,
+    pub max: f32,
+    /// values snap to multiple of step from min, 0 means no snapping
+    pub step: f32,
+    pub vertical: bool,
+    /// two thumbs selecting a range
+    pub range: bool
+}
+
+/// Current value of a slider. Second value is used in range mode only.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct SliderValue(pub [f32; 2]);
+
+/// Thumb being dragged or moved by keyboard.
+#[derive(Component, Default, Debug)]
+pub struct SliderDrag {
+    pub dragging: bool,
+    pub thumb: usize
+}
+
+/// Component storing thumb entities of a slider.
+#[derive(Component)]
+pub struct SliderThumbEntities(pub [Entity; 2]);
+
+/// Component storing fill entity of a slider.
+#[derive(Component)]
+pub struct SliderFillEntity(pub Entity);
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use super::*;
+
+pub(crate) const THUMB_SIZE: f32 = 16.0;
+const RAIL_SIZE: f32 = 6.0;
+
+pub(crate) fn default_slider_node(vertical: bool) -> Node {
+    let mut node = Node {
+        position_type: PositionType::Relative,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        margin: UiRect::vertical(Val::Px(2.0)),
+        ..default()
+    };
+    if vertical {
+        node.width = Val::Px(THUMB_SIZE + 4.0);
+        node.height = Val::Px(150.0);
+    } else {
+        node.width = Val::Percent(100.0);
+        node.height = Val::Px(THUMB_SIZE + 4.0);
+    }
+    node
+}
+
+pub(crate) fn default_rail_node(vertical: bool) -> Node {
+    let mut node = Node {
+        position_type: PositionType::Relative,
+        ..default()
+    };
+    if vertical {
+        node.width = Val::Px(RAIL_SIZE);
+        node.height = Val::Percent(100.0);
+    } else {
+        node.width = Val::Percent(100.0);
+        node.height = Val::Px(RAIL_SIZE);
+    }
+    node
+}
+
+pub(crate) fn default_fill_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        ..default()
+    }
+}
+
+pub(crate) fn default_thumb_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        width: Val::Px(THUMB_SIZE),
+        height: Val::Px(THUMB_SIZE),
+        ..default()
+    }
+}
+
+/// Clamp `value` to the slider range and snap it to the nearest step.
+pub fn snap_slider_value(value: f32, config: &SliderConfig) -> f32 {
+    let (min, max) = (config.min.min(config.max), config.min.max(config.max));
+    let mut value = value.clamp(min, max);
+
+    if config.step > 0.0 {
+        value = min + ((value - min) / config.step).round() * config.step;
+        value = value.clamp(min, max);
+    }
+    value
+}
+
+/// Position of `value` along the slider, from 0 to 100.
+pub fn slider_percent(value: f32, config: &SliderConfig) -> f32 {
+    if config.max == config.min {
+        return 0.0;
+    }
+    ((value - config.min) / (config.max - config.min) * 100.0).clamp(0.0, 100.0)
+}
+
+/// Place thumb at `percent` along the slider.
+pub(crate) fn position_thumb(node: &mut Node, percent: f32, vertical: bool) {
+    let offset = Val::Px(-THUMB_SIZE / 2.0);
+    if vertical {
+        node.left = Val::Percent(50.0);
+        node.bottom = Val::Percent(percent);
+        node.top = Val::Auto;
+        node.margin = UiRect { left: offset, bottom: offset, ..default() };
+    } else {
+        node.left = Val::Percent(percent);
+        node.top = Val::Percent(50.0);
+        node.bottom = Val::Auto;
+        node.margin = UiRect { left: offset, top: offset, ..default() };
+    }
+}
+
+/// Fill the rail between `start` and `end` percent.
+pub(crate) fn position_fill(node: &mut Node, start: f32, end: f32, vertical: bool) {
+    if vertical {
+        node.left = Val::Px(0.0);
+        node.width = Val::Percent(100.0);
+        node.bottom = Val::Percent(start);
+        node.height = Val::Percent(end - start);
+    } else {
+        node.top = Val::Px(0.0);
+        node.height = Val::Percent(100.0);
+        node.left = Val::Percent(start);
+        node.width = Val::Percent(end - start);
+    }
+}
@@ -0,0 +1,303 @@
+pub mod components;
+pub mod helper;
+pub mod systems;
+pub mod tests;
+
+pub(crate) use components::*;
+pub(crate) use systems::*;
+pub use helper::{snap_slider_value, slider_percent};
+use helper::*;
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use famiq_macros::set_widget_attributes;
+
+use crate::widgets::container::base_container::*;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::plugin::{CursorType, CursorIcons};
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::resources::*;
+use crate::utils::*;
+use crate::widgets::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct SliderBuilder {
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    pub vertical: RVal,
+    pub range: bool,
+    pub all_reactive_keys: Vec<String>,
+    pub rail_entity: Option<Entity>,
+    pub fill_entity: Option<Entity>,
+    pub thumb_entities: Vec<Entity>
+}
+
+impl Default for SliderBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SliderBuilder {
+    pub fn new() -> Self {
+        Self {
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            vertical: RVal::Bool(false),
+            range: false,
+            all_reactive_keys: Vec::new(),
+            attributes: WidgetAttributes::default(),
+            cloned_attrs: WidgetAttributes::default(),
+            rail_entity: None,
+            fill_entity: None,
+            thumb_entities: Vec::new()
+        }
+    }
+
+    fn is_vertical(&mut self, r_data: &HashMap<String, RVal>) -> bool {
+        match self.vertical.to_owned() {
+            RVal::Bool(v) => v,
+            RVal::Str(v) => {
+                let reactive_keys = get_reactive_key(&v);
+                self.all_reactive_keys.extend_from_slice(&reactive_keys);
+
+                reactive_keys
+                    .iter()
+                    .filter_map(|key| get_reactive_value(r_data, key))
+                    .any(|r_v| *r_v == RVal::Bool(true))
+            }
+            _ => false
+        }
+    }
+
+    fn config(&self, vertical: bool) -> SliderConfig {
+        SliderConfig {
+            min: self.min,
+            max: self.max,
+            step: self.step,
+            vertical,
+            range: self.range
+        }
+    }
+
+    fn active_color(&self) -> Color {
+        match self.cloned_attrs.color {
+            WidgetColor::Default => PRIMARY_COLOR,
+            _ => get_color(&self.cloned_attrs.color)
+        }
+    }
+
+    fn rail_attrs(&self, vertical: bool) -> WidgetAttributes {
+        WidgetAttributes {
+            node: default_rail_node(vertical),
+            border_radius: BorderRadius::all(Val::Px(3.0)),
+            overrided_background_color: Some(Color::srgba(0.6, 0.6, 0.6, 0.3)),
+            overrided_border_color: Some(Color::NONE),
+            ..default()
+        }
+    }
+
+    fn fill_components(&self) -> impl Bundle {
+        (
+            default_fill_node(),
+            BackgroundColor(self.active_color()),
+            BorderRadius::all(Val::Px(3.0)),
+            Pickable::IGNORE,
+            IsFamiqSliderFill
+        )
+    }
+
+    fn thumb_components(&self, index: usize) -> impl Bundle {
+        let mut node = default_thumb_node();
+        if index == 1 && !self.range {
+            node.display = Display::None;
+        }
+        (
+            node,
+            BackgroundColor(self.active_color()),
+            BorderColor(Color::WHITE),
+            BorderRadius::all(Val::Percent(50.0)),
+            IsFamiqSliderThumb
+        )
+    }
+
+    fn build_parts(
+        &mut self,
+        slider_entity: Entity,
+        vertical: bool,
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) {
+        let mut rail = FaBaseContainer::new_with_attributes(&self.rail_attrs(vertical));
+        let rail_entity = rail.build(r_data, commands);
+        commands.entity(rail_entity).insert(Pickable::IGNORE);
+
+        let fill_entity = commands.spawn(self.fill_components()).id();
+        commands.entity(rail_entity).add_child(fill_entity);
+
+        let thumbs = [
+            commands.spawn(self.thumb_components(0)).id(),
+            commands.spawn(self.thumb_components(1)).id()
+        ];
+        commands
+            .entity(slider_entity)
+            .insert((SliderFillEntity(fill_entity), SliderThumbEntities(thumbs)))
+            .add_children(&[rail_entity, thumbs[0], thumbs[1]]);
+
+        self.rail_entity = Some(rail_entity);
+        self.fill_entity = Some(fill_entity);
+        self.thumb_entities = thumbs.to_vec();
+    }
+
+    fn rebuild_parts(&mut self, vertical: bool, r_data: &HashMap<String, RVal>, world: &mut World) {
+        if let Some(rail_entity) = self.rail_entity {
+            let mut rail = FaBaseContainer::new_with_attributes(&self.rail_attrs(vertical));
+            rail.rebuild(r_data, rail_entity, world);
+        }
+        if let Some(fill_entity) = self.fill_entity {
+            world.entity_mut(fill_entity).insert(self.fill_components());
+        }
+        for (index, thumb_entity) in self.thumb_entities.clone().into_iter().enumerate() {
+            world.entity_mut(thumb_entity).insert(self.thumb_components(index));
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) -> bool {
+        self.cloned_attrs = self.attributes.clone();
+        let vertical = self.is_vertical(r_data);
+        self.cloned_attrs.node = default_slider_node(vertical);
+        self.cloned_attrs.overrided_background_color = Some(Color::NONE);
+        self.cloned_attrs.overrided_border_color = Some(Color::NONE);
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        vertical
+    }
+}
+
+impl SetupWidget for SliderBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (MainWidget, IsFamiqSlider, ReactiveWidget, RelativeCursorPosition::default())
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        let vertical = self.prepare_attrs(r_data);
+        let mut slider = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let slider_entity = slider.build(r_data, commands);
+        self.build_parts(slider_entity, vertical, r_data, commands);
+
+        let config = self.config(vertical);
+        commands
+            .entity(slider_entity)
+            .insert((
+                self.components(),
+                config,
+                SliderValue([config.min, config.max]),
+                SliderDrag::default()
+            ))
+            .observe(on_mouse_up)
+            .observe(on_mouse_down)
+            .observe(on_mouse_over)
+            .observe(on_mouse_out);
+
+        if self.attributes.has_tooltip {
+            build_tooltip_node(&self.cloned_attrs, commands, slider_entity);
+        }
+        insert_class_id(commands, slider_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, slider_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                slider_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Slider(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        slider_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        let vertical = self.prepare_attrs(r_data);
+        let mut slider = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        slider.rebuild(r_data, old_entity, world);
+        self.rebuild_parts(vertical, r_data, world);
+
+        // keeps current value, thumbs are placed again since config is changed
+        world.entity_mut(old_entity).insert(self.config(vertical));
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Slider(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a slider.
+#[macro_export]
+macro_rules! slider {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let s_builder = &mut SliderBuilder::new();
+        $(
+            $crate::slider_attributes!(s_builder, $key : $value);
+        )*
+        s_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(slider [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! slider_attributes {
+    ($s_builder:ident, min: $min:expr) => {{
+        $s_builder.min = $min as f32;
+    }};
+    ($s_builder:ident, max: $max:expr) => {{
+        $s_builder.max = $max as f32;
+    }};
+    ($s_builder:ident, step: $step:expr) => {{
+        $s_builder.step = $step as f32;
+    }};
+    ($s_builder:ident, range: $range:expr) => {{
+        $s_builder.range = $range;
+    }};
+    ($s_builder:ident, vertical: $vertical:expr) => {{
+        match to_rval($vertical) {
+            Ok(v) => $s_builder.vertical = v,
+            Err(_) => panic!("\nvertical attribute accepts only boolean and reactive string\n")
+        }
+    }};
+    ($s_builder:ident, model: $model:expr) => {{
+        $s_builder.set_model($model);
+    }};
+    ($s_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($s_builder, $key : $value);
+    }};
+}
+
+/// Determines if slider internal system(s) can run.
+///
+/// True only if there is a slider widget created.
+pub fn can_run_slider_systems(slider_q: Query<&IsFamiqSlider>) -> bool {
+    !slider_q.is_empty()
+}
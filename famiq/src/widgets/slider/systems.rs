@@ -0,0 +1,303 @@
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use super::*;
+
+/// New value of slider after `thumb` is moved to `raw`. In range mode,
+/// thumbs can't pass each other.
+pub(crate) fn moved_slider_value(current: [f32; 2], config: &SliderConfig, thumb: usize, raw: f32) -> [f32; 2] {
+    let mut value = current;
+    value[thumb] = snap_slider_value(raw, config);
+
+    if config.range {
+        if thumb == 0 {
+            value[0] = value[0].min(value[1]);
+        } else {
+            value[1] = value[1].max(value[0]);
+        }
+    }
+    value
+}
+
+/// Slider value as model value, `RVal::FNum` or `RVal::Array` of two `RVal::FNum` in range mode.
+pub(crate) fn slider_model_value(value: [f32; 2], config: &SliderConfig) -> RVal {
+    if config.range {
+        RVal::Array(vec![RVal::FNum(value[0]), RVal::FNum(value[1])])
+    } else {
+        RVal::FNum(value[0])
+    }
+}
+
+/// Slider value from model value.
+fn value_from_model(r_value: &RVal, current: [f32; 2], config: &SliderConfig) -> Option<[f32; 2]> {
+    if config.range {
+        let values = Vec::<f32>::from_rval(r_value).ok()?;
+        if values.len() < 2 {
+            return None;
+        }
+        let low = snap_slider_value(values[0].min(values[1]), config);
+        let high = snap_slider_value(values[0].max(values[1]), config);
+        Some([low, high])
+    } else {
+        let value = f32::from_rval(r_value).ok()?;
+        Some([snap_slider_value(value, config), current[1]])
+    }
+}
+
+type SliderHoverQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static GlobalTransform, Option<&'static WidgetId>, Option<&'static TooltipEntity>),
+    With<IsFamiqSlider>
+>;
+
+type SliderPressQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut SliderDrag, &'static SliderValue, &'static SliderConfig, &'static RelativeCursorPosition, Option<&'static WidgetId>),
+    With<IsFamiqSlider>
+>;
+
+type SliderDragQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut SliderDrag,
+        &'static mut SliderValue,
+        &'static SliderConfig,
+        &'static RelativeCursorPosition,
+        Option<&'static WidgetId>,
+        Option<&'static ReactiveModelKey>
+    )
+>;
+
+type SliderKeyQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static SliderDrag,
+        &'static mut SliderValue,
+        &'static SliderConfig,
+        Option<&'static WidgetId>,
+        Option<&'static ReactiveModelKey>
+    )
+>;
+
+type SliderNodeQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static SliderValue, &'static SliderConfig, &'static SliderThumbEntities, &'static SliderFillEntity),
+    Or<(Changed<SliderValue>, Changed<SliderConfig>)>
+>;
+
+fn commit_slider_value(
+    entity: Entity,
+    id: Option<&WidgetId>,
+    model_key: Option<&ReactiveModelKey>,
+    value: RVal,
+    r_data: &mut RData,
+    writer: &mut EventWriter<FaValueChangeEvent>
+) {
+    if let Some(key) = model_key {
+        let _ = r_data.try_mutate(&key.0, value.clone());
+    }
+    FaValueChangeEvent::send_event(writer, WidgetType::Slider, entity, id, value);
+}
+
+pub(crate) fn on_mouse_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    mut writer: EventWriter<FaMouseEvent>,
+    mut tooltip_q: Query<(&mut Node, &mut Transform), With<IsFamiqTooltip>>,
+    slider_q: SliderHoverQuery,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    if let Ok((transform, id, tooltip_entity)) = slider_q.get(trigger.target()) {
+        _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Pointer);
+        show_tooltip(tooltip_entity, &mut tooltip_q, transform.translation());
+        FaMouseEvent::send_event(&mut writer, EventType::Over, WidgetType::Slider, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut commands: Commands,
+    mut writer: EventWriter<FaMouseEvent>,
+    mut tooltip_q: Query<(&mut Node, &mut Transform), With<IsFamiqTooltip>>,
+    slider_q: Query<(Option<&WidgetId>, Option<&TooltipEntity>), With<IsFamiqSlider>>,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    if let Ok((id, tooltip_entity)) = slider_q.get(trigger.target()) {
+        _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Default);
+        hide_tooltip(tooltip_entity, &mut tooltip_q);
+        FaMouseEvent::send_event(&mut writer, EventType::Out, WidgetType::Slider, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+/// Pressing anywhere on the slider (thumbs included) starts dragging the nearest thumb.
+pub(crate) fn on_mouse_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    mut slider_q: SliderPressQuery,
+    mut writer: EventWriter<FaMouseEvent>,
+    mut famiq_res: ResMut<FamiqResource>
+) {
+    if let Ok((mut drag, value, config, cursor, id)) = slider_q.get_mut(trigger.target()) {
+        famiq_res.update_all_focus_states(false);
+        famiq_res.update_or_insert_focus_state(trigger.target(), true);
+
+        if trigger.event().button == PointerButton::Secondary {
+            FaMouseEvent::send_event(&mut writer, EventType::DownRight, WidgetType::Slider, trigger.target(), id);
+        } else {
+            drag.dragging = true;
+            drag.thumb = 0;
+
+            if config.range
+                && let Some(raw) = cursor.normalized.map(|pos| cursor_to_value(pos, config))
+                && ((raw - value.0[1]).abs() < (raw - value.0[0]).abs() || raw > value.0[1])
+            {
+                drag.thumb = 1;
+            }
+            FaMouseEvent::send_event(&mut writer, EventType::DownLeft, WidgetType::Slider, trigger.target(), id);
+        }
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_up(
+    mut trigger: Trigger<Pointer<Released>>,
+    mut writer: EventWriter<FaMouseEvent>,
+    slider_q: Query<Option<&WidgetId>, With<IsFamiqSlider>>
+) {
+    if let Ok(id) = slider_q.get(trigger.target()) {
+        FaMouseEvent::send_event(&mut writer, EventType::Up, WidgetType::Slider, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+fn cursor_to_value(normalized: Vec2, config: &SliderConfig) -> f32 {
+    let fraction = if config.vertical {
+        1.0 - normalized.y
+    } else {
+        normalized.x
+    };
+    config.min + fraction.clamp(0.0, 1.0) * (config.max - config.min)
+}
+
+/// Move the dragged thumb to cursor position until mouse's left button is released.
+pub fn handle_slider_dragging(
+    mut slider_q: SliderDragQuery,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut r_data: ResMut<RData>,
+    mut writer: EventWriter<FaValueChangeEvent>
+) {
+    for (entity, mut drag, mut value, config, cursor, id, model_key) in slider_q.iter_mut() {
+        if !drag.dragging {
+            continue;
+        }
+        if !mouse.pressed(MouseButton::Left) {
+            drag.dragging = false;
+            continue;
+        }
+        if let Some(pos) = cursor.normalized {
+            let new_value = moved_slider_value(value.0, config, drag.thumb, cursor_to_value(pos, config));
+
+            if new_value != value.0 {
+                value.0 = new_value;
+                let model_value = slider_model_value(new_value, config);
+                commit_slider_value(entity, id, model_key, model_value, &mut r_data, &mut writer);
+            }
+        }
+    }
+}
+
+/// Arrow keys move the active thumb of focused slider by one step, Home & End move it to min & max.
+pub fn handle_slider_keyboard(
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mut slider_q: SliderKeyQuery,
+    famiq_res: Res<FamiqResource>,
+    mut r_data: ResMut<RData>,
+    mut writer: EventWriter<FaValueChangeEvent>
+) {
+    for e in evr_kbd.read() {
+        if e.state == ButtonState::Released {
+            continue;
+        }
+
+        for (entity, drag, mut value, config, id, model_key) in slider_q.iter_mut() {
+            if famiq_res.get_widget_focus_state(&entity) != Some(true) {
+                continue;
+            }
+            let thumb = if config.range { drag.thumb } else { 0 };
+            let step = if config.step > 0.0 {
+                config.step
+            } else {
+                (config.max - config.min) / 100.0
+            };
+            let raw = match e.key_code {
+                KeyCode::ArrowRight | KeyCode::ArrowUp => value.0[thumb] + step,
+                KeyCode::ArrowLeft | KeyCode::ArrowDown => value.0[thumb] - step,
+                KeyCode::Home => config.min,
+                KeyCode::End => config.max,
+                _ => continue
+            };
+            let new_value = moved_slider_value(value.0, config, thumb, raw);
+
+            if new_value != value.0 {
+                value.0 = new_value;
+                let model_value = slider_model_value(new_value, config);
+                commit_slider_value(entity, id, model_key, model_value, &mut r_data, &mut writer);
+            }
+        }
+    }
+}
+
+pub fn detect_slider_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut slider_q: Query<(Ref<IsFamiqSlider>, &mut SliderValue, &SliderConfig, Option<&ReactiveModelKey>)>
+) {
+    let data_changed = reactive_data.is_changed() && !reactive_data.is_added();
+
+    for (slider, mut value, config, model_key) in slider_q.iter_mut() {
+        if !data_changed && !slider.is_added() {
+            continue;
+        }
+        if let Some(key) = model_key
+            && let Some(r_value) = get_reactive_value(&reactive_data.data, &key.0)
+            && let Some(new_value) = value_from_model(r_value, value.0, config)
+        {
+            value.set_if_neq(SliderValue(new_value));
+        }
+    }
+}
+
+/// Place thumbs and fill according to slider value.
+pub fn update_slider_nodes(
+    slider_q: SliderNodeQuery,
+    mut node_q: Query<&mut Node, Without<IsFamiqSlider>>
+) {
+    for (value, config, thumbs, fill) in slider_q.iter() {
+        let start = slider_percent(value.0[0], config);
+        let end = slider_percent(value.0[1], config);
+
+        if let Ok(mut node) = node_q.get_mut(thumbs.0[0]) {
+            position_thumb(&mut node, start, config.vertical);
+        }
+        if let Ok(mut node) = node_q.get_mut(thumbs.0[1]) {
+            position_thumb(&mut node, end, config.vertical);
+        }
+        if let Ok(mut node) = node_q.get_mut(fill.0) {
+            if config.range {
+                position_fill(&mut node, start, end, config.vertical);
+            } else {
+                position_fill(&mut node, 0.0, start, config.vertical);
+            }
+        }
+    }
+}
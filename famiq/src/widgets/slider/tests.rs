@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+use crate::widgets::FamiqResource;
+use crate::slider;
+use bevy::input::keyboard::Key;
+use super::*;
+
+fn setup_test_default_slider(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    slider!(id: "#test-slider");
+}
+
+fn setup_test_slider_with_model(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_fnum("volume", 25.0);
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    slider!(id: "#volume", min: 0, max: 50, step: 5, model: "volume");
+}
+
+fn setup_test_range_slider(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_data("price", vec![20.0_f32, 80.0]);
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    slider!(range: true, vertical: true, model: "price");
+}
+
+#[test]
+fn test_create_default_slider() {
+    let mut app = create_famiq_test_app(setup_test_default_slider);
+
+    let slider_q = app.world_mut()
+        .query::<(&WidgetId, &SliderConfig, &SliderValue)>()
+        .single(app.world());
+
+    let (id, config, value) = slider_q.unwrap();
+    assert_eq!("#test-slider".to_string(), id.0);
+    assert_eq!(config.min, 0.0);
+    assert_eq!(config.max, 100.0);
+    assert!(!config.vertical && !config.range);
+    assert_eq!(value.0[0], 0.0);
+}
+
+#[test]
+fn test_slider_model_places_thumb() {
+    let mut app = create_famiq_test_app(setup_test_slider_with_model);
+    app.update();
+
+    let thumbs = app.world_mut()
+        .query::<&SliderThumbEntities>()
+        .single(app.world())
+        .unwrap()
+        .0;
+    let thumb_node = app.world().get::<Node>(thumbs[0]).unwrap();
+    assert_eq!(thumb_node.left, Val::Percent(50.0));
+}
+
+#[test]
+fn test_slider_keyboard_updates_model() {
+    let mut app = create_famiq_test_app(setup_test_slider_with_model);
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqSlider>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .resource_mut::<FamiqResource>()
+        .update_or_insert_focus_state(entity, true);
+
+    press_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    let r_data = app.world().resource::<RData>();
+    assert_eq!(r_data.data.get("volume"), Some(&RVal::FNum(30.0)));
+
+    let events = app.world().resource::<Events<FaValueChangeEvent>>();
+    let event = events.iter_current_update_events().last().unwrap();
+    assert!(event.is_changed("#volume"));
+    assert_eq!(event.value, RVal::FNum(30.0));
+
+    press_key(&mut app, KeyCode::End, Key::End);
+    let r_data = app.world().resource::<RData>();
+    assert_eq!(r_data.data.get("volume"), Some(&RVal::FNum(50.0)));
+}
+
+#[test]
+fn test_range_slider() {
+    let mut app = create_famiq_test_app(setup_test_range_slider);
+    app.update();
+
+    let (value, config, fill) = app.world_mut()
+        .query::<(&SliderValue, &SliderConfig, &SliderFillEntity)>()
+        .single(app.world())
+        .map(|(value, config, fill)| (*value, *config, fill.0))
+        .unwrap();
+    assert!(config.vertical && config.range);
+    assert_eq!(value.0, [20.0, 80.0]);
+
+    let fill_node = app.world().get::<Node>(fill).unwrap();
+    assert_eq!(fill_node.bottom, Val::Percent(20.0));
+    assert_eq!(fill_node.height, Val::Percent(60.0));
+}
+
+#[test]
+fn test_moved_slider_value() {
+    let config = SliderConfig { min: 0.0, max: 10.0, step: 2.0, vertical: false, range: true };
+    assert_eq!(snap_slider_value(3.2, &config), 4.0);
+    assert_eq!(snap_slider_value(-5.0, &config), 0.0);
+    // low thumb can't pass high thumb
+    assert_eq!(moved_slider_value([2.0, 6.0], &config, 0, 9.0), [6.0, 6.0]);
+    assert_eq!(
+        slider_model_value([2.0, 6.0], &config),
+        RVal::Array(vec![RVal::FNum(2.0), RVal::FNum(6.0)])
+    );
+}
+
+#[test]
+fn test_slider_sends_reactive_data_changed() {
+    let mut app = create_famiq_test_app(setup_test_slider_with_model);
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqSlider>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .resource_mut::<FamiqResource>()
+        .update_or_insert_focus_state(entity, true);
+
+    press_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    let events = app.world().resource::<Events<ReactiveDataChanged>>();
+    let changes: Vec<&ReactiveDataChanged> = events.iter_current_update_events().collect();
+    assert_eq!(changes, vec![&ReactiveDataChanged {
+        key: "volume".to_string(),
+        old: RVal::FNum(25.0),
+        new: RVal::FNum(30.0)
+    }]);
+}
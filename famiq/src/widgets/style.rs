@@ -1,7 +1,10 @@
 use crate::utils;
 use crate::resources::*;
+use crate::errors::{FamiqError, FamiqErrorEvent, StylesFileError};
 use crate::widgets::{style_parse::*, *};
 use bevy::prelude::*;
+#[cfg(target_arch = "wasm32")]
+use bevy::asset::LoadState;
 use bevy::platform::collections::HashSet;
 use cosmic_text::FontSystem;
 use cosmic_text::fontdb::Source;
@@ -11,9 +14,23 @@ use super::DefaultTextSpanConfig;
 
 pub(crate) fn read_styles_from_file_system(
     mut styles: ResMut<StylesKeyValueResource>,
+    mut errors: EventWriter<FamiqErrorEvent>,
+    mut last_error: Local<Option<StylesFileError>>,
     famiq_res: Res<FamiqResource>,
 ) {
-    if let Ok(json_styles) = utils::read_styles_json_file(&famiq_res.style_path) {
+    let result = utils::read_styles_json_file(&famiq_res.style_path);
+
+    // styles file is optional. Report other errors once, not on every hot reload.
+    match result.as_ref() {
+        Err(StylesFileError::StylesFileDoesNotExist) => {}
+        Err(e) if last_error.as_ref() != Some(e) => {
+            errors.write(FamiqErrorEvent(FamiqError::from(e.clone())));
+        }
+        _ => {}
+    }
+    *last_error = result.as_ref().err().cloned();
+
+    if let Ok(json_styles) = result {
         let mut changed_keys: Vec<String> = Vec::new();
 
         for (external_key, external_value) in json_styles.iter() {
@@ -71,10 +88,11 @@ pub(crate) fn load_json_style_asset_wasm(
     }
 
     if !asset_state.fully_loaded {
+        // failed styles asset still loads the font, just without external styles
         let asset = json_asset.get(&asset_state.style_handle);
         let font = font_asset.get(&asset_state.font_handle);
 
-        if asset.is_none() || font.is_none() {
+        if (asset.is_none() && !asset_state.failed) || font.is_none() {
             return;
         }
         asset_state.fully_loaded = true;
@@ -84,7 +102,9 @@ pub(crate) fn load_json_style_asset_wasm(
             FontSystem::new_with_fonts([Source::Binary(font_data)])
         ));
 
-        let json_styles = asset.unwrap();
+        let Some(json_styles) = asset else {
+            return;
+        };
         let mut changed_keys: Vec<String> = Vec::new();
 
         for (external_key, external_value) in json_styles.0.iter() {
@@ -101,6 +121,22 @@ pub(crate) fn load_json_style_asset_wasm(
     }
 }
 
+/// Report styles asset that failed to load on web, once.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn report_json_style_asset_failure(
+    mut asset_state: ResMut<JsonStyleAssetState>,
+    asset_server: Res<AssetServer>,
+    mut errors: EventWriter<FamiqErrorEvent>
+) {
+    if asset_state.fully_loaded || asset_state.failed {
+        return;
+    }
+    if let Some(LoadState::Failed(e)) = asset_server.get_load_state(&asset_state.style_handle) {
+        errors.write(FamiqErrorEvent(FamiqError::InvalidStyles(e.to_string())));
+        asset_state.failed = true;
+    }
+}
+
 pub(crate) fn detect_widget_external_styles_change(
     styles: Res<StylesKeyValueResource>,
     mut style_q: Query<StyleQuery>,
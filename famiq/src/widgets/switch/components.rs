@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+/// Marker component for identifying an entity as a Famiq switch.
+#[derive(Component)]
+pub struct IsFamiqSwitch;
+
+/// On/off state of a switch.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq)]
+pub struct SwitchState(pub bool);
+
+/// Texts shown next to the track when switch is on or off.
+#[derive(Component, Default, Debug, Clone)]
+pub struct SwitchLabels {
+    pub on: String,
+    pub off: String
+}
+
+/// Track color when switch is on.
+#[derive(Component)]
+pub struct SwitchColor(pub Color);
+
+#[derive(Component)]
+pub struct SwitchTrackEntity(pub Entity);
+
+#[derive(Component)]
+pub struct SwitchThumbEntity(pub Entity);
+
+#[derive(Component)]
+pub struct SwitchLabelEntity(pub Entity);
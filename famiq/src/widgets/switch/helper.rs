@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+pub(crate) const TRACK_WIDTH: f32 = 36.0;
+pub(crate) const TRACK_HEIGHT: f32 = 20.0;
+pub(crate) const THUMB_SIZE: f32 = 16.0;
+pub(crate) const THUMB_GAP: f32 = 2.0;
+
+pub(crate) const OFF_COLOR: Color = Color::srgba(0.6, 0.6, 0.6, 0.5);
+
+pub(crate) fn default_switch_node() -> Node {
+    Node {
+        width: Val::Auto,
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        margin: UiRect::vertical(Val::Px(2.0)),
+        ..default()
+    }
+}
+
+pub(crate) fn default_track_node() -> Node {
+    Node {
+        position_type: PositionType::Relative,
+        width: Val::Px(TRACK_WIDTH),
+        height: Val::Px(TRACK_HEIGHT),
+        flex_shrink: 0.0,
+        ..default()
+    }
+}
+
+pub(crate) fn default_thumb_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        width: Val::Px(THUMB_SIZE),
+        height: Val::Px(THUMB_SIZE),
+        top: Val::Px(THUMB_GAP),
+        left: Val::Px(THUMB_GAP),
+        ..default()
+    }
+}
+
+pub(crate) fn default_label_node() -> Node {
+    Node {
+        margin: UiRect::left(Val::Px(6.0)),
+        ..default()
+    }
+}
+
+/// Thumb's left offset at animation `progress`, 0 is off and 1 is on.
+pub(crate) fn thumb_left(progress: f32) -> Val {
+    let travel = TRACK_WIDTH - THUMB_SIZE - THUMB_GAP * 2.0;
+    Val::Px(THUMB_GAP + travel * progress)
+}
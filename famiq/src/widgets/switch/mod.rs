@@ -0,0 +1,267 @@
+pub mod components;
+pub mod helper;
+pub mod systems;
+pub mod tests;
+
+pub(crate) use components::*;
+pub(crate) use systems::*;
+use helper::*;
+
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+
+use crate::widgets::container::base_container::*;
+use crate::widgets::text::base_text::*;
+use crate::widgets::dialog::AnimationProgress;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::plugin::{CursorType, CursorIcons};
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::utils::*;
+use crate::widgets::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct SwitchBuilder {
+    pub on_label: String,
+    pub off_label: String,
+    pub all_reactive_keys: Vec<String>,
+    pub track_entity: Option<Entity>,
+    pub thumb_entity: Option<Entity>,
+    pub label_entity: Option<Entity>
+}
+
+impl SwitchBuilder {
+    pub fn new(font_handle: &Handle<Font>) -> Self {
+        Self {
+            on_label: String::new(),
+            off_label: String::new(),
+            all_reactive_keys: Vec::new(),
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default(),
+            track_entity: None,
+            thumb_entity: None,
+            label_entity: None
+        }
+    }
+
+    pub fn set_on_label(&mut self, label: &str) {
+        self.on_label = resolve_template_alias(label);
+    }
+
+    pub fn set_off_label(&mut self, label: &str) {
+        self.off_label = resolve_template_alias(label);
+    }
+
+    fn labels(&mut self, r_data: &HashMap<String, RVal>) -> SwitchLabels {
+        let on_keys = get_reactive_key(&self.on_label);
+        let off_keys = get_reactive_key(&self.off_label);
+        let labels = SwitchLabels {
+            on: replace_reactive_keys(&self.on_label, &on_keys, r_data),
+            off: replace_reactive_keys(&self.off_label, &off_keys, r_data)
+        };
+        self.all_reactive_keys.extend_from_slice(&on_keys);
+        self.all_reactive_keys.extend_from_slice(&off_keys);
+        labels
+    }
+
+    fn has_label(&self) -> bool {
+        !self.on_label.is_empty() || !self.off_label.is_empty()
+    }
+
+    fn on_color(&self) -> Color {
+        match self.cloned_attrs.color {
+            WidgetColor::Default => PRIMARY_COLOR,
+            _ => get_color(&self.cloned_attrs.color)
+        }
+    }
+
+    fn track_components(&self) -> impl Bundle {
+        (
+            default_track_node(),
+            BackgroundColor(OFF_COLOR),
+            BorderRadius::all(Val::Px(TRACK_HEIGHT / 2.0)),
+            Pickable::IGNORE
+        )
+    }
+
+    fn thumb_components(&self) -> impl Bundle {
+        (
+            default_thumb_node(),
+            BackgroundColor(Color::WHITE),
+            BorderRadius::all(Val::Percent(50.0)),
+            Pickable::IGNORE
+        )
+    }
+
+    fn build_parts(
+        &mut self,
+        switch_entity: Entity,
+        labels: &SwitchLabels,
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) {
+        let thumb_entity = commands.spawn(self.thumb_components()).id();
+        let track_entity = commands.spawn(self.track_components()).add_child(thumb_entity).id();
+        commands
+            .entity(switch_entity)
+            .insert((SwitchTrackEntity(track_entity), SwitchThumbEntity(thumb_entity)))
+            .add_child(track_entity);
+
+        if self.has_label() {
+            let mut label = FaBaseText::new_with_attributes(&labels.off, &self.cloned_attrs);
+            let label_entity = label.build(r_data, commands);
+            commands.entity(label_entity).insert((default_label_node(), Pickable::IGNORE));
+            commands
+                .entity(switch_entity)
+                .insert(SwitchLabelEntity(label_entity))
+                .add_child(label_entity);
+            self.label_entity = Some(label_entity);
+        }
+        self.track_entity = Some(track_entity);
+        self.thumb_entity = Some(thumb_entity);
+    }
+
+    fn rebuild_parts(&mut self, labels: &SwitchLabels, r_data: &HashMap<String, RVal>, world: &mut World) {
+        if let Some(track_entity) = self.track_entity {
+            world.entity_mut(track_entity).insert(self.track_components());
+        }
+        if let Some(thumb_entity) = self.thumb_entity {
+            world.entity_mut(thumb_entity).insert(self.thumb_components());
+        }
+        if let Some(label_entity) = self.label_entity {
+            let mut label = FaBaseText::new_with_attributes(&labels.off, &self.cloned_attrs);
+            label.rebuild(r_data, label_entity, world);
+            world.entity_mut(label_entity).insert(default_label_node());
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_switch_node();
+        self.cloned_attrs.overrided_background_color = Some(Color::NONE);
+        self.cloned_attrs.overrided_border_color = Some(Color::NONE);
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+    }
+}
+
+impl SetupWidget for SwitchBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (MainWidget, IsFamiqSwitch, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+        let labels = self.labels(r_data);
+        let mut switch = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let switch_entity = switch.build(r_data, commands);
+        self.build_parts(switch_entity, &labels, r_data, commands);
+
+        commands
+            .entity(switch_entity)
+            .insert((
+                self.components(),
+                SwitchState::default(),
+                SwitchColor(self.on_color()),
+                AnimationProgress(0.0),
+                labels
+            ))
+            .observe(on_mouse_down)
+            .observe(on_mouse_over)
+            .observe(on_mouse_out);
+
+        if self.attributes.has_tooltip {
+            build_tooltip_node(&self.cloned_attrs, commands, switch_entity);
+        }
+        insert_class_id(commands, switch_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, switch_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                switch_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Switch(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        switch_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+        let labels = self.labels(r_data);
+        let mut switch = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        switch.rebuild(r_data, old_entity, world);
+        self.rebuild_parts(&labels, r_data, world);
+
+        // label & track color follow the current state again
+        world.entity_mut(old_entity).insert((SwitchColor(self.on_color()), labels));
+        if let Some(mut state) = world.get_mut::<SwitchState>(old_entity) {
+            state.set_changed();
+        }
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Switch(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a switch.
+#[macro_export]
+macro_rules! switch {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let s_builder = &mut SwitchBuilder::new(&famiq_builder.get_font_handle());
+        $(
+            $crate::switch_attributes!(s_builder, $key : $value);
+        )*
+        s_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(switch [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! switch_attributes {
+    ($s_builder:ident, on_label: $label:expr) => {{
+        $s_builder.set_on_label($label);
+    }};
+    ($s_builder:ident, off_label: $label:expr) => {{
+        $s_builder.set_off_label($label);
+    }};
+    ($s_builder:ident, model: $model:expr) => {{
+        $s_builder.set_model($model);
+    }};
+    ($s_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($s_builder, $key : $value);
+    }};
+}
+
+/// Determines if switch internal system(s) can run.
+///
+/// True only if there is a switch widget created.
+pub fn can_run_switch_systems(switch_q: Query<&IsFamiqSwitch>) -> bool {
+    !switch_q.is_empty()
+}
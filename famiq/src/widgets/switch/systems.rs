@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use super::*;
+
+type SwitchHoverQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static GlobalTransform, Option<&'static WidgetId>, Option<&'static TooltipEntity>),
+    With<IsFamiqSwitch>
+>;
+
+type SwitchToggleQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut SwitchState, Option<&'static WidgetId>, Option<&'static ReactiveModelKey>),
+    With<IsFamiqSwitch>
+>;
+
+type SwitchAnimationQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static SwitchState,
+        &'static mut AnimationProgress,
+        Ref<'static, SwitchColor>,
+        &'static SwitchTrackEntity,
+        &'static SwitchThumbEntity
+    )
+>;
+
+pub(crate) fn on_mouse_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut commands: Commands,
+    mut writer: EventWriter<FaMouseEvent>,
+    mut tooltip_q: Query<(&mut Node, &mut Transform), With<IsFamiqTooltip>>,
+    switch_q: SwitchHoverQuery,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    if let Ok((transform, id, tooltip_entity)) = switch_q.get(trigger.target()) {
+        _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Pointer);
+        show_tooltip(tooltip_entity, &mut tooltip_q, transform.translation());
+        FaMouseEvent::send_event(&mut writer, EventType::Over, WidgetType::Switch, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut commands: Commands,
+    mut writer: EventWriter<FaMouseEvent>,
+    mut tooltip_q: Query<(&mut Node, &mut Transform), With<IsFamiqTooltip>>,
+    switch_q: Query<(Option<&WidgetId>, Option<&TooltipEntity>), With<IsFamiqSwitch>>,
+    window: Single<Entity, With<Window>>,
+    cursor_icons: Res<CursorIcons>,
+) {
+    if let Ok((id, tooltip_entity)) = switch_q.get(trigger.target()) {
+        _change_cursor_icon(&mut commands, &cursor_icons, *window, CursorType::Default);
+        hide_tooltip(tooltip_entity, &mut tooltip_q);
+        FaMouseEvent::send_event(&mut writer, EventType::Out, WidgetType::Switch, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+/// Toggle the switch, its model is updated and `FaValueChangeEvent` is sent.
+pub(crate) fn on_mouse_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    mut switch_q: SwitchToggleQuery,
+    mut writer: EventWriter<FaValueChangeEvent>,
+    mut fa_query: FaQuery,
+) {
+    if trigger.event().button == PointerButton::Primary
+        && let Ok((mut state, id, model_key)) = switch_q.get_mut(trigger.target())
+    {
+        state.0 = !state.0;
+
+        if let Some(key) = model_key {
+            let _ = fa_query.try_mutate(&key.0, RVal::Bool(state.0));
+        }
+        FaValueChangeEvent::send_event(&mut writer, WidgetType::Switch, trigger.target(), id, RVal::Bool(state.0));
+    }
+    trigger.propagate(false);
+}
+
+pub fn detect_switch_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut switch_q: Query<(
+        Ref<IsFamiqSwitch>,
+        &mut SwitchState,
+        &mut AnimationProgress,
+        Option<&ReactiveModelKey>
+    )>
+) {
+    let data_changed = reactive_data.is_changed() && !reactive_data.is_added();
+
+    for (switch, mut state, mut progress, model_key) in switch_q.iter_mut() {
+        if !data_changed && !switch.is_added() {
+            continue;
+        }
+        if let Some(key) = model_key
+            && let Some(RVal::Bool(value)) = get_reactive_value(&reactive_data.data, &key.0)
+        {
+            state.set_if_neq(SwitchState(*value));
+
+            // no animation for initial state
+            if switch.is_added() {
+                progress.0 = if *value { 1.0 } else { 0.0 };
+            }
+        }
+    }
+}
+
+pub fn update_switch_label(
+    switch_q: Query<(&SwitchState, &SwitchLabels, &SwitchLabelEntity), Changed<SwitchState>>,
+    mut text_q: Query<&mut Text>
+) {
+    for (state, labels, label_entity) in switch_q.iter() {
+        if let Ok(mut text) = text_q.get_mut(label_entity.0) {
+            let label = if state.0 { &labels.on } else { &labels.off };
+            text.0.clone_from(label);
+        }
+    }
+}
+
+/// Move thumb toward current state & blend track color.
+pub fn animate_switch(
+    mut switch_q: SwitchAnimationQuery,
+    mut node_q: Query<&mut Node>,
+    mut bg_q: Query<&mut BackgroundColor>,
+    time: Res<Time>
+) {
+    let delta = time.delta_secs() * 7.0;
+
+    for (state, mut progress, color, track, thumb) in switch_q.iter_mut() {
+        let target = if state.0 { 1.0 } else { 0.0 };
+
+        if progress.0 != target {
+            progress.0 = if state.0 {
+                (progress.0 + delta).min(1.0)
+            } else {
+                (progress.0 - delta).max(0.0)
+            };
+        } else if !progress.is_changed() && !color.is_changed() {
+            continue;
+        }
+
+        if let Ok(mut node) = node_q.get_mut(thumb.0) {
+            node.left = thumb_left(progress.0);
+        }
+        if let Ok(mut bg_color) = bg_q.get_mut(track.0) {
+            bg_color.0 = OFF_COLOR.mix(&color.0, progress.0);
+        }
+    }
+}
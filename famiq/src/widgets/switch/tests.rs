@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use crate::FamiqPlugin;
+use crate::widgets::FamiqResource;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::switch;
+use bevy::ecs::system::RunSystemOnce;
+use super::*;
+
+fn setup_test_default_switch(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    switch!(id: "#test-switch", class: "success");
+}
+
+fn setup_test_switch_with_model(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_bool("sound", true);
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    switch!(on_label: "On", off_label: "Off", model: "sound");
+}
+
+fn label_text(app: &mut App) -> String {
+    let label = app.world_mut()
+        .query::<&SwitchLabelEntity>()
+        .single(app.world())
+        .unwrap()
+        .0;
+    app.world().get::<Text>(label).unwrap().0.clone()
+}
+
+#[test]
+fn test_create_default_switch() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_default_switch);
+    app.update();
+
+    let (id, state, color) = app.world_mut()
+        .query::<(&WidgetId, &SwitchState, &SwitchColor)>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!("#test-switch".to_string(), id.0);
+    assert!(!state.0);
+    assert_ne!(color.0, PRIMARY_COLOR, "success class should be used");
+}
+
+#[test]
+fn test_switch_follows_model() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_systems(Startup, setup_test_switch_with_model);
+    app.update();
+
+    let (state, progress, thumb) = app.world_mut()
+        .query::<(&SwitchState, &AnimationProgress, &SwitchThumbEntity)>()
+        .single(app.world())
+        .map(|(state, progress, thumb)| (state.0, progress.0, thumb.0))
+        .unwrap();
+    assert!(state);
+    assert_eq!(progress, 1.0, "initial state isn't animated");
+    assert_eq!(app.world().get::<Node>(thumb).unwrap().left, thumb_left(1.0));
+    assert_eq!(label_text(&mut app), "On");
+
+    let _ = app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+        fa_query.mutate_bool("sound", false);
+    });
+    app.update();
+
+    let state = app.world_mut()
+        .query::<&SwitchState>()
+        .single(app.world())
+        .unwrap();
+    assert!(!state.0);
+    assert_eq!(label_text(&mut app), "Off");
+}
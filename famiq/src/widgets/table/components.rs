@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+/// Marker component for identifying an entity as a Famiq table.
+#[derive(Component)]
+pub struct IsFamiqTable;
+
+/// Marker component identifying the header row of a table.
+#[derive(Component)]
+pub struct IsFamiqTableHeader;
+
+/// Column of a table, `key` is the key of its cells in every row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableColumn {
+    pub key: String,
+    pub title: String,
+    pub width: f32,
+    pub sortable: bool,
+    pub editable: bool
+}
+
+impl TableColumn {
+    pub fn new(key: &str, title: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            title: title.to_string(),
+            width: 120.0,
+            sortable: true,
+            editable: false
+        }
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct TableColumns(pub Vec<TableColumn>);
+
+/// Reactive key of table rows, an array of maps keyed by column keys.
+#[derive(Component, Clone, Debug)]
+pub struct TableRowsKey(pub String);
+
+/// Sorted column of a table, rows keep their data order if `column` is None.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq)]
+pub struct TableSort {
+    pub column: Option<usize>,
+    pub descending: bool
+}
+
+/// Data index of the selected row.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq)]
+pub struct TableSelected(pub Option<usize>);
+
+/// Row entities in display order, along with the data index each of them shows.
+#[derive(Component, Default, Debug)]
+pub struct TableRows {
+    pub entities: Vec<Entity>,
+    pub order: Vec<usize>
+}
+
+/// Cell being edited, `row` is the data index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableEdit {
+    pub row: usize,
+    pub column: usize,
+    pub text: String
+}
+
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+pub struct TableEditing(pub Option<TableEdit>);
+
+/// Text style of cells & background color of selected row.
+#[derive(Component, Clone, Debug)]
+pub struct TableStyle {
+    pub font: TextFont,
+    pub text_color: Color,
+    pub selected_color: Color
+}
+
+/// Row at display position `slot` of a table.
+#[derive(Component)]
+pub struct TableRow {
+    pub table: Entity,
+    pub slot: usize
+}
+
+#[derive(Component)]
+pub struct TableCell {
+    pub table: Entity,
+    pub slot: usize,
+    pub column: usize
+}
+
+#[derive(Component)]
+pub struct TableHeaderCell {
+    pub table: Entity,
+    pub column: usize
+}
+
+/// Handle at the right edge of a header cell, dragging it resizes the column.
+#[derive(Component)]
+pub struct TableResizeHandle {
+    pub table: Entity,
+    pub column: usize
+}
+
+#[derive(Component)]
+pub struct TableHeaderEntity(pub Entity);
+
+#[derive(Component)]
+pub struct TableBodyEntity(pub Entity);
+
+/// Header cells of a table with their label entities.
+#[derive(Component)]
+pub struct TableHeaderCellEntities(pub Vec<(Entity, Entity)>);
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use std::cmp::Ordering;
+
+use crate::widgets::text_input::helper::{rval_to_number, update_model_value};
+use super::*;
+
+/// Rows of a table, non-map items are shown as empty rows.
+pub(crate) fn table_rows<'a>(r_data: &'a HashMap<String, RVal>, key: &str) -> Vec<Option<&'a HashMap<String, RVal>>> {
+    match get_reactive_value(r_data, key) {
+        Some(RVal::Array(items)) => items
+            .iter()
+            .map(|item| match item {
+                RVal::Map(row) => Some(row),
+                _ => None
+            })
+            .collect(),
+        _ => Vec::new()
+    }
+}
+
+pub(crate) fn cell_text(row: Option<&HashMap<String, RVal>>, column: &TableColumn) -> String {
+    row.and_then(|row| row.get(&column.key))
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// Numbers are compared by value, others by text. Missing cells come last.
+fn compare_cells(a: Option<&RVal>, b: Option<&RVal>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => match (rval_to_number(a), rval_to_number(b)) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            _ => a.to_string().cmp(&b.to_string())
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal
+    }
+}
+
+/// Data indexes of rows in display order.
+pub(crate) fn sorted_order(
+    rows: &[Option<&HashMap<String, RVal>>],
+    columns: &[TableColumn],
+    sort: &TableSort
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+
+    if let Some(column) = sort.column.and_then(|index| columns.get(index)) {
+        let cell = |index: usize| rows[index].and_then(|row| row.get(&column.key));
+
+        order.sort_by(|a, b| {
+            let ordering = compare_cells(cell(*a), cell(*b));
+            if sort.descending { ordering.reverse() } else { ordering }
+        });
+    }
+    order
+}
+
+/// Sort by the column ascending, then descending, then back to data order.
+pub(crate) fn next_sort(sort: &TableSort, column: usize) -> TableSort {
+    match (sort.column, sort.descending) {
+        (Some(current), false) if current == column => TableSort { column: Some(column), descending: true },
+        (Some(current), true) if current == column => TableSort::default(),
+        _ => TableSort { column: Some(column), descending: false }
+    }
+}
+
+/// Write edited text to a cell. The cell keeps its type, invalid numbers are ignored.
+pub(crate) fn update_cell_value(cell: &mut RVal, text: &str) {
+    match cell {
+        RVal::Bool(v) => {
+            if let Ok(value) = text.trim().parse::<bool>() {
+                *v = value;
+            }
+        }
+        RVal::None => *cell = RVal::Str(text.to_owned()),
+        _ => update_model_value(cell, text)
+    }
+}
@@ -0,0 +1,255 @@
+pub mod components;
+pub mod helper;
+pub mod styling;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub(crate) use helper::*;
+pub(crate) use styling::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+
+use crate::widgets::container::base_container::*;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::utils::*;
+use crate::widgets::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct TableBuilder {
+    pub columns: Vec<TableColumn>,
+    /// reactive key of rows, an array of maps
+    pub rows_key: String,
+    pub all_reactive_keys: Vec<String>
+}
+
+impl TableBuilder {
+    pub fn new(font_handle: &Handle<Font>) -> Self {
+        Self {
+            columns: Vec::new(),
+            rows_key: String::new(),
+            all_reactive_keys: Vec::new(),
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default()
+        }
+    }
+
+    pub fn add_column(&mut self, column: TableColumn) {
+        self.columns.push(column);
+    }
+
+    pub fn set_rows(&mut self, rows_key: &str) {
+        self.rows_key = resolve_key_alias(rows_key);
+    }
+
+    fn style(&self) -> TableStyle {
+        let selected_color = match self.cloned_attrs.color {
+            WidgetColor::Default => PRIMARY_COLOR,
+            _ => get_color(&self.cloned_attrs.color)
+        };
+        TableStyle {
+            font: TextFont {
+                font: self.cloned_attrs.font_handle.clone().unwrap(),
+                font_size: get_text_size(&self.cloned_attrs.size),
+                ..default()
+            },
+            text_color: get_text_color(&self.cloned_attrs.color),
+            selected_color: selected_color.with_alpha(0.35)
+        }
+    }
+
+    fn build_header(
+        &self,
+        table_entity: Entity,
+        style: &TableStyle,
+        commands: &mut Commands
+    ) -> (Entity, TableHeaderCellEntities) {
+        let mut cells = Vec::new();
+
+        for (index, column) in self.columns.iter().enumerate() {
+            let label_entity = commands
+                .spawn((Text::new(column.title.clone()), style.font.clone(), TextColor(style.text_color), Pickable::IGNORE))
+                .id();
+            let handle_entity = commands
+                .spawn((default_resize_handle_node(), TableResizeHandle { table: table_entity, column: index }))
+                .observe(on_resize_handle_down)
+                .observe(on_resize_handle_drag)
+                .observe(on_pointer_cursor_over)
+                .observe(on_pointer_cursor_out)
+                .id();
+            let cell_entity = commands
+                .spawn((default_table_cell_node(column.width), TableHeaderCell { table: table_entity, column: index }))
+                .observe(on_header_cell_down)
+                .observe(on_pointer_cursor_over)
+                .observe(on_pointer_cursor_out)
+                .add_children(&[label_entity, handle_entity])
+                .id();
+            cells.push((cell_entity, label_entity));
+        }
+        let cell_entities: Vec<Entity> = cells.iter().map(|(cell, _)| *cell).collect();
+        let header_entity = commands
+            .spawn((
+                default_table_row_node(),
+                IsFamiqTableHeader,
+                BackgroundColor(get_color(&self.cloned_attrs.color)),
+                // stays above rows while sticking to the top of a scroll
+                ZIndex(1)
+            ))
+            .add_children(&cell_entities)
+            .id();
+
+        (header_entity, TableHeaderCellEntities(cells))
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_table_node();
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+        self._process_built_in_size_class();
+    }
+}
+
+impl SetupWidget for TableBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqTable, MainWidget, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+        let style = self.style();
+
+        let mut table = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let table_entity = table.build(r_data, commands);
+        let (header_entity, header_cells) = self.build_header(table_entity, &style, commands);
+        let body_entity = commands.spawn(default_table_body_node()).id();
+
+        commands
+            .entity(table_entity)
+            .insert((
+                self.components(),
+                TableColumns(self.columns.clone()),
+                TableRowsKey(self.rows_key.clone()),
+                TableSort::default(),
+                TableSelected::default(),
+                TableEditing::default(),
+                TableRows::default(),
+                TableHeaderEntity(header_entity),
+                TableBodyEntity(body_entity),
+                header_cells,
+                style
+            ))
+            .add_children(&[header_entity, body_entity]);
+
+        insert_class_id(commands, table_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, table_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                table_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Table(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        table_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+
+        let mut table = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        table.rebuild(r_data, old_entity, world);
+
+        // rows are spawned again with the new style
+        world.entity_mut(old_entity).insert(self.style());
+        let old_rows = world
+            .get_mut::<TableRows>(old_entity)
+            .map(|mut rows| std::mem::take(&mut rows.entities))
+            .unwrap_or_default();
+        for row in old_rows {
+            world.entity_mut(row).despawn();
+        }
+        if let Some(mut sort) = world.get_mut::<TableSort>(old_entity) {
+            sort.set_changed();
+        }
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Table(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a table showing rows of a reactive key.
+///
+/// ```text
+/// table!(
+///     columns: [
+///         TableColumn::new("name", "Name").editable(true),
+///         TableColumn::new("score", "Score").width(80.0)
+///     ],
+///     rows: "players",
+///     model: "selected_player"
+/// );
+/// ```
+#[macro_export]
+macro_rules! table {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let t_builder = &mut TableBuilder::new(&famiq_builder.get_font_handle());
+        $(
+            $crate::table_attributes!(t_builder, $key : $value);
+        )*
+        t_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(table [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! table_attributes {
+    ($t_builder:ident, columns: [$( $column:expr ),* $(,)?]) => {{
+        $(
+            $t_builder.add_column($column);
+        )*
+    }};
+    ($t_builder:ident, rows: $rows:expr) => {{
+        $t_builder.set_rows($rows);
+    }};
+    ($t_builder:ident, model: $model:expr) => {{
+        $t_builder.set_model($model);
+    }};
+    ($t_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($t_builder, $key : $value);
+    }};
+}
+
+pub fn can_run_table_systems(table_q: Query<&IsFamiqTable>) -> bool {
+    !table_q.is_empty()
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+pub(crate) const MIN_COLUMN_WIDTH: f32 = 40.0;
+
+pub fn default_table_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Column,
+        overflow: Overflow::clip_x(),
+        margin: UiRect {
+            top: Val::Px(2.0),
+            right: Val::Px(0.0),
+            left: Val::Px(0.0),
+            bottom: Val::Px(2.0),
+        },
+        ..default()
+    }
+}
+
+pub fn default_table_row_node() -> Node {
+    Node {
+        width: Val::Auto,
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        ..default()
+    }
+}
+
+pub fn default_table_body_node() -> Node {
+    Node {
+        width: Val::Auto,
+        height: Val::Auto,
+        flex_direction: FlexDirection::Column,
+        ..default()
+    }
+}
+
+pub fn default_table_cell_node(width: f32) -> Node {
+    Node {
+        width: Val::Px(width),
+        flex_shrink: 0.0,
+        padding: UiRect {
+            top: Val::Px(4.0),
+            right: Val::Px(6.0),
+            left: Val::Px(6.0),
+            bottom: Val::Px(4.0),
+        },
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+pub fn default_resize_handle_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        right: Val::Px(0.0),
+        top: Val::Px(0.0),
+        bottom: Val::Px(0.0),
+        width: Val::Px(6.0),
+        ..default()
+    }
+}
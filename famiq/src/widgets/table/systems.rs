@@ -0,0 +1,368 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+
+use crate::widgets::scroll::ScrollList;
+use super::*;
+
+type TableRenderQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, IsFamiqTable>,
+        &'static TableColumns,
+        &'static TableRowsKey,
+        Ref<'static, TableSort>,
+        Ref<'static, TableSelected>,
+        Ref<'static, TableEditing>,
+        &'static mut TableRows,
+        &'static TableStyle,
+        &'static TableBodyEntity
+    )
+>;
+
+type TableSelectQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static TableColumns,
+        &'static TableRowsKey,
+        &'static TableRows,
+        &'static mut TableSelected,
+        &'static mut TableEditing,
+        Option<&'static WidgetId>,
+        Option<&'static ReactiveModelKey>
+    ),
+    With<IsFamiqTable>
+>;
+
+type TableColumnsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static TableColumns, &'static TableSort, &'static TableHeaderCellEntities, &'static TableRows),
+    Or<(Changed<TableColumns>, Changed<TableSort>)>
+>;
+
+type TableCellNodeQuery<'w, 's> = Query<'w, 's, &'static mut Node, Or<(With<TableCell>, With<TableHeaderCell>)>>;
+
+pub(crate) fn on_header_cell_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    header_q: Query<&TableHeaderCell>,
+    mut table_q: Query<(&TableColumns, &mut TableSort)>
+) {
+    if let Ok(header) = header_q.get(trigger.target())
+        && let Ok((columns, mut sort)) = table_q.get_mut(header.table)
+        && columns.0[header.column].sortable
+    {
+        *sort = next_sort(&sort, header.column);
+    }
+    trigger.propagate(false);
+}
+
+/// Pressing a resize handle shouldn't sort its column.
+pub(crate) fn on_resize_handle_down(mut trigger: Trigger<Pointer<Pressed>>) {
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_resize_handle_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    handle_q: Query<&TableResizeHandle>,
+    mut table_q: Query<&mut TableColumns>
+) {
+    if let Ok(handle) = handle_q.get(trigger.target())
+        && let Ok(mut columns) = table_q.get_mut(handle.table)
+    {
+        let column = &mut columns.0[handle.column];
+        column.width = (column.width + trigger.event().delta.x).max(MIN_COLUMN_WIDTH);
+    }
+    trigger.propagate(false);
+}
+
+/// Pressing a cell selects its row. Pressing an editable cell of the selected row
+/// starts editing it.
+pub(crate) fn on_cell_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    cell_q: Query<&TableCell>,
+    mut table_q: TableSelectQuery,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut r_data: ResMut<RData>,
+    mut writer: EventWriter<FaValueChangeEvent>
+) {
+    if let Ok(cell) = cell_q.get(trigger.target())
+        && let Ok((columns, rows_key, rows, mut selected, mut editing, id, model_key)) = table_q.get_mut(cell.table)
+        && let Some(index) = rows.order.get(cell.slot).copied()
+    {
+        let column = &columns.0[cell.column];
+
+        if column.editable && selected.0 == Some(index) {
+            let rows = table_rows(&r_data.data, &rows_key.0);
+            let text = cell_text(rows.get(index).copied().flatten(), column);
+            editing.set_if_neq(TableEditing(Some(TableEdit { row: index, column: cell.column, text })));
+        } else {
+            editing.set_if_neq(TableEditing(None));
+
+            if selected.0 != Some(index) {
+                selected.0 = Some(index);
+
+                if let Some(key) = model_key {
+                    let _ = r_data.try_mutate(&key.0, RVal::Num(index as i32));
+                }
+                FaValueChangeEvent::send_event(&mut writer, WidgetType::Table, cell.table, id, RVal::Num(index as i32));
+            }
+        }
+        // focused table receives typing of edited cell
+        famiq_res.update_all_focus_states(false);
+        famiq_res.update_or_insert_focus_state(cell.table, true);
+    }
+    trigger.propagate(false);
+}
+
+/// Typing edits the cell being edited of focused table. Enter writes the text to the row,
+/// Escape or losing focus cancels editing.
+pub fn handle_table_editing(
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mut table_q: Query<(Entity, &TableColumns, &TableRowsKey, &mut TableEditing), With<IsFamiqTable>>,
+    famiq_res: Res<FamiqResource>,
+    mut r_data: ResMut<RData>
+) {
+    if famiq_res.is_changed() {
+        for (entity, _, _, mut editing) in table_q.iter_mut() {
+            if editing.0.is_some() && famiq_res.get_widget_focus_state(&entity) != Some(true) {
+                editing.0 = None;
+            }
+        }
+    }
+
+    for e in evr_kbd.read() {
+        if e.state == ButtonState::Released {
+            continue;
+        }
+
+        for (entity, columns, rows_key, mut editing) in table_q.iter_mut() {
+            if famiq_res.get_widget_focus_state(&entity) != Some(true) {
+                continue;
+            }
+            let Some(edit) = editing.0.as_mut() else { continue };
+
+            match &e.logical_key {
+                Key::Character(key_input) => edit.text.push_str(key_input),
+                Key::Space => edit.text.push(' '),
+                Key::Backspace => {
+                    edit.text.pop();
+                }
+                Key::Escape => editing.0 = None,
+                Key::Enter => {
+                    let column_key = &columns.0[edit.column].key;
+                    let row_path = format!("{}.{}", rows_key.0, edit.row);
+
+                    if let Some(RVal::Map(_)) = get_reactive_value(&r_data.data, &row_path) {
+                        r_data.mark_changed(&format!("{row_path}.{column_key}"));
+
+                        if let Some(RVal::Map(row)) = get_reactive_value_mut(&mut r_data.data, &row_path) {
+                            let cell = row.entry(column_key.clone()).or_insert(RVal::None);
+                            update_cell_value(cell, &edit.text);
+                        }
+                    }
+                    editing.0 = None;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn detect_table_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut table_q: Query<(Ref<IsFamiqTable>, &mut TableSelected, Option<&ReactiveModelKey>)>
+) {
+    let data_changed = reactive_data.is_changed() && !reactive_data.is_added();
+
+    for (table, mut selected, model_key) in table_q.iter_mut() {
+        if !data_changed && !table.is_added() {
+            continue;
+        }
+        if let Some(key) = model_key {
+            match get_reactive_value(&reactive_data.data, &key.0) {
+                Some(RVal::Num(v)) => {
+                    selected.set_if_neq(TableSelected(usize::try_from(*v).ok()));
+                }
+                Some(RVal::None) => {
+                    selected.set_if_neq(TableSelected(None));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Text of a cell, the cell being edited shows its text with a cursor.
+fn display_text(
+    row: Option<&HashMap<String, RVal>>,
+    column: &TableColumn,
+    column_index: usize,
+    row_index: usize,
+    editing: &TableEditing
+) -> String {
+    match &editing.0 {
+        Some(edit) if edit.row == row_index && edit.column == column_index => format!("{}|", edit.text),
+        _ => cell_text(row, column)
+    }
+}
+
+fn spawn_table_row(
+    commands: &mut Commands,
+    row: TableRow,
+    columns: &[TableColumn],
+    texts: Vec<String>,
+    style: &TableStyle,
+    color: Color
+) -> Entity {
+    let (table_entity, slot) = (row.table, row.slot);
+    let row_entity = commands.spawn((default_table_row_node(), row, BackgroundColor(color))).id();
+
+    for (index, (column, text)) in columns.iter().zip(texts).enumerate() {
+        let cell_entity = commands
+            .spawn((
+                default_table_cell_node(column.width),
+                Text::new(text),
+                style.font.clone(),
+                TextColor(style.text_color),
+                TableCell { table: table_entity, slot, column: index }
+            ))
+            .observe(on_cell_down)
+            .observe(on_pointer_cursor_over)
+            .observe(on_pointer_cursor_out)
+            .id();
+
+        commands.entity(row_entity).add_child(cell_entity);
+    }
+    row_entity
+}
+
+/// Internal system to show rows of tables in sorted order, along with the selected row
+/// and the cell being edited. Rows are spawned again only if their number changes.
+pub(crate) fn update_table_rows(
+    mut commands: Commands,
+    reactive_data: Res<RData>,
+    mut table_q: TableRenderQuery,
+    mut row_q: Query<(&mut BackgroundColor, &Children), With<TableRow>>,
+    mut text_q: Query<&mut Text, With<TableCell>>
+) {
+    for (
+        entity,
+        table,
+        columns,
+        rows_key,
+        sort,
+        selected,
+        editing,
+        mut table_rows_state,
+        style,
+        body
+    ) in table_q.iter_mut() {
+        let need_update = reactive_data.is_changed()
+            || table.is_added()
+            || sort.is_changed()
+            || selected.is_changed()
+            || editing.is_changed();
+
+        if !need_update {
+            continue;
+        }
+        let rows = table_rows(&reactive_data.data, &rows_key.0);
+        let order = sorted_order(&rows, &columns.0, &sort);
+        let respawn = order.len() != table_rows_state.entities.len();
+
+        if respawn {
+            for row_entity in table_rows_state.entities.drain(..) {
+                commands.entity(row_entity).despawn();
+            }
+        }
+        for (slot, index) in order.iter().copied().enumerate() {
+            let row_color = if selected.0 == Some(index) { style.selected_color } else { Color::NONE };
+            let texts: Vec<String> = columns.0
+                .iter()
+                .enumerate()
+                .map(|(column_index, column)| display_text(rows[index], column, column_index, index, &editing))
+                .collect();
+
+            if respawn {
+                let row = TableRow { table: entity, slot };
+                let row_entity = spawn_table_row(&mut commands, row, &columns.0, texts, style, row_color);
+                commands.entity(body.0).add_child(row_entity);
+                table_rows_state.entities.push(row_entity);
+                continue;
+            }
+            if let Ok((mut bg_color, children)) = row_q.get_mut(table_rows_state.entities[slot]) {
+                bg_color.0 = row_color;
+
+                for (cell_entity, new_text) in children.iter().zip(texts) {
+                    if let Ok(mut text) = text_q.get_mut(cell_entity)
+                        && text.0 != new_text
+                    {
+                        text.0 = new_text;
+                    }
+                }
+            }
+        }
+        table_rows_state.order = order;
+    }
+}
+
+/// Apply column widths to header & cells, and show the sort direction on header labels.
+pub fn update_table_columns(
+    table_q: TableColumnsQuery,
+    children_q: Query<&Children, With<TableRow>>,
+    mut node_q: TableCellNodeQuery,
+    mut label_q: Query<&mut Text, Without<TableCell>>
+) {
+    for (columns, sort, header_cells, rows) in table_q.iter() {
+        for (index, (column, (header_cell, label))) in columns.0.iter().zip(header_cells.0.iter()).enumerate() {
+            if let Ok(mut node) = node_q.get_mut(*header_cell) {
+                node.width = Val::Px(column.width);
+            }
+            if let Ok(mut text) = label_q.get_mut(*label) {
+                text.0 = match sort.column {
+                    Some(sorted) if sorted == index && sort.descending => format!("{} ▼", column.title),
+                    Some(sorted) if sorted == index => format!("{} ▲", column.title),
+                    _ => column.title.clone()
+                };
+            }
+        }
+        for children in rows.entities.iter().filter_map(|row| children_q.get(*row).ok()) {
+            for (cell_entity, column) in children.iter().zip(columns.0.iter()) {
+                if let Ok(mut node) = node_q.get_mut(cell_entity) {
+                    node.width = Val::Px(column.width);
+                }
+            }
+        }
+    }
+}
+
+/// Keep header of a table at the top of its scroll area while the rows are scrolled.
+pub fn update_sticky_table_headers(
+    table_q: Query<(Entity, &TableHeaderEntity, &GlobalTransform, &ComputedNode), With<IsFamiqTable>>,
+    parent_q: Query<&ChildOf>,
+    panel_q: Query<(&ScrollList, &GlobalTransform, &ComputedNode)>,
+    mut header_q: Query<(&mut Node, &ComputedNode), With<IsFamiqTableHeader>>
+) {
+    for (entity, header_entity, transform, computed) in table_q.iter() {
+        let Some((scroll_list, panel_transform, panel_computed)) = parent_q
+            .iter_ancestors(entity)
+            .find_map(|ancestor| panel_q.get(ancestor).ok()) else { continue };
+
+        let Ok((mut header_node, header_computed)) = header_q.get_mut(header_entity.0) else { continue };
+
+        let scale = computed.inverse_scale_factor();
+        let table_top = transform.translation().y - computed.size().y / 2.0;
+        let panel_top = panel_transform.translation().y - panel_computed.size().y / 2.0;
+        let offset_in_panel = (table_top - panel_top) * scale;
+        let max_top = ((computed.size().y - header_computed.size().y) * scale).max(0.0);
+        let top = (-scroll_list.position - offset_in_panel).clamp(0.0, max_top);
+
+        if header_node.top != Val::Px(top) {
+            header_node.top = Val::Px(top);
+        }
+    }
+}
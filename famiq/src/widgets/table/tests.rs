@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::widgets::FamiqResource;
+use crate::table;
+use bevy::input::keyboard::Key;
+use super::*;
+
+fn player(name: &str, score: i32) -> RVal {
+    let mut row = HashMap::new();
+    row.insert("name".to_string(), RVal::Str(name.to_string()));
+    row.insert("score".to_string(), RVal::Num(score));
+    RVal::Map(row)
+}
+
+fn setup_test_table(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_array("players", vec![player("Kim", 10), player("Bo", 30), player("Al", 20)]);
+    fa_query.insert_none("selected");
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    table!(
+        id: "#test-table",
+        columns: [
+            TableColumn::new("name", "Name").editable(true),
+            TableColumn::new("score", "Score").editable(true)
+        ],
+        rows: "players",
+        model: "selected"
+    );
+}
+
+fn table_entity(app: &mut App) -> Entity {
+    app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTable>>()
+        .single(app.world())
+        .unwrap()
+}
+
+/// Texts of the first column in display order.
+fn first_column(app: &mut App) -> Vec<String> {
+    let entity = table_entity(app);
+    let rows = app.world().get::<TableRows>(entity).unwrap().entities.clone();
+
+    rows.iter()
+        .map(|row| {
+            let cell = app.world().get::<Children>(*row).unwrap()[0];
+            app.world().get::<Text>(cell).unwrap().0.clone()
+        })
+        .collect()
+}
+
+#[test]
+fn test_table_rows_and_sorting() {
+    let mut app = create_famiq_test_app(setup_test_table);
+    app.update();
+    let entity = table_entity(&mut app);
+    assert_eq!(vec!["Kim", "Bo", "Al"], first_column(&mut app));
+
+    let sort = *app.world().get::<TableSort>(entity).unwrap();
+    app.world_mut().entity_mut(entity).insert(next_sort(&sort, 1));
+    app.update();
+    assert_eq!(vec!["Kim", "Al", "Bo"], first_column(&mut app));
+
+    let sort = *app.world().get::<TableSort>(entity).unwrap();
+    app.world_mut().entity_mut(entity).insert(next_sort(&sort, 1));
+    app.update();
+    assert_eq!(vec!["Bo", "Al", "Kim"], first_column(&mut app));
+    assert_eq!(vec![1, 2, 0], app.world().get::<TableRows>(entity).unwrap().order);
+}
+
+#[test]
+fn test_table_selection_follows_model() {
+    let mut app = create_famiq_test_app(setup_test_table);
+    app.update();
+    let entity = table_entity(&mut app);
+
+    app.world_mut().resource_mut::<RData>().data.insert("selected".to_string(), RVal::Num(1));
+    app.update();
+
+    assert_eq!(TableSelected(Some(1)), *app.world().get::<TableSelected>(entity).unwrap());
+    let row = app.world().get::<TableRows>(entity).unwrap().entities[1];
+    assert_ne!(Color::NONE, app.world().get::<BackgroundColor>(row).unwrap().0);
+}
+
+#[test]
+fn test_table_inline_editing() {
+    let mut app = create_famiq_test_app(setup_test_table);
+    app.update();
+    let entity = table_entity(&mut app);
+
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    app.world_mut().entity_mut(entity).insert(TableEditing(Some(TableEdit {
+        row: 0,
+        column: 0,
+        text: "Kim".to_string()
+    })));
+    press_key(&mut app, KeyCode::KeyX, Key::Character("x".into()));
+    assert_eq!(vec!["Kimx|", "Bo", "Al"], first_column(&mut app));
+
+    press_key(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(vec!["Kimx", "Bo", "Al"], first_column(&mut app));
+
+    // invalid number is ignored, the cell keeps its type
+    app.world_mut().entity_mut(entity).insert(TableEditing(Some(TableEdit {
+        row: 0,
+        column: 1,
+        text: "ten".to_string()
+    })));
+    press_key(&mut app, KeyCode::Enter, Key::Enter);
+
+    let players = app.world().resource::<RData>().data.get("players").cloned();
+    assert_eq!(Some(RVal::Array(vec![player("Kimx", 10), player("Bo", 30), player("Al", 20)])), players);
+}
+
+#[test]
+fn test_table_selection_sends_reactive_data_changed() {
+    use bevy::picking::backend::HitData;
+    use bevy::picking::pointer::{Location, PointerButton, PointerId};
+    use bevy::render::camera::{ManualTextureViewHandle, NormalizedRenderTarget};
+
+    let mut app = create_famiq_test_app(setup_test_table);
+    app.update();
+    let entity = table_entity(&mut app);
+    let row = app.world().get::<TableRows>(entity).unwrap().entities[1];
+    let cell = app.world().get::<Children>(row).unwrap()[0];
+
+    app.world_mut().trigger_targets(
+        Pointer::new(
+            PointerId::Mouse,
+            Location {
+                target: NormalizedRenderTarget::TextureView(ManualTextureViewHandle(0)),
+                position: Vec2::ZERO
+            },
+            cell,
+            Pressed {
+                button: PointerButton::Primary,
+                hit: HitData::new(Entity::PLACEHOLDER, 0.0, None, None)
+            }
+        ),
+        cell
+    );
+    app.update();
+
+    let events = app.world().resource::<Events<ReactiveDataChanged>>();
+    let changes: Vec<&ReactiveDataChanged> = events.iter_current_update_events().collect();
+    assert_eq!(changes, vec![&ReactiveDataChanged {
+        key: "selected".to_string(),
+        old: RVal::None,
+        new: RVal::Num(1)
+    }]);
+}
+
+#[test]
+fn test_table_cell_edit_sends_reactive_data_changed() {
+    let mut app = create_famiq_test_app(setup_test_table);
+    app.update();
+    let entity = table_entity(&mut app);
+
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    app.world_mut().entity_mut(entity).insert(TableEditing(Some(TableEdit {
+        row: 1,
+        column: 1,
+        text: "35".to_string()
+    })));
+    press_key(&mut app, KeyCode::Enter, Key::Enter);
+
+    let events = app.world().resource::<Events<ReactiveDataChanged>>();
+    let changes: Vec<&ReactiveDataChanged> = events.iter_current_update_events().collect();
+    assert_eq!(changes, vec![&ReactiveDataChanged {
+        key: "players.1.score".to_string(),
+        old: RVal::Num(30),
+        new: RVal::Num(35)
+    }]);
+}
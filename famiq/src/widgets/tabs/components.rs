@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use crate::widgets::conditional::BranchTemplate;
+
+/// Marker component for identifying an entity as a Famiq tabs.
+#[derive(Component)]
+pub struct IsFamiqTabs;
+
+#[derive(Component)]
+pub struct IsFamiqTabHeader;
+
+/// Index of the active tab.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq)]
+pub struct TabsActive(pub usize);
+
+/// Labels of tab headers. `Str` models are written with them.
+#[derive(Component, Default, Debug, Clone)]
+pub struct TabsLabels(pub Vec<String>);
+
+/// Content of a tab, built into `slot` the first time it's needed.
+#[derive(Clone, Debug)]
+pub struct TabPanel {
+    pub template: BranchTemplate,
+    pub slot: Entity,
+    pub built: bool
+}
+
+/// Panels of a tabs. Lazy panels are built on first activation,
+/// others are built along with the tabs.
+#[derive(Component, Debug)]
+pub struct TabsPanels {
+    pub panels: Vec<TabPanel>,
+    pub lazy: bool,
+    pub(crate) aliases: Vec<(String, String)>
+}
+
+/// Header of the tab at `index`.
+#[derive(Component)]
+pub struct TabHeader {
+    pub tabs: Entity,
+    pub index: usize
+}
+
+#[derive(Component)]
+pub struct TabsHeaderEntities(pub Vec<Entity>);
+
+/// Border color of the active tab header.
+#[derive(Component)]
+pub struct TabsActiveColor(pub Color);
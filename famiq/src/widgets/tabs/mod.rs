@@ -0,0 +1,312 @@
+pub mod components;
+pub mod styling;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub(crate) use styling::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+use std::sync::Arc;
+
+use crate::widgets::container::base_container::*;
+use crate::widgets::text::base_text::*;
+use crate::widgets::conditional::BranchTemplate;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::utils::*;
+use crate::widgets::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct TabsBuilder {
+    /// header labels, may contain reactive keys
+    pub labels: Vec<String>,
+    pub panels: Vec<BranchTemplate>,
+    /// build panels on first activation instead of along with the tabs
+    pub lazy: bool,
+    /// class of every tab header, for styling headers from the stylesheet
+    pub header_class: Option<String>,
+    /// class of every panel
+    pub panel_class: Option<String>,
+    pub all_reactive_keys: Vec<String>,
+    pub header_text_entities: Vec<Entity>,
+    /// aliases of outer lists, lazy panels are built later with them
+    pub(crate) aliases: Vec<(String, String)>
+}
+
+impl TabsBuilder {
+    pub fn new(font_handle: &Handle<Font>) -> Self {
+        Self {
+            labels: Vec::new(),
+            panels: Vec::new(),
+            lazy: false,
+            header_class: None,
+            panel_class: None,
+            all_reactive_keys: Vec::new(),
+            header_text_entities: Vec::new(),
+            aliases: current_template_aliases(),
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default()
+        }
+    }
+
+    pub fn set_labels(&mut self, labels: Vec<String>) {
+        self.labels = labels.iter().map(|label| resolve_template_alias(label)).collect();
+    }
+
+    pub fn add_panel(&mut self, panel: impl Fn() -> Entity + Send + Sync + 'static) {
+        self.panels.push(BranchTemplate(Arc::new(panel)));
+    }
+
+    /// Labels with reactive keys replaced.
+    fn resolve_labels(&mut self, r_data: &HashMap<String, RVal>) -> Vec<String> {
+        self.labels
+            .iter()
+            .map(|label| {
+                let reactive_keys = get_reactive_key(label);
+                self.all_reactive_keys.extend_from_slice(&reactive_keys);
+                replace_reactive_keys(label, &reactive_keys, r_data)
+            })
+            .collect()
+    }
+
+    fn active_color(&self) -> Color {
+        match self.cloned_attrs.color {
+            WidgetColor::Default => PRIMARY_COLOR,
+            _ => get_color(&self.cloned_attrs.color)
+        }
+    }
+
+    /// Attributes of headers & panels, they are styled by their own class.
+    fn part_attrs(&self, node: Node, class: &Option<String>) -> WidgetAttributes {
+        WidgetAttributes {
+            node,
+            class: class.clone(),
+            overrided_background_color: Some(Color::NONE),
+            overrided_border_color: Some(Color::NONE),
+            ..default()
+        }
+    }
+
+    fn build_header(
+        &mut self,
+        tabs_entity: Entity,
+        index: usize,
+        label: &str,
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) -> Entity {
+        let attrs = self.part_attrs(default_tab_header_node(), &self.header_class);
+        let header_entity = FaBaseContainer::new_with_attributes(&attrs).build(r_data, commands);
+
+        let mut text = FaBaseText::new_with_attributes(label, &self.cloned_attrs);
+        let text_entity = text.build(r_data, commands);
+        commands.entity(text_entity).insert(Pickable::IGNORE);
+
+        commands
+            .entity(header_entity)
+            .insert((IsFamiqTabHeader, TabHeader { tabs: tabs_entity, index }))
+            .observe(on_header_down)
+            .observe(on_pointer_cursor_over)
+            .observe(on_pointer_cursor_out)
+            .add_child(text_entity);
+
+        insert_class_id(commands, header_entity, &None, &self.header_class);
+        self.header_text_entities.push(text_entity);
+        header_entity
+    }
+
+    fn build_panel_slot(&self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        let attrs = self.part_attrs(default_tab_panel_node(), &self.panel_class);
+        let slot_entity = FaBaseContainer::new_with_attributes(&attrs).build(r_data, commands);
+        insert_class_id(commands, slot_entity, &None, &self.panel_class);
+        slot_entity
+    }
+
+    fn build_parts(
+        &mut self,
+        tabs_entity: Entity,
+        labels: &[String],
+        r_data: &HashMap<String, RVal>,
+        commands: &mut Commands
+    ) -> (TabsHeaderEntities, TabsPanels) {
+        let mut headers = Vec::new();
+        let mut panels = Vec::new();
+
+        for (index, label) in labels.iter().enumerate() {
+            headers.push(self.build_header(tabs_entity, index, label, r_data, commands));
+        }
+        for template in self.panels.clone().into_iter() {
+            let slot = self.build_panel_slot(r_data, commands);
+            panels.push(TabPanel { template, slot, built: false });
+        }
+        let list_entity = commands.spawn(default_tab_list_node()).add_children(&headers).id();
+        let slots: Vec<Entity> = panels.iter().map(|panel| panel.slot).collect();
+        commands.entity(tabs_entity).add_child(list_entity).add_children(&slots);
+
+        let tabs_panels = TabsPanels {
+            panels,
+            lazy: self.lazy,
+            aliases: self.aliases.clone()
+        };
+        (TabsHeaderEntities(headers), tabs_panels)
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_tabs_node();
+        self.cloned_attrs.overrided_background_color = Some(Color::NONE);
+        self.cloned_attrs.overrided_border_color = Some(Color::NONE);
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+        self._process_built_in_size_class();
+    }
+}
+
+impl SetupWidget for TabsBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqTabs, MainWidget, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+        let labels = self.resolve_labels(r_data);
+
+        let mut tabs = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let tabs_entity = tabs.build(r_data, commands);
+        let (headers, panels) = self.build_parts(tabs_entity, &labels, r_data, commands);
+
+        commands
+            .entity(tabs_entity)
+            .insert((
+                self.components(),
+                TabsActive::default(),
+                TabsLabels(labels),
+                TabsActiveColor(self.active_color()),
+                headers,
+                panels
+            ));
+
+        insert_class_id(commands, tabs_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, tabs_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                tabs_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Tabs(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        tabs_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+        let labels = self.resolve_labels(r_data);
+
+        let mut tabs = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        tabs.rebuild(r_data, old_entity, world);
+
+        // headers are kept, only their texts are updated
+        for (label, text_entity) in labels.iter().zip(self.header_text_entities.clone()) {
+            let mut text = FaBaseText::new_with_attributes(label, &self.cloned_attrs);
+            text.rebuild(r_data, text_entity, world);
+        }
+        world
+            .entity_mut(old_entity)
+            .insert((TabsLabels(labels), TabsActiveColor(self.active_color())));
+
+        // active header is marked again with the new color
+        if let Some(mut active) = world.get_mut::<TabsActive>(old_entity) {
+            active.set_changed();
+        }
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Tabs(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating tabs. Each panel is a closure building the content of a tab.
+///
+/// ```text
+/// tabs!(
+///     tabs: ["General", "Audio"],
+///     panels: [
+///         || text!(text: "General settings"),
+///         || text!(text: "Audio settings")
+///     ],
+///     lazy: true,
+///     model: "active_tab"
+/// );
+/// ```
+#[macro_export]
+macro_rules! tabs {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let t_builder = &mut TabsBuilder::new(&famiq_builder.get_font_handle());
+        $(
+            $crate::tabs_attributes!(t_builder, $key : $value);
+        )*
+        t_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(tabs [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! tabs_attributes {
+    ($t_builder:ident, tabs: [$( $label:expr ),* $(,)?]) => {{
+        $t_builder.set_labels(vec![$( $label.to_string() ),*]);
+    }};
+    ($t_builder:ident, panels: [$( $panel:expr ),* $(,)?]) => {{
+        $(
+            $t_builder.add_panel($panel);
+        )*
+    }};
+    ($t_builder:ident, lazy: $lazy:expr) => {{
+        $t_builder.lazy = $lazy;
+    }};
+    ($t_builder:ident, header_class: $class:expr) => {{
+        $t_builder.header_class = Some($class.to_string());
+    }};
+    ($t_builder:ident, panel_class: $class:expr) => {{
+        $t_builder.panel_class = Some($class.to_string());
+    }};
+    ($t_builder:ident, model: $model:expr) => {{
+        $t_builder.set_model($model);
+    }};
+    ($t_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($t_builder, $key : $value);
+    }};
+}
+
+pub fn can_run_tabs_systems(tabs_q: Query<&IsFamiqTabs>) -> bool {
+    !tabs_q.is_empty()
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+pub fn default_tabs_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Column,
+        margin: UiRect {
+            top: Val::Px(2.0),
+            right: Val::Px(0.0),
+            left: Val::Px(0.0),
+            bottom: Val::Px(2.0),
+        },
+        ..default()
+    }
+}
+
+pub fn default_tab_list_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        flex_wrap: FlexWrap::Wrap,
+        ..default()
+    }
+}
+
+pub fn default_tab_header_node() -> Node {
+    Node {
+        width: Val::Auto,
+        height: Val::Auto,
+        padding: UiRect {
+            top: Val::Px(6.0),
+            right: Val::Px(12.0),
+            left: Val::Px(12.0),
+            bottom: Val::Px(6.0),
+        },
+        border: UiRect::bottom(Val::Px(2.0)),
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+pub fn default_tab_panel_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Column,
+        padding: UiRect::top(Val::Px(6.0)),
+        display: Display::None,
+        ..default()
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use super::*;
+
+type TabsSelectQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut TabsActive,
+        &'static TabsLabels,
+        Option<&'static WidgetId>,
+        Option<&'static ReactiveModelKey>
+    ),
+    With<IsFamiqTabs>
+>;
+
+/// Activate the tab at `index`. The model keeps its type, `Str` models get the label
+/// while others get the index.
+fn select_tab(
+    entity: Entity,
+    index: usize,
+    labels: &TabsLabels,
+    id: Option<&WidgetId>,
+    model_key: Option<&ReactiveModelKey>,
+    r_data: &mut RData,
+    writer: &mut EventWriter<FaValueChangeEvent>
+) {
+    let mut value = RVal::Num(index as i32);
+
+    if let Some(key) = model_key
+        && let Some(old_value) = get_reactive_value(&r_data.data, &key.0)
+    {
+        if let RVal::Str(_) = old_value {
+            value = RVal::Str(labels.0[index].clone());
+        }
+        let _ = r_data.try_mutate(&key.0, value.clone());
+    }
+    FaValueChangeEvent::send_event(writer, WidgetType::Tabs, entity, id, value);
+}
+
+pub(crate) fn on_header_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    header_q: Query<&TabHeader>,
+    mut tabs_q: TabsSelectQuery,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut r_data: ResMut<RData>,
+    mut writer: EventWriter<FaValueChangeEvent>
+) {
+    if let Ok(header) = header_q.get(trigger.target())
+        && let Ok((entity, mut active, labels, id, model_key)) = tabs_q.get_mut(header.tabs)
+    {
+        if active.0 != header.index {
+            active.0 = header.index;
+            select_tab(entity, header.index, labels, id, model_key, &mut r_data, &mut writer);
+        }
+        // focused tabs can be navigated by keyboard
+        famiq_res.update_all_focus_states(false);
+        famiq_res.update_or_insert_focus_state(entity, true);
+    }
+    trigger.propagate(false);
+}
+
+/// Arrow left & right move to previous & next tab of focused tabs, Home & End move to
+/// the first & last tab.
+pub fn handle_tabs_keyboard(
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mut tabs_q: TabsSelectQuery,
+    famiq_res: Res<FamiqResource>,
+    mut r_data: ResMut<RData>,
+    mut writer: EventWriter<FaValueChangeEvent>
+) {
+    for e in evr_kbd.read() {
+        if e.state == ButtonState::Released {
+            continue;
+        }
+
+        for (entity, mut active, labels, id, model_key) in tabs_q.iter_mut() {
+            let count = labels.0.len();
+
+            if count == 0 || famiq_res.get_widget_focus_state(&entity) != Some(true) {
+                continue;
+            }
+            let index = match e.key_code {
+                KeyCode::ArrowRight => (active.0 + 1) % count,
+                KeyCode::ArrowLeft => (active.0 + count - 1) % count,
+                KeyCode::Home => 0,
+                KeyCode::End => count - 1,
+                _ => continue
+            };
+            if index != active.0 {
+                active.0 = index;
+                select_tab(entity, index, labels, id, model_key, &mut r_data, &mut writer);
+            }
+        }
+    }
+}
+
+pub fn detect_tabs_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut tabs_q: Query<(Ref<IsFamiqTabs>, &mut TabsActive, &TabsLabels, Option<&ReactiveModelKey>)>
+) {
+    let data_changed = reactive_data.is_changed() && !reactive_data.is_added();
+
+    for (tabs, mut active, labels, model_key) in tabs_q.iter_mut() {
+        if !data_changed && !tabs.is_added() {
+            continue;
+        }
+        let Some(key) = model_key else { continue };
+
+        let index = match get_reactive_value(&reactive_data.data, &key.0) {
+            Some(RVal::Num(v)) => usize::try_from(*v).ok(),
+            Some(RVal::Str(v)) => labels.0.iter().position(|label| label == v),
+            _ => None
+        };
+        if let Some(index) = index.filter(|i| *i < labels.0.len()) {
+            active.set_if_neq(TabsActive(index));
+        }
+    }
+}
+
+/// Mark the header of active tab.
+pub fn update_tab_headers(
+    tabs_q: Query<(&TabsActive, &TabsActiveColor, &TabsHeaderEntities), Changed<TabsActive>>,
+    mut header_q: Query<&mut BorderColor, With<IsFamiqTabHeader>>
+) {
+    for (active, active_color, headers) in tabs_q.iter() {
+        for (index, header) in headers.0.iter().enumerate() {
+            if let Ok(mut border_color) = header_q.get_mut(*header) {
+                if index == active.0 {
+                    border_color.0 = active_color.0;
+                } else {
+                    border_color.0 = Color::NONE;
+                }
+            }
+        }
+    }
+}
+
+/// Internal system to show the panel of active tab, building it first if needed.
+pub(crate) fn update_tab_panels(
+    mut fa_query: FaQuery,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut tabs_q: Query<(&TabsActive, &mut TabsPanels), Changed<TabsActive>>,
+    mut node_q: Query<&mut Node>
+) {
+    if tabs_q.is_empty() {
+        return;
+    }
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+
+    for (active, mut tabs_panels) in tabs_q.iter_mut() {
+        let TabsPanels { panels, lazy, aliases } = &mut *tabs_panels;
+
+        for (index, panel) in panels.iter_mut().enumerate() {
+            let is_active = index == active.0;
+
+            if !panel.built && (is_active || !*lazy) {
+                let content = with_template_aliases(aliases, || (panel.template.0)());
+                fa_query.commands.entity(panel.slot).add_child(content);
+                panel.built = true;
+            }
+            if let Ok(mut node) = node_q.get_mut(panel.slot) {
+                node.display = if is_active { Display::Flex } else { Display::None };
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+#![cfg(test)]
+
+use crate::FamiqPlugin;
+use crate::widgets::FamiqResource;
+use crate::{tabs, text};
+use crate::widgets::text::TextBuilder;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::input::InputPlugin;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use super::*;
+
+fn setup_test_lazy_tabs(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_num("tab", 0);
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    tabs!(
+        id: "#test-tabs",
+        tabs: ["General", "Audio"],
+        panels: [
+            || text!(text: "General settings"),
+            || text!(text: "Audio settings")
+        ],
+        lazy: true,
+        model: "tab"
+    );
+}
+
+fn setup_test_tabs_with_label_model(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("tab", "Audio");
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    tabs!(
+        tabs: ["General", "Audio"],
+        panels: [
+            || text!(text: "General settings"),
+            || text!(text: "Audio settings")
+        ],
+        model: "tab"
+    );
+}
+
+/// Built state & display of every panel.
+fn panels(app: &mut App) -> Vec<(bool, Display)> {
+    let panels = app.world_mut()
+        .query::<&TabsPanels>()
+        .single(app.world())
+        .unwrap()
+        .panels
+        .clone();
+
+    panels
+        .iter()
+        .map(|panel| (panel.built, app.world().get::<Node>(panel.slot).unwrap().display))
+        .collect()
+}
+
+#[test]
+fn test_lazy_tabs_build_panel_on_activation() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_lazy_tabs);
+    app.update();
+
+    assert_eq!(vec![(true, Display::Flex), (false, Display::None)], panels(&mut app));
+
+    let _ = app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+        fa_query.mutate_num("tab", 1);
+    });
+    app.update();
+
+    assert_eq!(vec![(true, Display::None), (true, Display::Flex)], panels(&mut app));
+}
+
+#[test]
+fn test_tabs_follow_label_model() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_tabs_with_label_model);
+    app.update();
+    app.update();
+
+    let active = app.world_mut()
+        .query::<&TabsActive>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(1, active.0);
+    assert_eq!(vec![(true, Display::None), (true, Display::Flex)], panels(&mut app));
+}
+
+#[test]
+fn test_tabs_keyboard_navigation() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_lazy_tabs);
+    app.update();
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTabs>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::ArrowLeft,
+        logical_key: Key::ArrowLeft,
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER
+    });
+    app.update();
+
+    let model = app.world().resource::<RData>().data.get("tab").cloned();
+    assert_eq!(Some(RVal::Num(1)), model, "arrow left wraps to the last tab");
+    assert_eq!(vec![(true, Display::None), (true, Display::Flex)], panels(&mut app));
+}
+
+#[test]
+fn test_tabs_selection_sends_reactive_data_changed() {
+    let mut app = create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_tabs_with_label_model);
+    app.update();
+    app.update();
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTabs>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::ArrowRight,
+        logical_key: Key::ArrowRight,
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER
+    });
+    app.update();
+
+    let events = app.world().resource::<Events<ReactiveDataChanged>>();
+    let changes: Vec<&ReactiveDataChanged> = events.iter_current_update_events().collect();
+    assert_eq!(changes, vec![&ReactiveDataChanged {
+        key: "tab".to_string(),
+        old: RVal::Str("Audio".to_string()),
+        new: RVal::Str("General".to_string())
+    }]);
+}
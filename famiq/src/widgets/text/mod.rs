@@ -51,7 +51,7 @@ impl TextBuilder {
         let mut attributes = WidgetAttributes::default();
         attributes.font_handle = Some(font_handle.clone());
         Self {
-            value,
+            value: resolve_template_alias(&value),
             attributes,
             cloned_attrs: WidgetAttributes::default(),
             all_reactive_keys: Vec::new()
@@ -62,7 +62,10 @@ impl TextBuilder {
         self.cloned_attrs = self.attributes.clone();
         let reactive_keys = get_reactive_key(&self.value);
         let parsed_text = replace_reactive_keys(&self.value, &reactive_keys, r_data);
-        self.all_reactive_keys.extend_from_slice(&reactive_keys);
+        if !reactive_keys.is_empty() {
+            self.cloned_attrs.bindings.push(ReactiveBinding::new(BindingTarget::Text, &self.value, None));
+        }
+        self.cloned_attrs.bind_color = true;
         replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
         parsed_text
     }
@@ -144,7 +147,7 @@ impl SetupWidget for TextBuilder {
 /// Macro for creating a text.
 #[macro_export]
 macro_rules! text {
-    ( text: $text:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+    ( @widget text: $text:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let text_builder = &mut TextBuilder::new($text.to_string(), &famiq_builder.get_font_handle());
         $(
@@ -155,6 +158,11 @@ macro_rules! text {
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(text [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
@@ -183,6 +191,57 @@ mod tests {
         text!(text: "Test Text", id: "#test-text");
     }
 
+    fn setup_test_text_with_path(
+        mut famiq_res: ResMut<FamiqResource>,
+        mut fa_query: FaQuery
+    ) {
+        let mut stats = HashMap::new();
+        stats.insert("hp".to_string(), RVal::Num(80));
+        let mut player = HashMap::new();
+        player.insert("stats".to_string(), RVal::Map(stats));
+        fa_query.insert_map("player", player);
+
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        text!(text: "HP: $[player.stats.hp]", id: "#test-text");
+    }
+
+    fn setup_test_text_with_computed_key(
+        mut famiq_res: ResMut<FamiqResource>,
+        mut fa_query: FaQuery
+    ) {
+        fa_query.insert_num("price", 5);
+        fa_query.insert_num("qty", 2);
+        fa_query.insert_computed("total", &["price", "qty"], |data| {
+            RVal::Num(data["price"].as_num() * data["qty"].as_num())
+        }).unwrap();
+
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        text!(text: "Total: $[total]", id: "#test-text");
+    }
+
+    fn setup_test_text_with_expression(
+        mut famiq_res: ResMut<FamiqResource>,
+        mut fa_query: FaQuery
+    ) {
+        fa_query.insert_num("hp", 50);
+        fa_query.insert_num("max_hp", 200);
+
+        FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+        text!(text: "HP: $[hp / max_hp * 100 | fixed(1)]% $[hp < 60 ? 'low' : 'ok']", id: "#test-text");
+    }
+
+    fn run_mutate_hp(mut fa_query: FaQuery) {
+        fa_query.mutate_num("hp", 150);
+    }
+
+    fn run_mutate_qty(mut fa_query: FaQuery) {
+        fa_query.mutate_num("qty", 3);
+    }
+
+    fn run_mutate_player_hp(mut fa_query: FaQuery) {
+        fa_query.mutate_data("player.stats.hp", RVal::Num(50));
+    }
+
     #[test]
     fn test_create_default_text() {
         let mut app = create_test_app();
@@ -200,4 +259,67 @@ mod tests {
         assert_eq!("#test-text".to_string(), id.0);
         assert_eq!("Test Text".to_string(), txt.0);
     }
+
+    #[test]
+    fn test_text_with_reactive_path() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_text_with_path);
+        app.update();
+
+        let txt = app.world_mut()
+            .query::<(&Text, &IsFamiqText)>()
+            .single(app.world());
+        assert_eq!("HP: 80".to_string(), txt.unwrap().0.0);
+
+        app.add_systems(Update, run_mutate_player_hp);
+        app.update();
+
+        let txt = app.world_mut()
+            .query::<(&Text, &IsFamiqText)>()
+            .single(app.world());
+        assert_eq!("HP: 50".to_string(), txt.unwrap().0.0);
+    }
+
+    #[test]
+    fn test_text_with_expression() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_text_with_expression);
+        app.update();
+
+        let txt = app.world_mut()
+            .query::<(&Text, &IsFamiqText)>()
+            .single(app.world());
+        assert_eq!("HP: 25.0% low".to_string(), txt.unwrap().0.0);
+
+        app.add_systems(Update, run_mutate_hp);
+        app.update();
+
+        let txt = app.world_mut()
+            .query::<(&Text, &IsFamiqText)>()
+            .single(app.world());
+        assert_eq!("HP: 75.0% ok".to_string(), txt.unwrap().0.0);
+    }
+
+    #[test]
+    fn test_text_with_computed_key() {
+        let mut app = create_test_app();
+        app.add_plugins(FamiqPlugin::new());
+        app.add_systems(Startup, setup_test_text_with_computed_key);
+        app.update();
+
+        let txt = app.world_mut()
+            .query::<(&Text, &IsFamiqText)>()
+            .single(app.world());
+        assert_eq!("Total: 10".to_string(), txt.unwrap().0.0);
+
+        app.add_systems(Update, run_mutate_qty);
+        app.update();
+
+        let txt = app.world_mut()
+            .query::<(&Text, &IsFamiqText)>()
+            .single(app.world());
+        assert_eq!("Total: 15".to_string(), txt.unwrap().0.0);
+    }
 }
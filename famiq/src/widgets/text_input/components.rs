@@ -27,11 +27,93 @@ pub struct FaTextInputToggleIconEntity(pub Entity);
 #[derive(Component)]
 pub struct FaTextInputEntity(pub Entity);
 
+/// Links a spin button to its step direction, `1.0` or `-1.0`.
+#[derive(Component)]
+pub struct FaTextInputSpinButton(pub f32);
+
 /// Type options for text input widget.
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub enum TextInputType {
     Text,
-    Password
+    Password,
+    Number
+}
+
+/// Range & step of a number input. Value is rounded when its model is an integer.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct NumberInputConfig {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub step: f64,
+    pub integer: bool
+}
+
+impl Default for NumberInputConfig {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+            integer: false
+        }
+    }
+}
+
+impl NumberInputConfig {
+    pub fn clamp(&self, value: f64) -> f64 {
+        let mut value = if self.integer { value.round() } else { value };
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        value
+    }
+
+    /// Format value with as many decimals as `step` has.
+    pub fn format(&self, value: f64) -> String {
+        let decimals = if self.integer {
+            0
+        } else {
+            self.step.to_string().split_once('.').map_or(0, |(_, d)| d.len())
+        };
+        format!("{:.*}", decimals, value)
+    }
+
+    /// Whether character can be typed at `index` of current value.
+    pub fn accepts(&self, c: char, value: &str, index: usize) -> bool {
+        match c {
+            '0'..='9' => true,
+            '-' => index == 0 && !value.starts_with('-') && self.min.is_none_or(|min| min < 0.0),
+            '.' => !self.integer && !value.contains('.'),
+            _ => false
+        }
+    }
+
+    /// Whether `text` can be pasted at `index` of current value, the result must be
+    /// a number that could be typed.
+    pub fn accepts_paste(&self, text: &str, value: &str, index: usize) -> bool {
+        let mut pasted = value.to_string();
+        pasted.insert_str(index, text);
+
+        pasted.parse::<f64>().is_ok_and(|n| n.is_finite())
+            && pasted.char_indices().all(|(i, c)| self.accepts(c, &pasted[..i], i))
+    }
+
+    /// Whether `value` is a number within `min` & `max`, so it can be written to the model.
+    pub fn in_range(&self, value: &str) -> bool {
+        value.trim().parse::<f64>().is_ok_and(|n| n.is_finite() && self.clamp(n) == n)
+    }
+
+    /// Value after one step up (`direction` 1.0) or down (`direction` -1.0).
+    /// Invalid value starts from `min`, or zero.
+    pub fn stepped(&self, value: &str, direction: f32) -> f64 {
+        match value.trim().parse::<f64>() {
+            Ok(current) if current.is_finite() => self.clamp(current + self.step * direction as f64),
+            _ => self.clamp(self.min.unwrap_or(0.0))
+        }
+    }
 }
 
 /// Handles the blinking behavior of the text input cursor.
@@ -22,6 +22,38 @@ pub(crate) fn scroll_left_end(buf_texture_node: &mut Node) {
     buf_texture_node.left = Val::Px(0.0);
 }
 
+/// Load the font into cosmic's font system & return `Attrs` using that font.
+/// Default `Attrs` is returned if the font isn't loaded yet.
+pub(crate) fn load_font_attrs(
+    font_assets: &Assets<Font>,
+    font_system: &mut FontSystem,
+    handle: &Handle<Font>
+) -> Attrs<'static> {
+    let mut attrs = Attrs::new();
+    if let Some(font) = font_assets.get(handle) {
+        let data: Arc<dyn AsRef<[u8]> + Send + Sync> = Arc::new((*font.data).clone());
+
+        let face_ids = font_system
+            .db_mut()
+            .load_font_source(cosmic_text::fontdb::Source::Binary(data));
+
+        // Get face ID for Attrs.
+        // ref: https://github.com/bevyengine/bevy/blob/main/crates/bevy_text/src/pipeline.rs#L170
+        if let Some(&face_id) = face_ids.last() {
+            let face = font_system.db().face(face_id).unwrap().clone();
+            let family_name = face.families[0].0.clone();
+            let family_name: &'static str = Box::leak(family_name.into_boxed_str());
+
+            attrs = Attrs::new()
+                .family(Family::Name(family_name))
+                .weight(Weight(face.weight.0))
+                .stretch(face.stretch)
+                .style(face.style);
+        }
+    }
+    attrs
+}
+
 /// find closest cursor index at pointer location
 pub(crate) fn find_glyph_index_on_mouse_down(
     buffer: &mut Buffer,
@@ -144,6 +176,33 @@ pub(crate) fn update_buffer_text_layout(
     buffer.set_size(font_system, Some(buffer_dim.x), Some(buffer_dim.y));
 }
 
+/// Set buffer text to the value, or placeholder if value is empty, after the value
+/// is replaced. Texture is scrolled so that the end of the text is visible.
+pub(crate) fn reset_buffer_text(
+    editor: &mut Editor,
+    text_edit: &mut FaTextEdit,
+    font_system: &mut FontSystem,
+    attrs: Attrs,
+    buffer_dim: &mut Vec2,
+    texture_node: &mut Node
+) {
+    scroll_left_end(texture_node);
+
+    editor.with_buffer_mut(|buffer| {
+        buffer.set_size(font_system, None, None); // reset
+
+        buffer.set_text(font_system, &text_edit.buffer_text(), attrs, Shaping::Advanced);
+        update_buffer_text_layout(font_system, text_edit, buffer_dim, buffer, texture_node);
+        buffer.shape_until_scroll(font_system, true);
+    });
+    editor.set_selection(Selection::None);
+    editor.set_cursor(Cursor::new(0, text_edit.cursor_index));
+
+    if !text_edit.value.is_empty() {
+        texture_node.left = Val::Px(-text_edit.max_scroll_right());
+    }
+}
+
 fn blend_alpha(color: CosmicColor, pixels: &mut Vec<u8>, pixel_index: usize) {
     // Convert to [0,1]
     let src_a = color.a() as f32 / 255.0;
@@ -263,3 +322,64 @@ pub(crate) fn create_buffer_texture(
     texture.sampler = ImageSampler::linear();
     image_assets.add(texture)
 }
+
+/// Replace the whole value, then reset the buffer text.
+/// Returns `true` if the buffer needs to be redrawn.
+pub(crate) fn replace_input_value(
+    value: &str,
+    text_edit: &mut FaTextEdit,
+    computed: &ComputedNode,
+    cosmic_data: &mut CosmicData,
+    font_system: &mut FontSystem,
+    texture_node: &mut Node
+) -> bool {
+    text_edit.set_value(value);
+    text_edit.widget_computed = *computed;
+    text_edit.set_min_max_cursor_pos();
+
+    let CosmicData { editor, attrs, buffer_dim, .. } = cosmic_data;
+
+    if let (Some(editor), Some(attrs)) = (editor.as_mut(), attrs) {
+        reset_buffer_text(editor, text_edit, font_system, *attrs, buffer_dim, texture_node);
+        return true;
+    }
+    false
+}
+
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    text.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+pub(crate) fn rval_to_number(value: &RVal) -> Option<f64> {
+    match value {
+        RVal::Num(v) => Some(*v as f64),
+        RVal::Num64(v) => Some(*v as f64),
+        RVal::UNum(v) => Some(*v as f64),
+        RVal::FNum(v) => Some(*v as f64),
+        RVal::FNum64(v) => Some(*v),
+        _ => None
+    }
+}
+
+pub(crate) fn is_integer_rval(value: &RVal) -> bool {
+    matches!(value, RVal::Num(_) | RVal::Num64(_) | RVal::UNum(_))
+}
+
+/// Write input's text to its model. Number models are updated
+/// only when the text is a valid number.
+pub(crate) fn update_model_value(model: &mut RVal, text: &str) {
+    if let RVal::Str(v) = model {
+        *v = text.to_owned();
+        return;
+    }
+    let Some(n) = parse_number(text) else { return };
+
+    match model {
+        RVal::Num(v) => *v = n.round() as i32,
+        RVal::Num64(v) => *v = n.round() as i64,
+        RVal::UNum(v) => *v = n.max(0.0).round() as u64,
+        RVal::FNum(v) => *v = n as f32,
+        RVal::FNum64(v) => *v = n,
+        _ => {}
+    }
+}
@@ -16,6 +16,7 @@ pub(crate) use components::*;
 pub(crate) use text_edit::*;
 
 use crate::widgets::container::base_container::*;
+use crate::widgets::text::base_text::*;
 use crate::event_writer::*;
 use crate::plugin::{CursorIcons, CursorType};
 use crate::utils::*;
@@ -30,7 +31,7 @@ use bevy::asset::RenderAssetUsages;
 use bevy::input::ButtonState;
 use bevy::prelude::*;
 use cosmic_text::{
-    Attrs, Metrics, Buffer, Editor, Family, Edit, Shaping, Weight, Cursor, Selection, Action
+    Metrics, Buffer, Editor, Family, Edit, Shaping, Weight, Cursor, Selection, Action
 };
 use std::sync::Arc;
 
@@ -47,9 +48,8 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
 // TODO:
-// 1. make text input reactive
-// 2. clipboard support on wasm
-// 3. on macos, use commands + c or v, instead of ctrls
+// 1. clipboard support on wasm
+// 2. on macos, use commands + c or v, instead of ctrls
 
 #[derive(Event, Debug)]
 pub struct RequestRedrawBuffer {
@@ -67,10 +67,10 @@ impl RequestRedrawBuffer {
 #[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
 pub struct TextInputMaterial {
     #[uniform(1)]
-    color: Vec4,
+    pub(crate) color: Vec4,
     #[texture(2)]
     #[sampler(3)]
-    texture: Handle<Image>,
+    pub(crate) texture: Handle<Image>,
 }
 
 impl UiMaterial for TextInputMaterial {
@@ -80,8 +80,13 @@ impl UiMaterial for TextInputMaterial {
 }
 
 #[set_widget_attributes]
+#[derive(Clone, Debug)]
 pub struct TextInputBuilder {
-    pub placeholder: String
+    pub placeholder: String,
+    pub input_type: TextInputType,
+    pub reveal_toggle: bool,
+    pub number_config: NumberInputConfig,
+    pub all_reactive_keys: Vec<String>
 }
 
 impl TextInputBuilder {
@@ -90,8 +95,88 @@ impl TextInputBuilder {
         attributes.font_handle = Some(font_handle.clone());
         Self {
             attributes,
-            placeholder,
-            cloned_attrs: WidgetAttributes::default()
+            placeholder: resolve_template_alias(&placeholder),
+            input_type: TextInputType::Text,
+            reveal_toggle: false,
+            number_config: NumberInputConfig::default(),
+            cloned_attrs: WidgetAttributes::default(),
+            all_reactive_keys: Vec::new()
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) -> String {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_input_node();
+        if self.has_toggle_icon() || self.input_type == TextInputType::Number {
+            self.cloned_attrs.node.padding.right = Val::Px(TRAILING_BUTTON_SPACE);
+        }
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+
+        let reactive_keys = get_reactive_key(&self.placeholder);
+        let parsed_placeholder = replace_reactive_keys(&self.placeholder, &reactive_keys, r_data);
+        self.all_reactive_keys.extend_from_slice(&reactive_keys);
+        parsed_placeholder
+    }
+
+    pub fn set_password(&mut self, password: bool) {
+        self.input_type = if password { TextInputType::Password } else { TextInputType::Text };
+    }
+
+    pub fn set_number(&mut self) {
+        self.input_type = TextInputType::Number;
+    }
+
+    fn has_toggle_icon(&self) -> bool {
+        self.input_type == TextInputType::Password && self.reveal_toggle
+    }
+
+    fn build_toggle_icon(&self, input_entity: Entity, r_data: &HashMap<String, RVal>, commands: &mut Commands) {
+        let mut icon = FaBaseText::new_with_attributes(toggle_icon_label(true), &self.cloned_attrs);
+        let icon_entity = icon.build(r_data, commands);
+
+        commands
+            .entity(icon_entity)
+            .insert((default_toggle_icon_node(), FaTextInputEntity(input_entity)))
+            .observe(on_toggle_icon_down);
+
+        commands
+            .entity(input_entity)
+            .insert(FaTextInputToggleIconEntity(icon_entity))
+            .add_child(icon_entity);
+    }
+
+    fn build_spin_buttons(&self, input_entity: Entity, r_data: &HashMap<String, RVal>, commands: &mut Commands) {
+        let container_entity = commands.spawn((default_spin_buttons_node(), Pickable::IGNORE)).id();
+
+        for (label, direction) in [("-", -1.0), ("+", 1.0)] {
+            let mut button = FaBaseText::new_with_attributes(label, &self.cloned_attrs);
+            let button_entity = button.build(r_data, commands);
+
+            commands
+                .entity(button_entity)
+                .insert((FaTextInputEntity(input_entity), FaTextInputSpinButton(direction)))
+                .observe(on_spin_button_down);
+
+            commands.entity(container_entity).add_child(button_entity);
+        }
+        commands.entity(input_entity).add_child(container_entity);
+    }
+
+    /// Number config of this input, `integer` follows the model's type.
+    fn number_config(&self, r_data: &HashMap<String, RVal>) -> NumberInputConfig {
+        let integer = self.cloned_attrs.model_key
+            .as_ref()
+            .and_then(|key| get_reactive_value(r_data, key))
+            .is_some_and(helper::is_integer_rval);
+
+        NumberInputConfig { integer, ..self.number_config }
+    }
+
+    fn text_data(&self) -> CosmicTextData {
+        CosmicTextData {
+            handle: self.cloned_attrs.font_handle.clone().unwrap(),
+            size: get_text_size(&self.cloned_attrs.size),
+            color: get_text_color(&self.cloned_attrs.color)
         }
     }
 }
@@ -99,16 +184,11 @@ impl TextInputBuilder {
 impl SetupWidget for TextInputBuilder {
     fn components(&mut self) -> impl Bundle {
         self._process_built_in_size_class();
-        let placeholder_color = get_text_color(&self.cloned_attrs.color);
-        let text_data = CosmicTextData {
-            handle: self.cloned_attrs.font_handle.clone().unwrap(),
-            size: get_text_size(&self.cloned_attrs.size),
-            color: placeholder_color
-        };
+        let text_data = self.text_data();
         (
             IsFamiqTextInput,
             MainWidget,
-            CosmicDataColor::new(placeholder_color),
+            CosmicDataColor::new(text_data.color),
             CosmicData::default(),
             CursorBlinkTimer::default(),
             text_data.clone(),
@@ -119,14 +199,7 @@ impl SetupWidget for TextInputBuilder {
     }
 
     fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
-        let mut all_reactive_keys: Vec<String> = Vec::new();
-        self.cloned_attrs = self.attributes.clone();
-        self.cloned_attrs.node = default_input_node();
-        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut all_reactive_keys);
-
-        let reactive_keys = get_reactive_key(&self.placeholder);
-        let parsed_placeholder = replace_reactive_keys(&self.placeholder, &reactive_keys, r_data);
-        all_reactive_keys.extend_from_slice(&reactive_keys);
+        let parsed_placeholder = self.prepare_attrs(r_data);
 
         let mut input = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
         let input_entity = input.build(r_data, commands);
@@ -134,51 +207,112 @@ impl SetupWidget for TextInputBuilder {
         commands
             .entity(input_entity)
             .insert(self.components())
-            .insert(FaTextEdit::new(&parsed_placeholder))
+            .insert(FaTextEdit {
+                masked: self.input_type == TextInputType::Password,
+                ..FaTextEdit::new(&parsed_placeholder)
+            })
             .observe(on_mouse_up)
             .observe(on_mouse_down)
             .observe(on_mouse_over)
             .observe(on_mouse_out);
 
+        if self.has_toggle_icon() {
+            self.build_toggle_icon(input_entity, r_data, commands);
+        }
+        if self.input_type == TextInputType::Number {
+            commands.entity(input_entity).insert(self.number_config(r_data));
+            self.build_spin_buttons(input_entity, r_data, commands);
+        }
         if self.attributes.has_tooltip {
             build_tooltip_node(&self.cloned_attrs, commands, input_entity);
         }
 
         insert_class_id(commands, input_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
         insert_model(commands, input_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                input_entity,
+                WidgetBuilder {
+                    builder: BuilderType::TextInput(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
         input_entity
     }
 
-    fn rebuild(
-        &mut self,
-        _reactive_data: &HashMap<String, RVal>,
-        _old_entity: Entity,
-        _world: &mut World
-    ) {}
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        let parsed_placeholder = self.prepare_attrs(r_data);
+        let mut input = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        input.rebuild(r_data, old_entity, world);
+
+        // editor is kept, changed text data resets its buffer with the new placeholder.
+        self._process_built_in_size_class();
+        let text_data = self.text_data();
+        if let Some(mut text_edit) = world.get_mut::<FaTextEdit>(old_entity) {
+            text_edit.placeholder = parsed_placeholder;
+        }
+        world
+            .entity_mut(old_entity)
+            .insert((text_data.clone(), DefaultCosmicTextEntity { text_data }));
+
+        if self.input_type == TextInputType::Number {
+            world.entity_mut(old_entity).insert(self.number_config(r_data));
+        }
+
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::TextInput(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
 }
 
 /// Macro for creating a single line text input.
+/// Its `model` is two-way bound, changing the model from code replaces the text.
 /// Clipboard is not supported yet on WASM.
 #[macro_export]
 macro_rules! text_input {
-    ( placeholder: $placeholder:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+    ( @widget placeholder: $placeholder:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
         let famiq_builder = builder_mut();
         let t_builder = &mut TextInputBuilder::new(
             $placeholder.to_string(),
             &famiq_builder.get_font_handle()
         );
         $(
-            $crate::selection_attributes!(t_builder, $key : $value);
+            $crate::text_input_attributes!(t_builder, $key : $value);
         )*
         t_builder.build(
             &famiq_builder.reactive_data.data,
             &mut famiq_builder.ui_root_node.commands()
         )
     }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(text_input [] $( $tokens )*)
+    };
 }
 
 #[macro_export]
 macro_rules! text_input_attributes {
+    ($t_builder:ident, password: $password:expr) => {{
+        $t_builder.set_password($password);
+    }};
+    ($t_builder:ident, reveal_toggle: $reveal_toggle:expr) => {{
+        $t_builder.reveal_toggle = $reveal_toggle;
+    }};
     ($t_builder:ident, model: $model:expr) => {{
         $t_builder.set_model($model);
     }};
@@ -186,3 +320,46 @@ macro_rules! text_input_attributes {
         $crate::common_attributes!($t_builder, $key : $value);
     }};
 }
+
+/// Macro for creating a text input that only accepts numbers.
+/// Its `model` can be a number (`RVal::Num`, `RVal::FNum`, ..) or a string.
+/// Value is clamped to `min` & `max` when the input loses focus.
+#[macro_export]
+macro_rules! number_input {
+    ( @widget placeholder: $placeholder:expr $(, $key:ident : $value:tt )* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let t_builder = &mut TextInputBuilder::new(
+            $placeholder.to_string(),
+            &famiq_builder.get_font_handle()
+        );
+        t_builder.set_number();
+        $(
+            $crate::number_input_attributes!(t_builder, $key : $value);
+        )*
+        t_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(number_input [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! number_input_attributes {
+    ($t_builder:ident, min: $min:expr) => {{
+        $t_builder.number_config.min = Some($min as f64);
+    }};
+    ($t_builder:ident, max: $max:expr) => {{
+        $t_builder.number_config.max = Some($max as f64);
+    }};
+    ($t_builder:ident, step: $step:expr) => {{
+        $t_builder.number_config.step = $step as f64;
+    }};
+    ($t_builder:ident, $key:ident : $value:expr) => {{
+        $crate::text_input_attributes!($t_builder, $key : $value);
+    }};
+}
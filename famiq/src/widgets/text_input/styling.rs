@@ -23,3 +23,29 @@ pub fn default_input_node() -> Node {
         ..default()
     }
 }
+
+/// Space reserved at the right of an input for its toggle icon or spin buttons.
+pub(crate) const TRAILING_BUTTON_SPACE: f32 = 45.0;
+
+pub(crate) fn default_toggle_icon_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        right: Val::Px(5.0),
+        ..default()
+    }
+}
+
+/// Label of toggle icon, `show` while password is masked.
+pub(crate) fn toggle_icon_label(masked: bool) -> &'static str {
+    if masked { "show" } else { "hide" }
+}
+
+pub(crate) fn default_spin_buttons_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        right: Val::Px(5.0),
+        column_gap: Val::Px(10.0),
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
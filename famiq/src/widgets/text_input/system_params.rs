@@ -70,18 +70,72 @@ pub(crate) struct DetectTextStyleChangeParam<'w, 's> {
     pub window: Option<Single<'w, &'static Window>>
 }
 
+pub(crate) type TypingInputQuery<'w, 's> = Query<
+    'w, 's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static FaTextInputBufferTextureEntity,
+        &'static mut CursorBlinkTimer,
+        &'static mut CosmicData,
+        &'static mut FaTextEdit,
+        &'static ReactiveModelKey,
+        Option<&'static NumberInputConfig>
+    ),
+    With<IsFamiqTextInput>
+>;
+
 #[derive(SystemParam)]
 pub(crate) struct TypingParam<'w, 's> {
+    pub input_q: TypingInputQuery<'w, 's>,
+    pub texture_q: Query<
+        'w, 's, &'static mut Node,
+        (With<IsFamiqTextInputBufferTexture>, Without<MainWidget>)
+    >,
+    pub fa_query: FaQuery<'w, 's>,
+    pub evr_kbd: EventReader<'w, 's, KeyboardInput>,
+    pub famiq_res: ResMut<'w, FamiqResource>,
+    pub font_system: ResMut<'w, CosmicFontSystem>,
+    pub request_redraw: EventWriter<'w, RequestRedrawBuffer>,
+    pub keys: Res<'w, ButtonInput<KeyCode>>,
+}
+
+pub(crate) type ModelChangeInputQuery<'w, 's> = Query<
+    'w, 's,
+    (
+        Entity,
+        &'static ComputedNode,
+        Option<&'static FaTextInputBufferTextureEntity>,
+        &'static mut CosmicData,
+        &'static mut FaTextEdit,
+        &'static ReactiveModelKey,
+        Option<&'static NumberInputConfig>
+    ),
+    With<IsFamiqTextInput>
+>;
+
+#[derive(SystemParam)]
+pub(crate) struct ModelChangeParam<'w, 's> {
+    pub input_q: ModelChangeInputQuery<'w, 's>,
+    pub texture_q: Query<
+        'w, 's, &'static mut Node,
+        (With<IsFamiqTextInputBufferTexture>, Without<MainWidget>)
+    >,
+    pub reactive_data: Res<'w, RData>,
+    pub font_system: ResMut<'w, CosmicFontSystem>,
+    pub request_redraw: EventWriter<'w, RequestRedrawBuffer>,
+}
+
+#[derive(SystemParam)]
+pub(crate) struct ToggleIconParam<'w, 's> {
+    pub icon_q: Query<'w, 's, (&'static FaTextInputEntity, &'static mut Text)>,
     pub input_q: Query<
         'w, 's,
         (
-            Entity,
             &'static ComputedNode,
             &'static FaTextInputBufferTextureEntity,
-            &'static mut CursorBlinkTimer,
             &'static mut CosmicData,
-            &'static mut FaTextEdit,
-            &'static ReactiveModelKey
+            &'static mut FaTextEdit
         ),
         With<IsFamiqTextInput>
     >,
@@ -89,10 +143,34 @@ pub(crate) struct TypingParam<'w, 's> {
         'w, 's, &'static mut Node,
         (With<IsFamiqTextInputBufferTexture>, Without<MainWidget>)
     >,
+    pub font_system: ResMut<'w, CosmicFontSystem>,
+    pub request_redraw: EventWriter<'w, RequestRedrawBuffer>,
+}
+
+pub(crate) type NumberInputQuery<'w, 's> = Query<
+    'w, 's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static FaTextInputBufferTextureEntity,
+        &'static NumberInputConfig,
+        &'static mut CosmicData,
+        &'static mut FaTextEdit,
+        Option<&'static ReactiveModelKey>
+    ),
+    With<IsFamiqTextInput>
+>;
+
+// params that used to step & clamp number inputs
+#[derive(SystemParam)]
+pub(crate) struct NumberInputParam<'w, 's> {
+    pub input_q: NumberInputQuery<'w, 's>,
+    pub texture_q: Query<
+        'w, 's, &'static mut Node,
+        (With<IsFamiqTextInputBufferTexture>, Without<MainWidget>)
+    >,
     pub fa_query: FaQuery<'w, 's>,
-    pub evr_kbd: EventReader<'w, 's, KeyboardInput>,
     pub famiq_res: ResMut<'w, FamiqResource>,
     pub font_system: ResMut<'w, CosmicFontSystem>,
     pub request_redraw: EventWriter<'w, RequestRedrawBuffer>,
-    pub keys: Res<'w, ButtonInput<KeyCode>>,
 }
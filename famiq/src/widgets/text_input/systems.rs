@@ -115,6 +115,63 @@ pub(crate) fn on_mouse_up(
     trigger.propagate(false);
 }
 
+/// Show or hide the value of password input. Cursor is moved to the end.
+pub(crate) fn on_toggle_icon_down(mut trigger: Trigger<Pointer<Pressed>>, mut param: ToggleIconParam) {
+    if let Ok((input_entity, mut icon_text)) = param.icon_q.get_mut(trigger.target())
+        && let Ok((computed, texture_entity, mut cosmic_data, mut text_edit)) = param.input_q.get_mut(input_entity.0)
+        && let Ok(mut texture_node) = param.texture_q.get_mut(texture_entity.0)
+    {
+        let value = text_edit.value.clone();
+        text_edit.masked = !text_edit.masked;
+        icon_text.0 = toggle_icon_label(text_edit.masked).to_string();
+
+        if helper::replace_input_value(
+            &value,
+            &mut text_edit,
+            computed,
+            &mut cosmic_data,
+            &mut param.font_system.0,
+            &mut texture_node
+        ) {
+            param.request_redraw.write(RequestRedrawBuffer::new(input_entity.0));
+        }
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_spin_button_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    button_q: Query<(&FaTextInputEntity, &FaTextInputSpinButton)>,
+    mut param: NumberInputParam
+) {
+    if let Ok((input_entity, button)) = button_q.get(trigger.target())
+        && let Ok((entity, computed, texture_entity, config, mut cosmic_data, mut text_edit, model_key)) =
+            param.input_q.get_mut(input_entity.0)
+        && let Ok(mut texture_node) = param.texture_q.get_mut(texture_entity.0)
+    {
+        let value = config.format(config.stepped(&text_edit.value, button.0));
+
+        if helper::replace_input_value(
+            &value,
+            &mut text_edit,
+            computed,
+            &mut cosmic_data,
+            &mut param.font_system.0,
+            &mut texture_node
+        ) {
+            param.request_redraw.write(RequestRedrawBuffer::new(entity));
+        }
+        if let Some(model_key) = model_key
+            && let Some(model) = param.fa_query.get_data_mut(&model_key.0)
+        {
+            helper::update_model_value(model, &value);
+        }
+        param.famiq_res.update_all_focus_states(false);
+        param.famiq_res.update_or_insert_focus_state(entity, true);
+    }
+    trigger.propagate(false);
+}
+
 pub fn handle_cursor_blink_system(
     mut input_q: Query<
         (Entity, &mut CursorBlinkTimer, &mut CosmicDataColor),
@@ -260,11 +317,7 @@ pub(crate) fn detect_text_input_text_style_change(mut param: DetectTextStyleChan
                     }
                     buffer.set_metrics(font_system, new_metrics);
 
-                    if text_edit.value.is_empty() {
-                        buffer.set_text(font_system, &text_edit.placeholder, attrs.unwrap(), Shaping::Advanced);
-                    } else {
-                        buffer.set_text(font_system, &text_edit.value, attrs.unwrap(), Shaping::Advanced);
-                    }
+                    buffer.set_text(font_system, &text_edit.buffer_text(), attrs.unwrap(), Shaping::Advanced);
                     if let Some(layout) = buffer.line_layout(font_system, 0) {
                         text_edit.text_width = layout[0].w;
                         text_edit.text_height = new_metrics.line_height;
@@ -284,6 +337,118 @@ pub(crate) fn detect_text_input_text_style_change(mut param: DetectTextStyleChan
     });
 }
 
+/// Internal system to apply model changes made outside of typing (eg. from code)
+/// to text inputs. Newly spawned text inputs load their model value as well.
+pub(crate) fn detect_text_input_reactive_model_change(mut param: ModelChangeParam) {
+    let data_changed = param.reactive_data.is_changed();
+
+    for (
+        entity,
+        computed,
+        texture_entity,
+        mut cosmic_data,
+        mut text_edit,
+        model_key,
+        number_config
+    ) in param.input_q.iter_mut() {
+        if !data_changed && !text_edit.is_added() {
+            continue;
+        }
+        let Some(model) = get_reactive_value(&param.reactive_data.data, &model_key.0) else { continue };
+
+        // other keys changed, keep text that isn't written to the model (out of range, "-", empty)
+        if text_edit.synced_model.as_ref() == Some(model) {
+            continue;
+        }
+        text_edit.synced_model = Some(model.clone());
+
+        let value = match (model, number_config) {
+            (RVal::Str(value), _) => value.clone(),
+            (value, Some(config)) => {
+                let Some(n) = helper::rval_to_number(value) else { continue };
+
+                if helper::parse_number(&text_edit.value) == Some(n) {
+                    continue;
+                }
+                config.format(n)
+            }
+            // numeric model of a text input, eg. a field of a bound resource
+            (value, None) => {
+                let Some(n) = helper::rval_to_number(value) else { continue };
+
+                if helper::parse_number(&text_edit.value) == Some(n) {
+                    continue;
+                }
+                value.to_string()
+            }
+        };
+        if value == text_edit.value {
+            continue;
+        }
+        let Some(texture_entity) = texture_entity else {
+            text_edit.set_value(&value);
+            continue;
+        };
+        let Ok(mut texture_node) = param.texture_q.get_mut(texture_entity.0) else { continue };
+
+        if helper::replace_input_value(
+            &value,
+            &mut text_edit,
+            computed,
+            &mut cosmic_data,
+            &mut param.font_system.0,
+            &mut texture_node
+        ) {
+            param.request_redraw.write(RequestRedrawBuffer::new(entity));
+        }
+    }
+}
+
+/// Internal system to clamp number inputs to their range, and clear invalid text,
+/// once they lose focus.
+pub(crate) fn clamp_number_input_on_blur(mut param: NumberInputParam) {
+    if !param.famiq_res.is_changed() {
+        return;
+    }
+    for (
+        entity,
+        computed,
+        texture_entity,
+        config,
+        mut cosmic_data,
+        mut text_edit,
+        model_key
+    ) in param.input_q.iter_mut() {
+        if param.famiq_res.get_widget_focus_state(&entity) == Some(true) || text_edit.value.is_empty() {
+            continue;
+        }
+        let value = helper::parse_number(&text_edit.value)
+            .map(|n| config.format(config.clamp(n)))
+            .unwrap_or_default();
+
+        if value == text_edit.value {
+            continue;
+        }
+        let Ok(mut texture_node) = param.texture_q.get_mut(texture_entity.0) else { continue };
+
+        if helper::replace_input_value(
+            &value,
+            &mut text_edit,
+            computed,
+            &mut cosmic_data,
+            &mut param.font_system.0,
+            &mut texture_node
+        ) {
+            param.request_redraw.write(RequestRedrawBuffer::new(entity));
+        }
+        if let Some(model_key) = model_key
+            && let Some(model) = param.fa_query.get_data_mut(&model_key.0)
+        {
+            helper::update_model_value(model, &value);
+        }
+    }
+}
+
 pub(crate) fn detect_new_text_input_widget_system(
     mut input_q: Query<
         (
@@ -304,29 +469,7 @@ pub(crate) fn detect_new_text_input_widget_system(
     window: Option<Single<&Window>>
 ) {
     input_q.iter_mut().for_each(|(entity, text_data, cosmic_color, mut text_edit, mut cosmic_data)| {
-        let mut attrs = Attrs::new();
-        if let Some(font) = font_assets.get(&text_data.handle) {
-            let data: Arc<dyn AsRef<[u8]> + Send + Sync> = Arc::new((*font.data).clone());
-
-            let face_ids = font_system.0
-                .db_mut()
-                .load_font_source(cosmic_text::fontdb::Source::Binary(data));
-
-            // Get face ID for Attrs.
-            // ref: https://github.com/bevyengine/bevy/blob/main/crates/bevy_text/src/pipeline.rs#L170
-            if let Some(&face_id) = face_ids.last() {
-                let face = font_system.0.db().face(face_id).unwrap().clone();
-                let family_name = face.families[0].0.clone();
-                let family_name: &'static str = Box::leak(family_name.into_boxed_str());
-
-                attrs = Attrs::new()
-                    .family(Family::Name(family_name))
-                    .weight(Weight(face.weight.0))
-                    .stretch(face.stretch)
-                    .style(face.style);
-            }
-        }
-
+        let attrs = helper::load_font_attrs(&font_assets, &mut font_system.0, &text_data.handle);
         let metrics = Metrics::relative(text_data.size, 1.2);
 
         #[cfg(not(target_os = "macos"))] {
@@ -337,7 +480,7 @@ pub(crate) fn detect_new_text_input_widget_system(
 
         let mut buffer = Buffer::new(&mut font_system.0, metrics);
         let mut buffer = buffer.borrow_with(&mut font_system.0);
-        buffer.set_text(&text_edit.placeholder, attrs, Shaping::Advanced);
+        buffer.set_text(&text_edit.buffer_text(), attrs, Shaping::Advanced);
 
         if let Some(layout) = buffer.line_layout(0) {
             text_edit.text_width = layout[0].w;
@@ -546,7 +689,8 @@ pub(crate) fn handle_text_input_on_typing(
             mut blink_timer,
             mut cosmic_data,
             mut text_edit,
-            model_key
+            model_key,
+            number_config
         ) in param.input_q.iter_mut() {
             let Some(focused) = param.famiq_res.get_widget_focus_state(&entity) else { continue };
 
@@ -558,6 +702,32 @@ pub(crate) fn handle_text_input_on_typing(
             text_edit.widget_computed = computed.clone();
             text_edit.set_min_max_cursor_pos();
 
+            if let Some(config) = number_config {
+                let direction = match &e.logical_key {
+                    Key::ArrowUp => Some(1.0),
+                    Key::ArrowDown => Some(-1.0),
+                    _ => None
+                };
+                if let Some(direction) = direction {
+                    let value = config.format(config.stepped(&text_edit.value, direction));
+
+                    if helper::replace_input_value(
+                        &value,
+                        &mut text_edit,
+                        computed,
+                        &mut cosmic_data,
+                        &mut param.font_system.0,
+                        &mut texture_node
+                    ) {
+                        param.request_redraw.write(RequestRedrawBuffer::new(entity));
+                    }
+                    if let Some(model) = param.fa_query.get_data_mut(&model_key.0) {
+                        helper::update_model_value(model, &value);
+                    }
+                    continue;
+                }
+            }
+
             let CosmicData { buffer_dim, attrs, editor, .. } = &mut *cosmic_data;
 
             if let Some(mut editor) = editor.as_mut() {
@@ -578,8 +748,8 @@ pub(crate) fn handle_text_input_on_typing(
                     if text_edit.is_ctrl_c_pressed(&param.keys, e.key_code) {
                         if let Some(copied_text) = text_edit.copy_text() {
                             param.famiq_res.copied_text = copied_text;
-                            continue;
                         }
+                        continue;
                     }
                     else if text_edit.is_ctrl_v_pressed(&param.keys, e.key_code) {
                         // TODO: proper scroll after pasted long text
@@ -592,18 +762,25 @@ pub(crate) fn handle_text_input_on_typing(
                             }
                         }
                         if let Some(text) = copied_text {
-                            if text.is_empty() {
+                            if text.is_empty() || number_config.is_some_and(|config| {
+                                let (value, index) = text_edit.value_without_selection();
+                                !config.accepts_paste(&text, &value, index)
+                            }) {
                                 continue;
                             }
                             helper::clear_buffer_before_insert(&mut editor, &mut text_edit, font_system, attrs.unwrap());
 
+                            if !text_edit.selected_text.is_empty() {
+                                editor.delete_selection();
+                                text_edit.remove_selected_text();
+                            }
                             let index = text_edit.cursor_index;
                             text_edit.value.insert_str(index, &text);
                             text_edit.cursor_index += text.len();
 
                             editor.with_buffer_mut(|buffer| {
                                 buffer.set_size(font_system, None, None); // reset
-                                buffer.set_text(font_system, &text_edit.value, attrs.unwrap(), Shaping::Advanced);
+                                buffer.set_text(font_system, &text_edit.display_value(), attrs.unwrap(), Shaping::Advanced);
                                 helper::update_buffer_text_layout(
                                     font_system,
                                     &mut text_edit,
@@ -620,10 +797,10 @@ pub(crate) fn handle_text_input_on_typing(
                 #[cfg(target_arch = "wasm32")]
                 {
                     if text_edit.is_ctrl_c_pressed(&param.keys, e.key_code) {
-                        if !text_edit.selected_text.trim().is_empty() {
+                        if !text_edit.masked && !text_edit.selected_text.trim().is_empty() {
                             write_clipboard_wasm(&text_edit.selected_text);
-                            continue;
                         }
+                        continue;
                     }
                     else if text_edit.is_ctrl_v_pressed(&param.keys, e.key_code) {
                         let tx = wasm_channel.as_ref().unwrap().tx.clone();
@@ -643,8 +820,10 @@ pub(crate) fn handle_text_input_on_typing(
 
                 if !skip_typing {
                     match &e.logical_key {
-                        Key::Character(key_input) => text_edit.insert_char(&mut editor, font_system, key_input, *attrs),
-                        Key::Space => text_edit.insert_space(&mut editor, font_system, *attrs),
+                        Key::Character(key_input) if number_config.is_none_or(|config| {
+                            key_input.chars().all(|c| config.accepts(c, &text_edit.value, text_edit.cursor_index))
+                        }) => text_edit.insert_char(editor, font_system, key_input, *attrs),
+                        Key::Space if number_config.is_none() => text_edit.insert_space(editor, font_system, *attrs),
                         Key::Backspace => text_edit.backspace(&mut editor, font_system),
                         Key::Escape => text_edit.escape(&mut editor, font_system),
                         Key::ArrowLeft => text_edit.arrow_left(&mut editor),
@@ -677,13 +856,11 @@ pub(crate) fn handle_text_input_on_typing(
                     NeedScroll::Left => helper::scroll_left(&mut texture_node, &text_edit),
                     _ => {}
                 }
-                if let Some(value) = param.fa_query.get_data_mut(&model_key.0) {
-                    match value {
-                        RVal::Str(v) => {
-                            *v = text_edit.value.to_owned();
-                        }
-                        _ => {}
-                    }
+                // out of range numbers are written once clamped on blur
+                if number_config.is_none_or(|config| config.in_range(&text_edit.value))
+                    && let Some(value) = param.fa_query.get_data_mut(&model_key.0)
+                {
+                    helper::update_model_value(value, &text_edit.value);
                 }
                 param.request_redraw.write(RequestRedrawBuffer::new(entity));
                 blink_timer.can_blink = false;
@@ -703,7 +880,8 @@ pub fn on_wasm_paste(
             &mut CursorBlinkTimer,
             &mut CosmicData,
             &mut FaTextEdit,
-            &ReactiveModelKey
+            &ReactiveModelKey,
+            Option<&NumberInputConfig>
         ),
         With<IsFamiqTextInput>
     >,
@@ -727,13 +905,20 @@ pub fn on_wasm_paste(
                 mut blink_timer,
                 mut cosmic_data,
                 mut text_edit,
-                model_key
+                model_key,
+                number_config
             )) = input_q.get_mut(inlet.entity) {
                 let Some(focused) = famiq_res.get_widget_focus_state(&entity) else { return };
 
                 if !focused {
                     return;
                 }
+                if number_config.is_some_and(|config| {
+                    let (value, index) = text_edit.value_without_selection();
+                    !config.accepts_paste(&inlet.text, &value, index)
+                }) {
+                    return;
+                }
                 let mut texture_node = texture_q.get_mut(texture_entity.0).unwrap();
 
                 let CosmicData { buffer_dim, attrs, editor, .. } = &mut *cosmic_data;
@@ -741,13 +926,18 @@ pub fn on_wasm_paste(
                 if let Some(mut editor) = editor.as_mut() {
                     let font_system = &mut font_system.0;
                     helper::clear_buffer_before_insert(&mut editor, &mut text_edit, font_system, attrs.unwrap());
+
+                    if !text_edit.selected_text.is_empty() {
+                        editor.delete_selection();
+                        text_edit.remove_selected_text();
+                    }
                     let index = text_edit.cursor_index;
                     text_edit.value.insert_str(index, &inlet.text);
                     text_edit.cursor_index += inlet.text.len();
 
                     editor.with_buffer_mut(|buffer| {
                         buffer.set_size(font_system, None, None); // reset
-                        buffer.set_text(font_system, &text_edit.value, attrs.unwrap(), Shaping::Advanced);
+                        buffer.set_text(font_system, &text_edit.display_value(), attrs.unwrap(), Shaping::Advanced);
                         helper::update_buffer_text_layout(
                             font_system,
                             &mut text_edit,
@@ -758,11 +948,10 @@ pub fn on_wasm_paste(
                     });
                     editor.set_cursor(Cursor::new(0, text_edit.cursor_index));
 
-                    if let Some(value) = fa_query.get_data_mut(&model_key.0) {
-                        match value {
-                            RVal::Str(v) =>  *v = text_edit.value.to_owned(),
-                            _ => {}
-                        }
+                    if number_config.is_none_or(|config| config.in_range(&text_edit.value))
+                        && let Some(value) = fa_query.get_data_mut(&model_key.0)
+                    {
+                        helper::update_model_value(value, &text_edit.value);
                     }
                     request_redraw.write(RequestRedrawBuffer::new(entity));
                     blink_timer.can_blink = false;
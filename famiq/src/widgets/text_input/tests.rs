@@ -5,7 +5,9 @@ use crate::widgets::color::PRIMARY_COLOR;
 use crate::widgets::WidgetClasses;
 use crate::utils;
 use crate::text_input;
+use crate::number_input;
 use bevy::input::InputPlugin;
+use bevy::ecs::system::RunSystemOnce;
 use super::*;
 
 fn setup_test_default_input(
@@ -24,6 +26,38 @@ fn setup_test_input_with_built_in_class(
     text_input!(placeholder: "First name", class: "primary");
 }
 
+fn setup_test_input_with_model(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("name", "Kim");
+    fa_query.insert_str("field", "name");
+
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    text_input!(placeholder: "Enter $[field]", model: "name");
+}
+
+fn run_clear_name(mut fa_query: FaQuery) {
+    fa_query.mutate_str("name", "");
+}
+
+fn run_load_name(mut fa_query: FaQuery) {
+    fa_query.mutate_str("name", "Kimhong");
+}
+
+fn run_change_field(mut fa_query: FaQuery) {
+    fa_query.mutate_str("field", "nickname");
+}
+
+fn text_edit(app: &mut App) -> (String, String, usize) {
+    let text_edit = app.world_mut()
+        .query::<&FaTextEdit>()
+        .single(app.world())
+        .unwrap();
+
+    (text_edit.value.clone(), text_edit.placeholder.clone(), text_edit.cursor_index)
+}
+
 #[test]
 fn test_create_default_input() {
     let mut app = utils::create_test_app();
@@ -58,3 +92,290 @@ fn test_create_input_with_built_in_class() {
     let input_bg = input_q.as_ref().unwrap().1;
     assert_eq!(BackgroundColor(PRIMARY_COLOR), *input_bg);
 }
+
+#[test]
+fn test_input_loads_model_value() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_input_with_model);
+    app.update();
+
+    let (value, placeholder, cursor_index) = text_edit(&mut app);
+    assert_eq!("Kim", value);
+    assert_eq!("Enter name", placeholder);
+    assert_eq!(3, cursor_index);
+}
+
+#[test]
+fn test_input_follows_model_changed_from_code() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_input_with_model);
+    app.update();
+
+    app.add_systems(Update, run_load_name);
+    app.update();
+    app.update();
+
+    let (value, _, cursor_index) = text_edit(&mut app);
+    assert_eq!("Kimhong", value);
+    assert_eq!(7, cursor_index);
+}
+
+#[test]
+fn test_input_cleared_from_code() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_input_with_model);
+    app.update();
+
+    app.add_systems(Update, run_clear_name);
+    app.update();
+    app.update();
+
+    let (value, _, cursor_index) = text_edit(&mut app);
+    assert_eq!("", value);
+    assert_eq!(0, cursor_index);
+}
+
+#[test]
+fn test_input_with_reactive_placeholder() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_input_with_model);
+    app.update();
+
+    app.add_systems(Update, run_change_field);
+    app.update();
+    app.update();
+
+    let (value, placeholder, _) = text_edit(&mut app);
+    assert_eq!("Kim", value);
+    assert_eq!("Enter nickname", placeholder);
+}
+
+fn setup_test_password_input(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("password", "secret");
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    text_input!(placeholder: "Password", password: true, reveal_toggle: true, model: "password");
+}
+
+fn buffer_text(app: &mut App) -> String {
+    let cosmic_data = app.world_mut()
+        .query::<&CosmicData>()
+        .single(app.world())
+        .unwrap();
+
+    cosmic_data.editor.as_ref().unwrap().with_buffer(|buffer| buffer.lines[0].text().to_string())
+}
+
+#[test]
+fn test_password_input_masks_value() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_password_input);
+    app.update();
+    app.update();
+
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("secret", value);
+    assert_eq!("******", buffer_text(&mut app));
+
+    let icon = app.world_mut()
+        .query::<&FaTextInputToggleIconEntity>()
+        .single(app.world())
+        .unwrap()
+        .0;
+    assert_eq!("show", app.world().get::<Text>(icon).unwrap().0);
+}
+
+#[test]
+fn test_password_input_typing_keeps_real_value() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_password_input);
+    app.update();
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTextInput>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::KeyA,
+        logical_key: Key::Character("a".into()),
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER
+    });
+    app.update();
+
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("secreta", value);
+    assert_eq!("*******", buffer_text(&mut app));
+
+    let model = app.world().resource::<RData>().data.get("password").cloned();
+    assert_eq!(Some(RVal::Str("secreta".to_string())), model);
+}
+
+fn setup_test_number_input(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_num("age", 5);
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    number_input!(placeholder: "Age", min: 0, max: 10, model: "age");
+}
+
+#[test]
+fn test_number_input_rejects_invalid_chars_and_steps() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_number_input);
+    app.update();
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTextInput>>()
+        .single(app.world())
+        .unwrap();
+    let config = app.world().get::<NumberInputConfig>(entity).unwrap();
+    assert!(config.integer);
+
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    press_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+    press_key(&mut app, KeyCode::Period, Key::Character(".".into()));
+
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("5", value);
+
+    press_key(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("6", value);
+
+    let model = app.world().resource::<RData>().data.get("age").cloned();
+    assert_eq!(Some(RVal::Num(6)), model);
+}
+
+#[test]
+fn test_number_input_clamps_on_blur() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_number_input);
+    app.update();
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTextInput>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    press_key(&mut app, KeyCode::Digit9, Key::Character("9".into()));
+
+    // out of range value isn't written until it's clamped
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("59", value);
+    let model = app.world().resource::<RData>().data.get("age").cloned();
+    assert_eq!(Some(RVal::Num(5)), model);
+
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, false);
+    app.update();
+
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("10", value);
+
+    let model = app.world().resource::<RData>().data.get("age").cloned();
+    assert_eq!(Some(RVal::Num(10)), model);
+}
+
+fn run_tick(mut fa_query: FaQuery) {
+    match fa_query.get::<i32>("tick") {
+        Some(tick) => fa_query.mutate_num("tick", tick + 1),
+        None => fa_query.insert_num("tick", 0)
+    }
+}
+
+#[test]
+fn test_number_input_keeps_draft_on_unrelated_change() {
+    let mut app = utils::create_test_app();
+    app.add_plugins(FamiqPlugin::new());
+    app.add_plugins(InputPlugin);
+    app.add_systems(Startup, setup_test_number_input);
+    app.add_systems(Update, run_tick);
+    app.update();
+
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTextInput>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    press_key(&mut app, KeyCode::Digit9, Key::Character("9".into()));
+    app.update();
+
+    // "tick" changes every frame, out of range text isn't replaced by the model
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("59", value);
+
+    app.world_mut().run_system_once(|mut fa_query: FaQuery| fa_query.mutate_num("age", 3)).unwrap();
+    app.update();
+
+    let (value, _, _) = text_edit(&mut app);
+    assert_eq!("3", value);
+}
+
+#[test]
+fn test_number_input_accepts_paste_and_range() {
+    let config = NumberInputConfig {
+        min: Some(0.0),
+        max: Some(10.0),
+        step: 1.0,
+        integer: true
+    };
+    assert!(config.accepts_paste("3", "5", 1));
+    assert!(!config.accepts_paste("-3", "5", 1));
+    assert!(!config.accepts_paste("-3", "", 0));
+    assert!(!config.accepts_paste("1.5", "", 0));
+    assert!(!config.accepts_paste("", "", 0));
+
+    // pasting "5" over selected "12" of "120"
+    let text_edit = FaTextEdit {
+        value: "120".to_string(),
+        selected_text: "12".to_string(),
+        selection_start_index: Some(2),
+        selection_end_index: Some(0),
+        cursor_index: 0,
+        ..default()
+    };
+    let (value, index) = text_edit.value_without_selection();
+    assert_eq!(("0".to_string(), 0), (value.clone(), index));
+    assert!(config.accepts_paste("5", &value, index));
+
+    // "2.5" over the whole selected "1.5" isn't a second decimal point
+    let decimal_config = NumberInputConfig { integer: false, step: 0.5, ..config };
+    let text_edit = FaTextEdit {
+        value: "1.5".to_string(),
+        selected_text: "1.5".to_string(),
+        selection_start_index: Some(0),
+        selection_end_index: Some(3),
+        cursor_index: 3,
+        ..default()
+    };
+    let (value, index) = text_edit.value_without_selection();
+    assert!(decimal_config.accepts_paste("2.5", &value, index));
+    assert!(!decimal_config.accepts_paste("2.5", &text_edit.value, text_edit.cursor_index));
+
+    assert!(config.in_range("10"));
+    assert!(!config.in_range("53"));
+    assert!(!config.in_range("-"));
+}
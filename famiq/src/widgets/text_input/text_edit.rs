@@ -104,7 +104,10 @@ pub struct FaTextEdit {
     pub selection_start_index: Option<usize>,
     pub selection_end_index: Option<usize>,
     pub widget_computed: ComputedNode,
-    pub buffer_empty: bool
+    pub buffer_empty: bool,
+    pub masked: bool, // password input, value is drawn as `*`
+    /// model value the input was last synced with, text is only replaced when the model changes
+    pub synced_model: Option<RVal>
 }
 
 impl Default for FaTextEdit {
@@ -124,7 +127,9 @@ impl Default for FaTextEdit {
             selection_start_index: None,
             selection_end_index: None,
             widget_computed: ComputedNode::default(),
-            buffer_empty: false
+            buffer_empty: false,
+            masked: false,
+            synced_model: None
         }
     }
 }
@@ -137,6 +142,35 @@ impl FaTextEdit {
         }
     }
 
+    /// Value to be drawn into buffer, masked if it's a password input.
+    pub fn display_value(&self) -> String {
+        if self.masked {
+            mask_string(&self.value)
+        } else {
+            self.value.clone()
+        }
+    }
+
+    /// Text to be drawn into buffer, placeholder is drawn when value is empty.
+    pub fn buffer_text(&self) -> String {
+        if self.value.is_empty() {
+            self.placeholder.clone()
+        } else {
+            self.display_value()
+        }
+    }
+
+    /// Replace the whole value, eg. when its model gets changed from code.
+    /// Cursor is moved to the end and selection is cleared.
+    pub fn set_value(&mut self, value: &str) {
+        self.value = value.to_string();
+        self.cursor_index = self.value.len();
+        self.move_direction = MoveDirection::Right;
+        self.need_scroll = NeedScroll::None;
+        self.buffer_empty = false;
+        self.clear_selection();
+    }
+
     pub fn move_cursor_left(&mut self) {
         if self.cursor_index > 0 {
             self.cursor_index -= 1;
@@ -187,6 +221,19 @@ impl FaTextEdit {
         }
     }
 
+    /// Value once the selected text is removed, with the index typing or pasting inserts at.
+    pub fn value_without_selection(&self) -> (String, usize) {
+        match (self.selection_start_index, self.selection_end_index) {
+            (Some(start), Some(end)) if start != end => {
+                let (start, end) = (start.min(end), start.max(end));
+                let mut value = self.value.clone();
+                value.drain(start..end);
+                (value, start)
+            }
+            _ => (self.value.clone(), self.cursor_index)
+        }
+    }
+
     pub fn widget_scale(&self) -> f32 {
         self.widget_computed.inverse_scale_factor()
     }
@@ -230,7 +277,7 @@ impl FaTextEdit {
 
     pub fn set_min_max_cursor_pos(&mut self) {
         self.min_cursor_pos = 0.0;
-        self.max_cursor_pos = self.widget_width();
+        self.max_cursor_pos = self.widget_width() - self.widget_padding_right();
     }
 
     /// calculate cursor position for given cursor index
@@ -292,11 +339,11 @@ impl FaTextEdit {
     }
 
     /// Copy text in `text_input`.
-    /// - return None if text is empty.
+    /// - return None if text is empty or it's a password input.
     pub fn copy_text(&mut self) -> Option<String> {
         #[cfg(not(target_arch = "wasm32"))]
         {
-            if !self.selected_text.trim().is_empty() {
+            if !self.masked && !self.selected_text.trim().is_empty() {
                 let mut ctx = Clipboard::new().unwrap();
                 #[cfg(target_os = "linux")]
                 ctx.set().clipboard(LinuxClipboardKind::Clipboard).text(self.selected_text.clone()).unwrap();
@@ -340,7 +387,7 @@ impl FaTextEdit {
 
         let b = key_input.as_bytes();
         for c in b {
-            let c: char = (*c).into();
+            let c: char = if self.masked { '*' } else { (*c).into() };
             editor.action(font_system, Action::Insert(c));
         }
     }
@@ -358,7 +405,7 @@ impl FaTextEdit {
             self.remove_selected_text();
         }
         self.insert(&SmolStr::new(" "));
-        editor.action(font_system, Action::Insert(' '));
+        editor.action(font_system, Action::Insert(if self.masked { '*' } else { ' ' }));
     }
 
     pub fn backspace(&mut self, editor: &mut Editor, font_system: &mut FontSystem) {
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+/// Marker component for identifying a textarea widget.
+#[derive(Component)]
+pub struct IsFamiqTextArea;
+
+/// Number of visible rows & whether the textarea grows with its content.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct TextAreaConfig {
+    pub rows: usize,
+    pub auto_grow: bool
+}
+
+impl Default for TextAreaConfig {
+    fn default() -> Self {
+        Self {
+            rows: 3,
+            auto_grow: false
+        }
+    }
+}
+
+/// Value & placeholder of a textarea. Lines in `value` are separated by `\n`.
+#[derive(Component, Debug, Default)]
+pub struct TextAreaText {
+    pub value: String,
+    pub placeholder: String
+}
+
+impl TextAreaText {
+    pub fn new(placeholder: &str) -> Self {
+        Self {
+            value: String::new(),
+            placeholder: placeholder.to_string()
+        }
+    }
+
+    /// Text drawn into the buffer, placeholder is drawn when value is empty.
+    pub fn display_text(&self) -> &str {
+        if self.value.is_empty() {
+            &self.placeholder
+        } else {
+            &self.value
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+use cosmic_text::{Attrs, FontSystem};
+use super::*;
+
+pub fn default_textarea_node() -> Node {
+    Node {
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Start,
+        align_items: AlignItems::Start,
+        padding: UiRect {
+            left: Val::Px(5.0),
+            right: Val::Px(5.0),
+            top: Val::Px(4.0),
+            bottom: Val::Px(4.0),
+        },
+        margin: UiRect {
+            top: Val::Px(2.0),
+            right: Val::Px(0.0),
+            left: Val::Px(0.0),
+            bottom: Val::Px(2.0),
+        },
+        height: Val::Auto,
+        width: Val::Percent(100.0),
+        border: UiRect::all(Val::Px(2.0)),
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+/// Text of every buffer line, joined by `\n`.
+pub(crate) fn buffer_text(editor: &Editor) -> String {
+    editor.with_buffer(|buffer| {
+        buffer.lines
+            .iter()
+            .map(|line| line.text())
+            .collect::<Vec<&str>>()
+            .join("\n")
+    })
+}
+
+/// Width that text can be drawn into, in logical pixels.
+pub(crate) fn content_width(computed: &ComputedNode) -> f32 {
+    let padding = computed.padding();
+    let border = computed.border();
+    let width = computed.size().x - padding.left - padding.right - border.left - border.right;
+    width * computed.inverse_scale_factor()
+}
+
+/// Set buffer text to the value, or placeholder if value is empty.
+/// Cursor is moved to the end of the value & selection is cleared.
+pub(crate) fn reset_buffer_text(
+    editor: &mut Editor,
+    font_system: &mut FontSystem,
+    attrs: Attrs,
+    text: &TextAreaText
+) {
+    editor.with_buffer_mut(|buffer| {
+        buffer.set_text(font_system, text.display_text(), attrs, Shaping::Advanced);
+    });
+    editor.set_selection(Selection::None);
+
+    if text.value.is_empty() {
+        editor.set_cursor(Cursor::new(0, 0));
+    } else {
+        editor.set_cursor(buffer_end_cursor(editor));
+    }
+}
+
+/// Wrap buffer at the given width & return its new dimension.
+///
+/// Buffer is `rows` lines tall and scrolls vertically, or grows to fit every
+/// wrapped line (never less than `rows`) if `auto_grow` is set.
+pub(crate) fn layout_buffer(
+    editor: &mut Editor,
+    font_system: &mut FontSystem,
+    config: &TextAreaConfig,
+    width: f32
+) -> Vec2 {
+    let width = width.max(1.0);
+    let cursor = editor.cursor();
+    let mut height = 0.0;
+
+    editor.with_buffer_mut(|buffer| {
+        let mut rows = config.rows.max(1);
+        buffer.set_size(font_system, Some(width), None);
+
+        if config.auto_grow {
+            let wrapped_lines: usize = (0..buffer.lines.len())
+                .map(|i| buffer.line_layout(font_system, i).map_or(1, |layout| layout.len()))
+                .sum();
+            rows = rows.max(wrapped_lines);
+        }
+        height = rows as f32 * buffer.metrics().line_height;
+        buffer.set_size(font_system, Some(width), Some(height));
+
+        // keep cursor visible
+        buffer.shape_until_cursor(font_system, cursor, true);
+    });
+    Vec2::new(width, height + 5.0)
+}
+
+/// Cursor at the end of the last buffer line.
+pub(crate) fn buffer_end_cursor(editor: &Editor) -> Cursor {
+    editor.with_buffer(|buffer| {
+        let line = buffer.lines.len().saturating_sub(1);
+        Cursor::new(line, buffer.lines.get(line).map_or(0, |l| l.text().len()))
+    })
+}
+
+/// Pointer position relative to the top-left of the buffer texture.
+pub(crate) fn pointer_buffer_pos(
+    pointer: &Vec2,
+    computed: &ComputedNode,
+    transform: &GlobalTransform
+) -> (i32, i32) {
+    let pos = mouse_pos_to_local_node_pos(pointer, computed, transform);
+    let border = computed.border();
+    let scale = computed.inverse_scale_factor();
+    ((pos.x - border.left * scale) as i32, (pos.y - border.top * scale) as i32)
+}
+
+/// Run an editing action on the buffer & read the value back from it.
+/// Placeholder is removed from buffer before editing & drawn again if
+/// the value becomes empty.
+pub(crate) fn edit_buffer(
+    editor: &mut Editor,
+    font_system: &mut FontSystem,
+    attrs: Attrs,
+    text: &mut TextAreaText,
+    edit: impl FnOnce(&mut Editor, &mut FontSystem)
+) {
+    if text.value.is_empty() {
+        editor.with_buffer_mut(|buffer| buffer.set_text(font_system, "", attrs, Shaping::Advanced));
+        editor.set_cursor(Cursor::new(0, 0));
+    }
+    edit(editor, font_system);
+    text.value = buffer_text(editor);
+
+    if text.value.is_empty() {
+        reset_buffer_text(editor, font_system, attrs, text);
+    }
+}
+
+/// Move cursor, selection is extended while shift is held.
+pub(crate) fn move_cursor(
+    editor: &mut Editor,
+    font_system: &mut FontSystem,
+    text: &TextAreaText,
+    motion: Motion,
+    shift: bool
+) {
+    if text.value.is_empty() {
+        return; // only placeholder in buffer
+    }
+    if shift {
+        if editor.selection() == Selection::None {
+            editor.set_selection(Selection::Normal(editor.cursor()));
+        }
+    } else {
+        editor.set_selection(Selection::None);
+    }
+    editor.action(font_system, Action::Motion(motion));
+}
+
+/// Select all, return true if value is not empty.
+pub(crate) fn select_all(editor: &mut Editor, text: &TextAreaText) -> bool {
+    if text.value.is_empty() {
+        return false;
+    }
+    editor.set_selection(Selection::Normal(Cursor::new(0, 0)));
+    editor.set_cursor(buffer_end_cursor(editor));
+    true
+}
@@ -0,0 +1,227 @@
+//! Multi-line text input, built on the same cosmic-text editor as `text_input`.
+
+pub mod components;
+pub mod helper;
+pub mod systems;
+pub mod system_params;
+pub mod tests;
+
+pub(crate) use components::*;
+pub(crate) use systems::*;
+use helper::*;
+use system_params::*;
+
+use famiq_macros::set_widget_attributes;
+
+use crate::widgets::container::base_container::*;
+use crate::widgets::text_input::{
+    helper as input_helper, CosmicData, CosmicDataColor, CursorBlinkTimer, CosmicTextData,
+    TextInputMaterial, RequestRedrawBuffer, FaTextInputBufferTextureEntity, FaTextInputEntity,
+    IsFamiqTextInputBufferTexture, CURSOR_INVISIBLE
+};
+use crate::widgets::text_input::system_params::InputPickingParam;
+use crate::event_writer::*;
+use crate::plugin::CursorType;
+use crate::utils::*;
+use crate::resources::*;
+use crate::widgets::*;
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input::mouse::MouseScrollUnit;
+use bevy::prelude::*;
+use cosmic_text::{Buffer, Editor, Edit, Metrics, Shaping, Wrap, Cursor, Selection, Action, Motion};
+
+#[cfg(not(target_arch = "wasm32"))]
+use arboard::Clipboard;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct TextAreaBuilder {
+    pub placeholder: String,
+    pub rows: usize,
+    pub auto_grow: bool,
+    pub all_reactive_keys: Vec<String>
+}
+
+impl TextAreaBuilder {
+    pub fn new(font_handle: &Handle<Font>) -> Self {
+        Self {
+            placeholder: String::new(),
+            rows: TextAreaConfig::default().rows,
+            auto_grow: false,
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default(),
+            all_reactive_keys: Vec::new()
+        }
+    }
+
+    pub fn set_placeholder(&mut self, placeholder: &str) {
+        self.placeholder = resolve_template_alias(placeholder);
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) -> String {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_textarea_node();
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+
+        let reactive_keys = get_reactive_key(&self.placeholder);
+        let parsed_placeholder = replace_reactive_keys(&self.placeholder, &reactive_keys, r_data);
+        self.all_reactive_keys.extend_from_slice(&reactive_keys);
+        parsed_placeholder
+    }
+
+    fn text_data(&self) -> CosmicTextData {
+        CosmicTextData {
+            handle: self.cloned_attrs.font_handle.clone().unwrap(),
+            size: get_text_size(&self.cloned_attrs.size),
+            color: get_text_color(&self.cloned_attrs.color)
+        }
+    }
+
+    fn config(&self) -> TextAreaConfig {
+        TextAreaConfig {
+            rows: self.rows.max(1),
+            auto_grow: self.auto_grow
+        }
+    }
+}
+
+impl SetupWidget for TextAreaBuilder {
+    fn components(&mut self) -> impl Bundle {
+        self._process_built_in_size_class();
+        let text_data = self.text_data();
+        (
+            IsFamiqTextArea,
+            MainWidget,
+            CosmicDataColor::new(text_data.color),
+            CosmicData::default(),
+            CursorBlinkTimer::default(),
+            self.config(),
+            text_data.clone(),
+            DefaultCosmicTextEntity {
+                text_data
+            }
+        )
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        let parsed_placeholder = self.prepare_attrs(r_data);
+
+        let mut textarea = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let textarea_entity = textarea.build(r_data, commands);
+
+        commands
+            .entity(textarea_entity)
+            .insert(self.components())
+            .insert(TextAreaText::new(&parsed_placeholder))
+            .observe(on_mouse_up)
+            .observe(on_mouse_down)
+            .observe(on_mouse_drag)
+            .observe(on_mouse_scroll)
+            .observe(on_mouse_over)
+            .observe(on_mouse_out);
+
+        if self.attributes.has_tooltip {
+            build_tooltip_node(&self.cloned_attrs, commands, textarea_entity);
+        }
+
+        insert_class_id(commands, textarea_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, textarea_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                textarea_entity,
+                WidgetBuilder {
+                    builder: BuilderType::TextArea(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        textarea_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        let parsed_placeholder = self.prepare_attrs(r_data);
+        let mut textarea = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        textarea.rebuild(r_data, old_entity, world);
+
+        // editor is kept, changed text data resets its buffer with the new placeholder.
+        self._process_built_in_size_class();
+        let text_data = self.text_data();
+        if let Some(mut text) = world.get_mut::<TextAreaText>(old_entity) {
+            text.placeholder = parsed_placeholder;
+        }
+        world
+            .entity_mut(old_entity)
+            .insert((text_data.clone(), DefaultCosmicTextEntity { text_data }));
+
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::TextArea(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a multi-line text input.
+/// Its `model` is two-way bound, changing the model from code replaces the text.
+/// Clipboard is not supported yet on WASM.
+#[macro_export]
+macro_rules! textarea {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let t_builder = &mut TextAreaBuilder::new(&famiq_builder.get_font_handle());
+        $(
+            $crate::textarea_attributes!(t_builder, $key : $value);
+        )*
+        t_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(textarea [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! textarea_attributes {
+    ($t_builder:ident, placeholder: $placeholder:expr) => {{
+        $t_builder.set_placeholder($placeholder);
+    }};
+    ($t_builder:ident, rows: $rows:expr) => {{
+        $t_builder.rows = $rows as usize;
+    }};
+    ($t_builder:ident, auto_grow: $auto_grow:expr) => {{
+        $t_builder.auto_grow = $auto_grow;
+    }};
+    ($t_builder:ident, model: $model:expr) => {{
+        $t_builder.set_model($model);
+    }};
+    ($t_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($t_builder, $key : $value);
+    }};
+}
+
+/// Determines if textarea internal system(s) can run.
+///
+/// True only if there is a textarea widget created.
+pub fn can_run_textarea_systems(textarea_q: Query<&IsFamiqTextArea>) -> bool {
+    !textarea_q.is_empty()
+}
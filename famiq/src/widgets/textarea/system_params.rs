@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+
+use crate::resources::*;
+use super::*;
+
+type TypingQuery<'w, 's> = Query<
+    'w, 's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static TextAreaConfig,
+        &'static mut CursorBlinkTimer,
+        &'static mut CosmicData,
+        &'static mut TextAreaText,
+        Option<&'static WidgetId>,
+        Option<&'static ReactiveModelKey>
+    ),
+    With<IsFamiqTextArea>
+>;
+
+// params that used in textarea typing system
+#[derive(SystemParam)]
+pub(crate) struct TextAreaTypingParam<'w, 's> {
+    pub textarea_q: TypingQuery<'w, 's>,
+    pub fa_query: FaQuery<'w, 's>,
+    pub evr_kbd: EventReader<'w, 's, KeyboardInput>,
+    pub famiq_res: ResMut<'w, FamiqResource>,
+    pub font_system: ResMut<'w, CosmicFontSystem>,
+    pub request_redraw: EventWriter<'w, RequestRedrawBuffer>,
+    pub value_writer: EventWriter<'w, FaValueChangeEvent>,
+    pub keys: Res<'w, ButtonInput<KeyCode>>,
+}
+
+// params that used in systems creating textarea's editor & buffer texture
+#[derive(SystemParam)]
+pub(crate) struct NewTextAreaParam<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub font_system: ResMut<'w, CosmicFontSystem>,
+    pub swash_cache: ResMut<'w, CosmicSwashCache>,
+    pub image_assets: ResMut<'w, Assets<Image>>,
+    pub materials: ResMut<'w, Assets<TextInputMaterial>>,
+    pub font_assets: Res<'w, Assets<Font>>,
+}
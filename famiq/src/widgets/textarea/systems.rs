@@ -0,0 +1,497 @@
+use bevy::prelude::*;
+use super::*;
+
+type TextAreaHoverQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut BoxShadow, &'static BorderColor, Option<&'static WidgetId>, &'static GlobalTransform, Option<&'static TooltipEntity>),
+    With<IsFamiqTextArea>
+>;
+
+type TextAreaOutQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut BoxShadow, Option<&'static WidgetId>, Option<&'static TooltipEntity>),
+    With<IsFamiqTextArea>
+>;
+
+type TextAreaModelQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static TextAreaConfig,
+        &'static mut CosmicData,
+        &'static mut TextAreaText,
+        &'static ReactiveModelKey
+    ),
+    With<IsFamiqTextArea>
+>;
+
+type TextAreaPickingQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static GlobalTransform,
+        &'static ComputedNode,
+        &'static TextAreaText,
+        &'static mut CosmicData,
+        Option<&'static WidgetId>
+    ),
+    With<IsFamiqTextArea>
+>;
+
+type NewTextAreaQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static TextAreaConfig,
+        &'static TextAreaText,
+        &'static CosmicTextData,
+        &'static CosmicDataColor,
+        &'static mut CosmicData
+    ),
+    Added<IsFamiqTextArea>
+>;
+
+type TextAreaStyleQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static TextAreaConfig,
+        &'static TextAreaText,
+        &'static CosmicTextData,
+        &'static mut CosmicData,
+        &'static mut CosmicDataColor
+    ),
+    (With<IsFamiqTextArea>, Changed<CosmicTextData>)
+>;
+
+type TextAreaResizeQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static ComputedNode, &'static TextAreaConfig, &'static mut CosmicData),
+    (With<IsFamiqTextArea>, Or<(Changed<ComputedNode>, Changed<TextAreaConfig>)>)
+>;
+
+pub(crate) fn on_mouse_over(
+    mut trigger: Trigger<Pointer<Over>>,
+    mut textarea_q: TextAreaHoverQuery,
+    mut param: InputPickingParam
+) {
+    if let Ok((mut box_shadow, border_color, id, transform, tooltip_entity)) = textarea_q.get_mut(trigger.target()) {
+        box_shadow.0[0].color = border_color.0;
+        show_tooltip(tooltip_entity, &mut param.tooltip_q, transform.translation());
+        _change_cursor_icon(&mut param.commands, &param.cursor_icons, *param.window, CursorType::Text);
+        FaMouseEvent::send_event(&mut param.mouse_writer, EventType::Over, WidgetType::TextArea, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_out(
+    mut trigger: Trigger<Pointer<Out>>,
+    mut textarea_q: TextAreaOutQuery,
+    mut param: InputPickingParam
+) {
+    if let Ok((mut box_shadow, id, tooltip_entity)) = textarea_q.get_mut(trigger.target()) {
+        box_shadow.0[0].color = Color::NONE;
+        hide_tooltip(tooltip_entity, &mut param.tooltip_q);
+        _change_cursor_icon(&mut param.commands, &param.cursor_icons, *param.window, CursorType::Default);
+        FaMouseEvent::send_event(&mut param.mouse_writer, EventType::Out, WidgetType::TextArea, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+/// Focus the textarea & move cursor to the clicked position.
+pub(crate) fn on_mouse_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    mut textarea_q: TextAreaPickingQuery,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut request_redraw: EventWriter<RequestRedrawBuffer>,
+    mut writer: EventWriter<FaMouseEvent>,
+) {
+    if let Ok((transform, computed, text, mut cosmic_data, id)) = textarea_q.get_mut(trigger.target()) {
+        famiq_res.update_all_focus_states(false);
+        famiq_res.update_or_insert_focus_state(trigger.target(), true);
+
+        if let Some(editor) = cosmic_data.editor.as_mut() {
+            editor.set_selection(Selection::None);
+
+            if trigger.event().button == PointerButton::Primary && !text.value.is_empty() {
+                let (x, y) = pointer_buffer_pos(&trigger.pointer_location.position, computed, transform);
+                editor.action(&mut font_system.0, Action::Click { x, y });
+            }
+            request_redraw.write(RequestRedrawBuffer::new(trigger.target()));
+        }
+        if trigger.event().button == PointerButton::Secondary {
+            FaMouseEvent::send_event(&mut writer, EventType::DownRight, WidgetType::TextArea, trigger.target(), id);
+        } else {
+            FaMouseEvent::send_event(&mut writer, EventType::DownLeft, WidgetType::TextArea, trigger.target(), id);
+        }
+    }
+    trigger.propagate(false);
+}
+
+/// Select text while dragging.
+pub(crate) fn on_mouse_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    mut textarea_q: TextAreaPickingQuery,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut request_redraw: EventWriter<RequestRedrawBuffer>,
+) {
+    if trigger.event().button == PointerButton::Primary
+        && let Ok((transform, computed, text, mut cosmic_data, _)) = textarea_q.get_mut(trigger.target())
+        && !text.value.is_empty()
+        && let Some(editor) = cosmic_data.editor.as_mut()
+    {
+        let (x, y) = pointer_buffer_pos(&trigger.pointer_location.position, computed, transform);
+        editor.action(&mut font_system.0, Action::Drag { x, y });
+        request_redraw.write(RequestRedrawBuffer::new(trigger.target()));
+    }
+    trigger.propagate(false);
+}
+
+/// Scroll the lines vertically. Auto-grow textarea has nothing to scroll,
+/// the event is left to its parent.
+pub(crate) fn on_mouse_scroll(
+    mut trigger: Trigger<Pointer<Scroll>>,
+    mut textarea_q: Query<(&TextAreaConfig, &mut CosmicData), With<IsFamiqTextArea>>,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut request_redraw: EventWriter<RequestRedrawBuffer>,
+) {
+    let Ok((config, mut cosmic_data)) = textarea_q.get_mut(trigger.target()) else { return };
+
+    if config.auto_grow {
+        return;
+    }
+    if let Some(editor) = cosmic_data.editor.as_mut() {
+        let font_system = &mut font_system.0;
+        let lines = match trigger.event().unit {
+            MouseScrollUnit::Line => -trigger.event().y,
+            MouseScrollUnit::Pixel => {
+                -trigger.event().y / editor.with_buffer(|buffer| buffer.metrics().line_height)
+            }
+        };
+        editor.action(font_system, Action::Scroll { lines: lines.round() as i32 });
+        editor.with_buffer_mut(|buffer| buffer.shape_until_scroll(font_system, true));
+        request_redraw.write(RequestRedrawBuffer::new(trigger.target()));
+    }
+    trigger.propagate(false);
+}
+
+pub(crate) fn on_mouse_up(
+    mut trigger: Trigger<Pointer<Released>>,
+    textarea_q: Query<Option<&WidgetId>, With<IsFamiqTextArea>>,
+    mut writer: EventWriter<FaMouseEvent>,
+) {
+    if let Ok(id) = textarea_q.get(trigger.target()) {
+        FaMouseEvent::send_event(&mut writer, EventType::Up, WidgetType::TextArea, trigger.target(), id);
+    }
+    trigger.propagate(false);
+}
+
+pub fn handle_textarea_cursor_blink_system(
+    mut textarea_q: Query<
+        (Entity, &mut CursorBlinkTimer, &mut CosmicDataColor),
+        With<IsFamiqTextArea>
+    >,
+    mut request_redraw_buffer: EventWriter<RequestRedrawBuffer>,
+    famiq_res: Res<FamiqResource>,
+    time: Res<Time>,
+) {
+    textarea_q.iter_mut().for_each(|(entity, mut blink_timer, mut cosmic_color)| {
+        blink_timer.timer.tick(time.delta());
+
+        let need_redraw = if famiq_res.get_widget_focus_state(&entity) == Some(true) {
+            input_helper::handle_cursor_blink_on_focused(&mut blink_timer, &mut cosmic_color)
+        } else {
+            input_helper::handle_cursor_blink_on_unfocused(&mut blink_timer, &mut cosmic_color)
+        };
+        if need_redraw {
+            request_redraw_buffer.write(RequestRedrawBuffer::new(entity));
+        }
+        blink_timer.can_blink = true;
+    });
+}
+
+pub(crate) fn handle_textarea_on_focused(
+    mut textarea_q: Query<(Entity, &mut CursorBlinkTimer, &mut CosmicDataColor), With<IsFamiqTextArea>>,
+    famiq_res: Res<FamiqResource>
+) {
+    if !famiq_res.is_changed() || famiq_res.is_added() {
+        return;
+    }
+    textarea_q.iter_mut().for_each(|(entity, mut cursor_blink, mut cosmic_color)| {
+        if let Some(focused) = famiq_res.get_widget_focus_state(&entity) {
+            if focused {
+                cosmic_color.cursor_color = cosmic_color.text_color;
+            }
+            let duration = cursor_blink.timer.duration();
+            cursor_blink.timer.set_elapsed(duration);
+        }
+    });
+}
+
+/// Internal system to create editor & buffer texture of newly spawned textareas.
+pub(crate) fn detect_new_textarea_widget_system(
+    mut textarea_q: NewTextAreaQuery,
+    mut param: NewTextAreaParam
+) {
+    for (entity, computed, config, text, text_data, cosmic_color, mut cosmic_data) in textarea_q.iter_mut() {
+        let font_system = &mut param.font_system.0;
+        let attrs = input_helper::load_font_attrs(&param.font_assets, font_system, &text_data.handle);
+        let metrics = Metrics::relative(text_data.size, 1.2);
+
+        let mut buffer = Buffer::new(font_system, metrics);
+        buffer.set_wrap(font_system, Wrap::WordOrGlyph);
+
+        let mut editor = Editor::new(buffer);
+        reset_buffer_text(&mut editor, font_system, attrs, text);
+        let buffer_dim = layout_buffer(&mut editor, font_system, config, content_width(computed));
+
+        let pixels = input_helper::draw_editor_buffer(
+            &buffer_dim,
+            font_system,
+            &mut param.swash_cache.0,
+            &mut editor,
+            cosmic_color
+        );
+
+        // need empty pixels at buffer size for ImageNode. see 'on_request_redraw_editor_buffer' system
+        let empty_texture_handle = input_helper::create_empty_buffer_texture(&buffer_dim, &mut param.image_assets);
+        let texture_handle = input_helper::create_buffer_texture(&buffer_dim, &pixels, &mut param.image_assets);
+
+        if let Color::Srgba(value) = cosmic_rgba_to_bevy_srgba(cosmic_color.text_color) {
+            let texture_image = param.commands
+                .spawn((
+                    ImageNode::new(empty_texture_handle),
+                    MaterialNode(param.materials.add(TextInputMaterial {
+                        color: Vec4::new(value.red, value.green, value.blue, 1.0),
+                        texture: texture_handle
+                    })),
+                    Node::default(),
+                    IsFamiqTextInputBufferTexture,
+                    FaTextInputEntity(entity),
+                    Pickable::IGNORE
+                ))
+                .id();
+
+            param.commands
+                .entity(entity)
+                .insert(FaTextInputBufferTextureEntity(texture_image))
+                .add_child(texture_image);
+        }
+        cosmic_data.editor = Some(editor);
+        cosmic_data.attrs = Some(attrs);
+        cosmic_data.metrics = Some(metrics);
+        cosmic_data.buffer_dim = buffer_dim;
+    }
+}
+
+pub(crate) fn handle_textarea_on_typing(mut param: TextAreaTypingParam) {
+    for e in param.evr_kbd.read() {
+        if e.state == ButtonState::Released {
+            continue;
+        }
+        let ctrl = param.keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+        let shift = param.keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+        for (
+            entity,
+            computed,
+            config,
+            mut blink_timer,
+            mut cosmic_data,
+            mut text,
+            id,
+            model_key
+        ) in param.textarea_q.iter_mut() {
+            if param.famiq_res.get_widget_focus_state(&entity) != Some(true) {
+                continue;
+            }
+            let CosmicData { editor, attrs, buffer_dim, .. } = &mut *cosmic_data;
+            let (Some(editor), Some(attrs)) = (editor.as_mut(), *attrs) else { continue };
+            let font_system = &mut param.font_system.0;
+            let old_value = text.value.clone();
+
+            if ctrl {
+                match e.key_code {
+                    KeyCode::KeyA => {
+                        select_all(editor, &text);
+                    }
+                    KeyCode::KeyC => {
+                        if let Some(selected) = editor.copy_selection() {
+                            #[cfg(not(target_arch = "wasm32"))]
+                            if let Ok(mut ctx) = Clipboard::new() {
+                                let _ = ctx.set_text(selected.clone());
+                            }
+                            param.famiq_res.copied_text = selected;
+                        }
+                        continue;
+                    }
+                    KeyCode::KeyV => {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        let copied_text = Clipboard::new()
+                            .and_then(|mut ctx| ctx.get_text())
+                            .ok()
+                            .filter(|s| !s.is_empty())
+                            .unwrap_or_else(|| param.famiq_res.copied_text.clone());
+
+                        #[cfg(target_arch = "wasm32")]
+                        let copied_text = param.famiq_res.copied_text.clone();
+
+                        if !copied_text.is_empty() {
+                            edit_buffer(editor, font_system, attrs, &mut text, |editor, _| {
+                                editor.insert_string(&copied_text, None);
+                            });
+                        }
+                    }
+                    _ => continue
+                }
+            } else {
+                match &e.logical_key {
+                    Key::Character(key_input) => {
+                        edit_buffer(editor, font_system, attrs, &mut text, |editor, font_system| {
+                            key_input.chars().for_each(|c| editor.action(font_system, Action::Insert(c)));
+                        });
+                    }
+                    Key::Space => edit_buffer(editor, font_system, attrs, &mut text, |editor, font_system| {
+                        editor.action(font_system, Action::Insert(' '));
+                    }),
+                    Key::Enter => edit_buffer(editor, font_system, attrs, &mut text, |editor, font_system| {
+                        editor.action(font_system, Action::Enter);
+                    }),
+                    Key::Backspace => edit_buffer(editor, font_system, attrs, &mut text, |editor, font_system| {
+                        editor.action(font_system, Action::Backspace);
+                    }),
+                    Key::Delete => edit_buffer(editor, font_system, attrs, &mut text, |editor, font_system| {
+                        editor.action(font_system, Action::Delete);
+                    }),
+                    Key::Escape => editor.action(font_system, Action::Escape),
+                    Key::ArrowLeft => move_cursor(editor, font_system, &text, Motion::Left, shift),
+                    Key::ArrowRight => move_cursor(editor, font_system, &text, Motion::Right, shift),
+                    Key::ArrowUp => move_cursor(editor, font_system, &text, Motion::Up, shift),
+                    Key::ArrowDown => move_cursor(editor, font_system, &text, Motion::Down, shift),
+                    Key::Home => move_cursor(editor, font_system, &text, Motion::Home, shift),
+                    Key::End => move_cursor(editor, font_system, &text, Motion::End, shift),
+                    Key::PageUp => move_cursor(editor, font_system, &text, Motion::PageUp, shift),
+                    Key::PageDown => move_cursor(editor, font_system, &text, Motion::PageDown, shift),
+                    _ => continue
+                }
+            }
+            *buffer_dim = layout_buffer(editor, font_system, config, content_width(computed));
+
+            if text.value != old_value {
+                if let Some(key) = model_key {
+                    let _ = param.fa_query.try_mutate(&key.0, RVal::Str(text.value.clone()));
+                }
+                FaValueChangeEvent::send_event(
+                    &mut param.value_writer,
+                    WidgetType::TextArea,
+                    entity,
+                    id,
+                    RVal::Str(text.value.clone())
+                );
+            }
+            param.request_redraw.write(RequestRedrawBuffer::new(entity));
+            blink_timer.can_blink = false;
+        }
+    }
+}
+
+/// Internal system to apply model changes made outside of typing (eg. from code)
+/// to textareas. Newly spawned textareas load their model value as well.
+pub(crate) fn detect_textarea_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut textarea_q: TextAreaModelQuery,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut request_redraw: EventWriter<RequestRedrawBuffer>,
+) {
+    let data_changed = reactive_data.is_changed();
+
+    for (entity, computed, config, mut cosmic_data, mut text, model_key) in textarea_q.iter_mut() {
+        if !data_changed && !text.is_added() {
+            continue;
+        }
+        let Some(RVal::Str(value)) = get_reactive_value(&reactive_data.data, &model_key.0) else {
+            continue;
+        };
+        if *value == text.value {
+            continue;
+        }
+        text.value.clone_from(value);
+
+        let CosmicData { editor, attrs, buffer_dim, .. } = &mut *cosmic_data;
+
+        if let (Some(editor), Some(attrs)) = (editor.as_mut(), attrs) {
+            reset_buffer_text(editor, &mut font_system.0, *attrs, &text);
+            *buffer_dim = layout_buffer(editor, &mut font_system.0, config, content_width(computed));
+            request_redraw.write(RequestRedrawBuffer::new(entity));
+        }
+    }
+}
+
+/// Internal system to detect text's style (font-size & color) changes
+pub(crate) fn detect_textarea_text_style_change(
+    mut textarea_q: TextAreaStyleQuery,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut request_redraw: EventWriter<RequestRedrawBuffer>,
+    famiq_res: Res<FamiqResource>,
+) {
+    for (entity, computed, config, text, text_data, mut cosmic_data, mut cosmic_color) in textarea_q.iter_mut() {
+        if let Some(color) = bevy_color_to_cosmic_rgba(text_data.color) {
+            cosmic_color.text_color = color;
+            cosmic_color.selected_text_color = color;
+
+            if let Some(is_focused) = famiq_res.get_widget_focus_state(&entity) {
+                cosmic_color.cursor_color = if is_focused { color } else { CURSOR_INVISIBLE };
+            }
+        }
+        let CosmicData { editor, attrs, metrics, buffer_dim, .. } = &mut *cosmic_data;
+
+        if text_data.size > 0.0
+            && let (Some(editor), Some(attrs)) = (editor.as_mut(), attrs)
+        {
+            let font_system = &mut font_system.0;
+            let cursor = editor.cursor();
+            let new_metrics = Metrics::relative(text_data.size, 1.2);
+
+            editor.with_buffer_mut(|buffer| buffer.set_metrics(font_system, new_metrics));
+            reset_buffer_text(editor, font_system, *attrs, text);
+
+            // placeholder may be changed, value is the same
+            if !text.value.is_empty() {
+                editor.set_cursor(cursor);
+            }
+            *metrics = Some(new_metrics);
+            *buffer_dim = layout_buffer(editor, font_system, config, content_width(computed));
+        }
+        request_redraw.write(RequestRedrawBuffer::new(entity));
+    }
+}
+
+/// Internal system to wrap lines again when a textarea gets resized.
+pub(crate) fn update_textarea_layout(
+    mut textarea_q: TextAreaResizeQuery,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut request_redraw: EventWriter<RequestRedrawBuffer>,
+) {
+    for (entity, computed, config, mut cosmic_data) in textarea_q.iter_mut() {
+        let CosmicData { editor, buffer_dim, .. } = &mut *cosmic_data;
+
+        if let Some(editor) = editor.as_mut() {
+            let new_dim = layout_buffer(editor, &mut font_system.0, config, content_width(computed));
+
+            if new_dim != *buffer_dim {
+                *buffer_dim = new_dim;
+                request_redraw.write(RequestRedrawBuffer::new(entity));
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+use crate::widgets::FamiqResource;
+use crate::textarea;
+use bevy::ecs::system::RunSystemOnce;
+use super::*;
+
+fn setup_test_default_textarea(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    textarea!(id: "#test-textarea", placeholder: "Write something");
+}
+
+fn setup_test_textarea_with_model(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_str("bio", "Hello\nWorld");
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    textarea!(rows: 5, auto_grow: true, model: "bio");
+}
+
+fn focus_textarea(app: &mut App) {
+    let entity = app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTextArea>>()
+        .single(app.world())
+        .unwrap();
+    let _ = app.world_mut().run_system_once(move |mut famiq_res: ResMut<FamiqResource>| {
+        famiq_res.update_or_insert_focus_state(entity, true);
+    });
+}
+
+fn textarea_value(app: &mut App) -> String {
+    app.world_mut()
+        .query::<&TextAreaText>()
+        .single(app.world())
+        .unwrap()
+        .value
+        .clone()
+}
+
+fn cursor_line(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&CosmicData>()
+        .single(app.world())
+        .unwrap()
+        .editor
+        .as_ref()
+        .unwrap()
+        .cursor()
+        .line
+}
+
+#[test]
+fn test_create_default_textarea() {
+    let mut app = create_famiq_test_app(setup_test_default_textarea);
+
+    let (id, config, text) = app.world_mut()
+        .query::<(&WidgetId, &TextAreaConfig, &TextAreaText)>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!("#test-textarea".to_string(), id.0);
+    assert_eq!(*config, TextAreaConfig { rows: 3, auto_grow: false });
+    assert_eq!("Write something", text.placeholder);
+    assert!(text.value.is_empty());
+}
+
+#[test]
+fn test_textarea_follows_model() {
+    let mut app = create_famiq_test_app(setup_test_textarea_with_model);
+
+    assert_eq!("Hello\nWorld", textarea_value(&mut app));
+    assert_eq!(1, cursor_line(&mut app), "cursor is at the end");
+
+    let _ = app.world_mut().run_system_once(|mut fa_query: FaQuery| {
+        fa_query.mutate_str("bio", "a\nb\nc");
+    });
+    app.update();
+
+    assert_eq!("a\nb\nc", textarea_value(&mut app));
+    assert_eq!(2, cursor_line(&mut app));
+}
+
+#[test]
+fn test_textarea_typing_updates_model() {
+    let mut app = create_famiq_test_app(setup_test_textarea_with_model);
+    focus_textarea(&mut app);
+
+    press_key(&mut app, KeyCode::Enter, Key::Enter);
+    press_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+    assert_eq!("Hello\nWorld\na", textarea_value(&mut app));
+    assert_eq!(2, cursor_line(&mut app));
+
+    press_key(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+    assert_eq!(1, cursor_line(&mut app));
+
+    press_key(&mut app, KeyCode::Backspace, Key::Backspace);
+    let model = app.world_mut()
+        .run_system_once(|fa_query: FaQuery| fa_query.get_data("bio").cloned())
+        .unwrap();
+    assert_eq!(Some(RVal::Str(textarea_value(&mut app))), model);
+}
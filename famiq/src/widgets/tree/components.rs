@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+use bevy::platform::collections::{HashMap, HashSet};
+
+use crate::reactivity::{IntoRVal, RVal};
+
+/// Marker component for identifying an entity as a Famiq tree.
+#[derive(Component)]
+pub struct IsFamiqTree;
+
+/// Node of a tree, converted into `RVal::Map` with `label`, `id`, `children` & `lazy` keys.
+///
+/// Children of a lazy node are loaded by the app once the node is expanded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeNode {
+    pub label: String,
+    pub id: Option<String>,
+    pub children: Vec<TreeNode>,
+    pub lazy: bool
+}
+
+impl TreeNode {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            ..default()
+        }
+    }
+
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+}
+
+impl IntoRVal for TreeNode {
+    fn into_rval(self) -> RVal {
+        let mut node = HashMap::new();
+        node.insert("label".to_string(), RVal::Str(self.label));
+
+        if let Some(id) = self.id {
+            node.insert("id".to_string(), RVal::Str(id));
+        }
+        node.insert("children".to_string(), self.children.into_rval());
+        node.insert("lazy".to_string(), RVal::Bool(self.lazy));
+        RVal::Map(node)
+    }
+}
+
+/// Reactive key of tree nodes, an array of maps.
+#[derive(Component, Clone, Debug)]
+pub struct TreeNodesKey(pub String);
+
+/// Keys of expanded nodes.
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+pub struct TreeExpanded(pub HashSet<String>);
+
+/// Keys of selected nodes.
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+pub struct TreeSelected(pub Vec<String>);
+
+/// Node moved by keyboard.
+#[derive(Component, Default, Clone, Debug, PartialEq)]
+pub struct TreeActive(pub Option<String>);
+
+/// Last pressed node & time, pressing it again shortly activates it.
+#[derive(Component, Default, Clone, Debug)]
+pub struct TreeLastPress(pub Option<(String, f64)>);
+
+/// More than one node can be selected with ctrl.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TreeMultiple(pub bool);
+
+/// Visible node of a tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeItem {
+    /// id of the node, or its index path if it has no id
+    pub key: String,
+    /// reactive path of the node
+    pub path: String,
+    pub depth: usize,
+    pub label: String,
+    pub has_children: bool,
+    pub expanded: bool,
+    /// placeholder shown while children of a lazy node are loading
+    pub loading: bool
+}
+
+/// Row entities along with the visible nodes they show.
+#[derive(Component, Default, Debug)]
+pub struct TreeRows {
+    pub entities: Vec<Entity>,
+    pub items: Vec<TreeItem>
+}
+
+/// Text style of rows, background color of selected rows & color of indentation guides.
+#[derive(Component, Clone, Debug)]
+pub struct TreeStyle {
+    pub font: TextFont,
+    pub text_color: Color,
+    pub selected_color: Color,
+    pub guide_color: Color
+}
+
+/// Row at visible position `slot` of a tree.
+#[derive(Component)]
+pub struct TreeRow {
+    pub tree: Entity,
+    pub slot: usize
+}
+
+/// Expand/collapse arrow of a row.
+#[derive(Component)]
+pub struct TreeArrow {
+    pub tree: Entity,
+    pub slot: usize
+}
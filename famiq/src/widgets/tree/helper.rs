@@ -0,0 +1,119 @@
+use bevy::platform::collections::HashSet;
+
+use super::*;
+
+pub(crate) const LOADING_LABEL: &str = "Loading...";
+
+fn node_children(node: &HashMap<String, RVal>) -> &[RVal] {
+    match node.get("children") {
+        Some(RVal::Array(children)) => children,
+        _ => &[]
+    }
+}
+
+fn collect_items(
+    nodes: &[RVal],
+    path: &str,
+    index_path: &str,
+    depth: usize,
+    expanded: &HashSet<String>,
+    items: &mut Vec<TreeItem>
+) {
+    for (index, value) in nodes.iter().enumerate() {
+        let RVal::Map(node) = value else { continue };
+
+        let node_path = format!("{path}.{index}");
+        let node_index_path = if index_path.is_empty() { index.to_string() } else { format!("{index_path}.{index}") };
+        let key = match node.get("id") {
+            Some(RVal::Str(id)) => id.clone(),
+            _ => node_index_path.clone()
+        };
+        let children = node_children(node);
+        let lazy = node.get("lazy").is_some_and(RVal::is_truthy);
+        let has_children = !children.is_empty() || lazy;
+        let is_expanded = has_children && expanded.contains(&key);
+
+        items.push(TreeItem {
+            key: key.clone(),
+            path: node_path.clone(),
+            depth,
+            label: node.get("label").map(RVal::to_string).unwrap_or_default(),
+            has_children,
+            expanded: is_expanded,
+            loading: false
+        });
+
+        if !is_expanded {
+            continue;
+        }
+        if children.is_empty() {
+            items.push(TreeItem {
+                key: format!("{key}.loading"),
+                path: format!("{node_path}.children"),
+                depth: depth + 1,
+                label: LOADING_LABEL.to_string(),
+                has_children: false,
+                expanded: false,
+                loading: true
+            });
+        } else {
+            let children_path = format!("{node_path}.children");
+            collect_items(children, &children_path, &node_index_path, depth + 1, expanded, items);
+        }
+    }
+}
+
+/// Visible nodes of a tree in display order. Children of collapsed nodes are hidden,
+/// expanded lazy nodes without children show a loading placeholder.
+pub(crate) fn visible_tree_items(
+    r_data: &HashMap<String, RVal>,
+    nodes_key: &str,
+    expanded: &HashSet<String>
+) -> Vec<TreeItem> {
+    let mut items = Vec::new();
+
+    if let Some(RVal::Array(nodes)) = get_reactive_value(r_data, nodes_key) {
+        collect_items(nodes, nodes_key, "", 0, expanded, &mut items);
+    }
+    items
+}
+
+/// Position of the closest visible ancestor of the item at `slot`.
+pub(crate) fn parent_slot(items: &[TreeItem], slot: usize) -> Option<usize> {
+    let depth = items.get(slot)?.depth;
+    items[..slot].iter().rposition(|item| item.depth < depth)
+}
+
+/// Next (or previous) selectable position from `slot`, the first or last one if `slot` is None.
+pub(crate) fn step_slot(items: &[TreeItem], slot: Option<usize>, forward: bool) -> Option<usize> {
+    let selectable = |index: &usize| !items[*index].loading;
+
+    match (slot, forward) {
+        (Some(slot), true) => (slot + 1..items.len()).find(selectable),
+        (Some(slot), false) => (0..slot).rev().find(selectable),
+        (None, true) => (0..items.len()).find(selectable),
+        (None, false) => (0..items.len()).rev().find(selectable)
+    }
+}
+
+/// Selected keys with `key` added, or removed if it's already selected.
+pub(crate) fn toggled_selection(selected: &[String], key: &str) -> Vec<String> {
+    let mut keys = selected.to_vec();
+
+    match keys.iter().position(|selected_key| selected_key == key) {
+        Some(index) => {
+            keys.remove(index);
+        }
+        None => keys.push(key.to_string())
+    }
+    keys
+}
+
+/// Model value of selected nodes, a list in multiple mode.
+pub(crate) fn selection_value(selected: &[String], multiple: bool) -> RVal {
+    match (multiple, selected.first()) {
+        (true, _) => RVal::List(selected.to_vec()),
+        (false, Some(key)) => RVal::Str(key.clone()),
+        (false, None) => RVal::None
+    }
+}
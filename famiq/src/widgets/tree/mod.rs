@@ -0,0 +1,206 @@
+pub mod components;
+pub mod helper;
+pub mod styling;
+pub mod systems;
+pub mod tests;
+
+pub use components::*;
+pub(crate) use helper::*;
+pub(crate) use styling::*;
+pub(crate) use systems::*;
+
+use bevy::prelude::*;
+use famiq_macros::set_widget_attributes;
+
+use crate::widgets::container::base_container::*;
+use crate::widgets::color::PRIMARY_COLOR;
+use crate::event_writer::*;
+use crate::reactivity::*;
+use crate::utils::*;
+use crate::widgets::*;
+
+#[set_widget_attributes]
+#[derive(Clone, Debug)]
+pub struct TreeBuilder {
+    /// reactive key of nodes, an array of maps
+    pub nodes_key: String,
+    pub multiple: bool,
+    pub all_reactive_keys: Vec<String>
+}
+
+impl TreeBuilder {
+    pub fn new(font_handle: &Handle<Font>) -> Self {
+        Self {
+            nodes_key: String::new(),
+            multiple: false,
+            all_reactive_keys: Vec::new(),
+            attributes: WidgetAttributes {
+                font_handle: Some(font_handle.clone()),
+                ..default()
+            },
+            cloned_attrs: WidgetAttributes::default()
+        }
+    }
+
+    pub fn set_nodes(&mut self, nodes_key: &str) {
+        self.nodes_key = resolve_key_alias(nodes_key);
+    }
+
+    pub fn set_multiple(&mut self, multiple: bool) {
+        self.multiple = multiple;
+    }
+
+    fn style(&self) -> TreeStyle {
+        let selected_color = match self.cloned_attrs.color {
+            WidgetColor::Default => PRIMARY_COLOR,
+            _ => get_color(&self.cloned_attrs.color)
+        };
+        let text_color = get_text_color(&self.cloned_attrs.color);
+        TreeStyle {
+            font: TextFont {
+                font: self.cloned_attrs.font_handle.clone().unwrap(),
+                font_size: get_text_size(&self.cloned_attrs.size),
+                ..default()
+            },
+            text_color,
+            selected_color: selected_color.with_alpha(0.35),
+            guide_color: text_color.with_alpha(0.25)
+        }
+    }
+
+    pub(crate) fn prepare_attrs(&mut self, r_data: &HashMap<String, RVal>) {
+        self.cloned_attrs = self.attributes.clone();
+        self.cloned_attrs.node = default_tree_node();
+        replace_reactive_keys_common_attrs(&mut self.cloned_attrs, r_data, &mut self.all_reactive_keys);
+        self._process_built_in_color_class();
+        self._process_built_in_size_class();
+    }
+}
+
+impl SetupWidget for TreeBuilder {
+    fn components(&mut self) -> impl Bundle {
+        (IsFamiqTree, MainWidget, ReactiveWidget)
+    }
+
+    fn build(&mut self, r_data: &HashMap<String, RVal>, commands: &mut Commands) -> Entity {
+        self.prepare_attrs(r_data);
+
+        let mut tree = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        let tree_entity = tree.build(r_data, commands);
+
+        commands
+            .entity(tree_entity)
+            .insert((
+                self.components(),
+                TreeNodesKey(self.nodes_key.clone()),
+                TreeMultiple(self.multiple),
+                TreeExpanded::default(),
+                TreeSelected::default(),
+                TreeActive::default(),
+                TreeLastPress::default(),
+                TreeRows::default(),
+                self.style()
+            ));
+
+        insert_class_id(commands, tree_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+        insert_model(commands, tree_entity, &self.cloned_attrs.model_key);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        commands.queue(move |w: &mut World| {
+            w.send_event(UpdateReactiveSubscriberEvent::new(
+                ar_keys,
+                tree_entity,
+                WidgetBuilder {
+                    builder: BuilderType::Tree(cloned_builder)
+                }
+            ));
+        });
+        self.all_reactive_keys.clear();
+        tree_entity
+    }
+
+    fn rebuild(&mut self, r_data: &HashMap<String, RVal>, old_entity: Entity, world: &mut World) {
+        self.prepare_attrs(r_data);
+
+        let mut tree = FaBaseContainer::new_with_attributes(&self.cloned_attrs);
+        tree.rebuild(r_data, old_entity, world);
+
+        // rows are spawned again with the new style
+        world.entity_mut(old_entity).insert(self.style());
+        let old_rows = world
+            .get_mut::<TreeRows>(old_entity)
+            .map(|mut rows| {
+                rows.items.clear();
+                std::mem::take(&mut rows.entities)
+            })
+            .unwrap_or_default();
+        for row in old_rows {
+            world.entity_mut(row).despawn();
+        }
+        if let Some(mut expanded) = world.get_mut::<TreeExpanded>(old_entity) {
+            expanded.set_changed();
+        }
+        insert_class_id_world(world, old_entity, &self.cloned_attrs.id, &self.cloned_attrs.class);
+
+        let cloned_builder = self.clone();
+        let ar_keys = self.all_reactive_keys.clone();
+        world.send_event(UpdateReactiveSubscriberEvent::new(
+            ar_keys,
+            old_entity,
+            WidgetBuilder {
+                builder: BuilderType::Tree(cloned_builder)
+            }
+        ));
+        self.all_reactive_keys.clear();
+    }
+}
+
+/// Macro for creating a tree showing nested nodes of a reactive key.
+///
+/// ```text
+/// tree!(
+///     nodes: "files",
+///     multiple: true,
+///     model: "selected_files"
+/// );
+/// ```
+#[macro_export]
+macro_rules! tree {
+    ( @widget $( $key:ident : $value:tt ),* $(,)? ) => {{
+        let famiq_builder = builder_mut();
+        let t_builder = &mut TreeBuilder::new(&famiq_builder.get_font_handle());
+        $(
+            $crate::tree_attributes!(t_builder, $key : $value);
+        )*
+        t_builder.build(
+            &famiq_builder.reactive_data.data,
+            &mut famiq_builder.ui_root_node.commands()
+        )
+    }};
+
+    // `show_if` may be anywhere among the attributes
+    ( $( $tokens:tt )* ) => {
+        $crate::show_if_widget!(tree [] $( $tokens )*)
+    };
+}
+
+#[macro_export]
+macro_rules! tree_attributes {
+    ($t_builder:ident, nodes: $nodes:expr) => {{
+        $t_builder.set_nodes($nodes);
+    }};
+    ($t_builder:ident, multiple: $multiple:expr) => {{
+        $t_builder.set_multiple($multiple);
+    }};
+    ($t_builder:ident, model: $model:expr) => {{
+        $t_builder.set_model($model);
+    }};
+    ($t_builder:ident, $key:ident : $value:expr) => {{
+        $crate::common_attributes!($t_builder, $key : $value);
+    }};
+}
+
+pub fn can_run_tree_systems(tree_q: Query<&IsFamiqTree>) -> bool {
+    !tree_q.is_empty()
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+pub(crate) const INDENT_WIDTH: f32 = 16.0;
+const GUIDE_OFFSET: f32 = 5.0;
+
+pub fn default_tree_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Column,
+        margin: UiRect {
+            top: Val::Px(2.0),
+            right: Val::Px(0.0),
+            left: Val::Px(0.0),
+            bottom: Val::Px(2.0),
+        },
+        ..default()
+    }
+}
+
+pub fn default_tree_row_node() -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height: Val::Auto,
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Stretch,
+        padding: UiRect::horizontal(Val::Px(4.0)),
+        ..default()
+    }
+}
+
+/// Vertical line of one indentation level, below the arrow of parent row.
+pub fn default_tree_guide_node() -> Node {
+    Node {
+        width: Val::Px(INDENT_WIDTH - GUIDE_OFFSET),
+        flex_shrink: 0.0,
+        margin: UiRect::left(Val::Px(GUIDE_OFFSET)),
+        border: UiRect::left(Val::Px(1.0)),
+        ..default()
+    }
+}
+
+pub fn default_tree_arrow_node() -> Node {
+    Node {
+        width: Val::Px(INDENT_WIDTH),
+        flex_shrink: 0.0,
+        align_self: AlignSelf::Center,
+        ..default()
+    }
+}
+
+pub fn default_tree_label_node() -> Node {
+    Node {
+        padding: UiRect::vertical(Val::Px(3.0)),
+        align_self: AlignSelf::Center,
+        ..default()
+    }
+}
@@ -0,0 +1,326 @@
+use bevy::prelude::*;
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+
+use super::*;
+
+/// Pressing the same node again within this time activates it.
+const DOUBLE_PRESS_SECS: f64 = 0.4;
+
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub(crate) struct TreeStateQuery {
+    pub entity: Entity,
+    pub rows: &'static TreeRows,
+    pub expanded: &'static mut TreeExpanded,
+    pub selected: &'static mut TreeSelected,
+    pub active: &'static mut TreeActive,
+    pub last_press: &'static mut TreeLastPress,
+    pub multiple: &'static TreeMultiple,
+    pub id: Option<&'static WidgetId>,
+    pub model_key: Option<&'static ReactiveModelKey>
+}
+
+type TreeRenderQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Ref<'static, IsFamiqTree>,
+        &'static TreeNodesKey,
+        Ref<'static, TreeExpanded>,
+        Ref<'static, TreeSelected>,
+        &'static mut TreeRows,
+        &'static TreeStyle
+    )
+>;
+
+/// Writes selection to the model & sends value change and tree events.
+#[derive(SystemParam)]
+pub(crate) struct TreeWriterParam<'w> {
+    pub r_data: ResMut<'w, RData>,
+    pub value_writer: EventWriter<'w, FaValueChangeEvent>,
+    pub tree_writer: EventWriter<'w, FaTreeEvent>
+}
+
+impl TreeWriterParam<'_> {
+    fn send(&mut self, event_type: TreeEventType, tree: &TreeStateQueryItem, item: &TreeItem) {
+        FaTreeEvent::send_event(&mut self.tree_writer, event_type, tree.entity, tree.id, &item.key, &item.path);
+    }
+
+    fn select(&mut self, tree: &mut TreeStateQueryItem, keys: Vec<String>) {
+        if tree.selected.0 == keys {
+            return;
+        }
+        tree.selected.0 = keys;
+        let value = selection_value(&tree.selected.0, tree.multiple.0);
+
+        if let Some(key) = tree.model_key {
+            let _ = self.r_data.try_mutate(&key.0, value.clone());
+        }
+        FaValueChangeEvent::send_event(&mut self.value_writer, WidgetType::Tree, tree.entity, tree.id, value);
+    }
+
+    /// Make the node active and the only selected one.
+    fn move_to(&mut self, tree: &mut TreeStateQueryItem, item: &TreeItem) {
+        tree.active.0 = Some(item.key.clone());
+        self.select(tree, vec![item.key.clone()]);
+        self.send(TreeEventType::Select, tree, item);
+    }
+
+    /// Expand or collapse the node, false if nothing changed.
+    fn expand(&mut self, tree: &mut TreeStateQueryItem, item: &TreeItem, expand: bool) -> bool {
+        if !item.has_children || tree.expanded.0.contains(&item.key) == expand {
+            return false;
+        }
+        let event_type = if expand {
+            tree.expanded.0.insert(item.key.clone());
+            TreeEventType::Expand
+        } else {
+            tree.expanded.0.remove(&item.key);
+            TreeEventType::Collapse
+        };
+        self.send(event_type, tree, item);
+        true
+    }
+}
+
+pub(crate) fn on_arrow_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    arrow_q: Query<&TreeArrow>,
+    mut tree_q: Query<TreeStateQuery, With<IsFamiqTree>>,
+    mut writer: TreeWriterParam
+) {
+    trigger.propagate(false);
+
+    let Ok(arrow) = arrow_q.get(trigger.target()) else { return };
+    let Ok(mut tree) = tree_q.get_mut(arrow.tree) else { return };
+    let rows = tree.rows;
+
+    if let Some(item) = rows.items.get(arrow.slot) {
+        writer.expand(&mut tree, item, !item.expanded);
+    }
+}
+
+/// Pressing a row selects its node, or toggles it with ctrl in multiple mode.
+/// Pressing the same node twice quickly activates it.
+pub(crate) fn on_row_down(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    row_q: Query<&TreeRow>,
+    mut tree_q: Query<TreeStateQuery, With<IsFamiqTree>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut famiq_res: ResMut<FamiqResource>,
+    mut writer: TreeWriterParam
+) {
+    trigger.propagate(false);
+
+    let Ok(row) = row_q.get(trigger.target()) else { return };
+    let Ok(mut tree) = tree_q.get_mut(row.tree) else { return };
+    let rows = tree.rows;
+    let Some(item) = rows.items.get(row.slot).filter(|item| !item.loading) else { return };
+
+    if tree.multiple.0 && keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        let new_selected = toggled_selection(&tree.selected.0, &item.key);
+        writer.select(&mut tree, new_selected);
+        tree.active.0 = Some(item.key.clone());
+
+        if tree.selected.0.contains(&item.key) {
+            writer.send(TreeEventType::Select, &tree, item);
+        }
+    } else {
+        writer.move_to(&mut tree, item);
+    }
+
+    let now = time.elapsed_secs_f64();
+    match &tree.last_press.0 {
+        Some((key, pressed_at)) if *key == item.key && now - pressed_at <= DOUBLE_PRESS_SECS => {
+            writer.send(TreeEventType::Activate, &tree, item);
+            tree.last_press.0 = None;
+        }
+        _ => tree.last_press.0 = Some((item.key.clone(), now))
+    }
+    famiq_res.update_all_focus_states(false);
+    famiq_res.update_or_insert_focus_state(row.tree, true);
+}
+
+/// Keyboard navigation of focused tree. Up & Down move between visible nodes, Right expands
+/// or moves to the first child, Left collapses or moves to the parent, Enter activates and
+/// Space toggles selection in multiple mode.
+pub(crate) fn handle_tree_keyboard(
+    mut evr_kbd: EventReader<KeyboardInput>,
+    mut tree_q: Query<TreeStateQuery, With<IsFamiqTree>>,
+    famiq_res: Res<FamiqResource>,
+    mut writer: TreeWriterParam
+) {
+    for e in evr_kbd.read() {
+        if e.state == ButtonState::Released {
+            continue;
+        }
+
+        for mut tree in tree_q.iter_mut() {
+            if famiq_res.get_widget_focus_state(&tree.entity) != Some(true) {
+                continue;
+            }
+            let rows = tree.rows;
+            let items = &rows.items;
+            let slot = tree.active.0
+                .as_ref()
+                .and_then(|key| items.iter().position(|item| item.key == *key));
+
+            match (e.key_code, slot) {
+                (KeyCode::ArrowDown | KeyCode::ArrowUp, _) => {
+                    if let Some(next) = step_slot(items, slot, e.key_code == KeyCode::ArrowDown) {
+                        writer.move_to(&mut tree, &items[next]);
+                    }
+                }
+                (KeyCode::ArrowRight, Some(slot)) => {
+                    let item = &items[slot];
+
+                    if !writer.expand(&mut tree, item, true)
+                        && item.expanded
+                        && let Some(child) = step_slot(items, Some(slot), true)
+                        && items[child].depth > item.depth
+                    {
+                        writer.move_to(&mut tree, &items[child]);
+                    }
+                }
+                (KeyCode::ArrowLeft, Some(slot)) => {
+                    if !writer.expand(&mut tree, &items[slot], false)
+                        && let Some(parent) = parent_slot(items, slot)
+                    {
+                        writer.move_to(&mut tree, &items[parent]);
+                    }
+                }
+                (KeyCode::Enter | KeyCode::NumpadEnter, Some(slot)) => {
+                    writer.send(TreeEventType::Activate, &tree, &items[slot]);
+                }
+                (KeyCode::Space, Some(slot)) if tree.multiple.0 => {
+                    let new_selected = toggled_selection(&tree.selected.0, &items[slot].key);
+                    writer.select(&mut tree, new_selected);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn detect_tree_reactive_model_change(
+    reactive_data: Res<RData>,
+    mut tree_q: Query<(Ref<IsFamiqTree>, &mut TreeSelected, Option<&ReactiveModelKey>)>
+) {
+    let data_changed = reactive_data.is_changed() && !reactive_data.is_added();
+
+    for (tree, mut selected, model_key) in tree_q.iter_mut() {
+        if !data_changed && !tree.is_added() {
+            continue;
+        }
+        let Some(key) = model_key else { continue };
+
+        let keys = match get_reactive_value(&reactive_data.data, &key.0) {
+            Some(RVal::Str(v)) => vec![v.clone()],
+            Some(RVal::List(v)) => v.clone(),
+            Some(RVal::None) => Vec::new(),
+            _ => continue
+        };
+        selected.set_if_neq(TreeSelected(keys));
+    }
+}
+
+fn spawn_tree_row(commands: &mut Commands, row: TreeRow, item: &TreeItem, style: &TreeStyle) -> Entity {
+    let (tree_entity, slot) = (row.tree, row.slot);
+    let row_entity = commands
+        .spawn((default_tree_row_node(), row, BackgroundColor(Color::NONE)))
+        .observe(on_row_down)
+        .observe(on_pointer_cursor_over)
+        .observe(on_pointer_cursor_out)
+        .id();
+
+    for _ in 0..item.depth {
+        let guide_entity = commands
+            .spawn((default_tree_guide_node(), BorderColor(style.guide_color), Pickable::IGNORE))
+            .id();
+        commands.entity(row_entity).add_child(guide_entity);
+    }
+
+    let arrow = match (item.has_children, item.expanded) {
+        (false, _) => "",
+        (true, false) => "►",
+        (true, true) => "▼"
+    };
+    let mut arrow_commands = commands.spawn((
+        default_tree_arrow_node(),
+        Text::new(arrow),
+        style.font.clone(),
+        TextColor(style.text_color)
+    ));
+    if item.has_children {
+        arrow_commands
+            .insert(TreeArrow { tree: tree_entity, slot })
+            .observe(on_arrow_down)
+            .observe(on_pointer_cursor_over)
+            .observe(on_pointer_cursor_out);
+    } else {
+        arrow_commands.insert(Pickable::IGNORE);
+    }
+    let arrow_entity = arrow_commands.id();
+
+    let label_color = if item.loading { style.text_color.with_alpha(0.5) } else { style.text_color };
+    let label_entity = commands
+        .spawn((
+            default_tree_label_node(),
+            Text::new(item.label.clone()),
+            style.font.clone(),
+            TextColor(label_color),
+            Pickable::IGNORE
+        ))
+        .id();
+
+    commands.entity(row_entity).add_children(&[arrow_entity, label_entity]);
+    row_entity
+}
+
+/// Internal system to show visible nodes of trees along with the selected ones.
+/// Rows are spawned again only if visible nodes change.
+pub(crate) fn update_tree_rows(
+    mut commands: Commands,
+    reactive_data: Res<RData>,
+    mut tree_q: TreeRenderQuery,
+    mut row_q: Query<&mut BackgroundColor, With<TreeRow>>
+) {
+    for (entity, tree, nodes_key, expanded, selected, mut rows, style) in tree_q.iter_mut() {
+        let need_update = reactive_data.is_changed()
+            || tree.is_added()
+            || expanded.is_changed()
+            || selected.is_changed();
+
+        if !need_update {
+            continue;
+        }
+        let items = visible_tree_items(&reactive_data.data, &nodes_key.0, &expanded.0);
+
+        if items != rows.items || items.len() != rows.entities.len() {
+            for row_entity in rows.entities.drain(..) {
+                commands.entity(row_entity).despawn();
+            }
+            for (slot, item) in items.iter().enumerate() {
+                let row_entity = spawn_tree_row(&mut commands, TreeRow { tree: entity, slot }, item, style);
+                commands.entity(entity).add_child(row_entity);
+                rows.entities.push(row_entity);
+            }
+            rows.items = items;
+        }
+        for (row_entity, item) in rows.entities.iter().zip(rows.items.iter()) {
+            let color = if selected.0.contains(&item.key) { style.selected_color } else { Color::NONE };
+
+            if let Ok(mut bg_color) = row_q.get_mut(*row_entity) {
+                bg_color.0 = color;
+            } else {
+                // row spawned above, not applied yet
+                commands.entity(*row_entity).insert(BackgroundColor(color));
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+#![cfg(test)]
+
+use crate::widgets::FamiqResource;
+use crate::tree;
+use bevy::input::keyboard::Key;
+use super::*;
+
+fn setup_test_tree(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_data("files", vec![
+        TreeNode::new("src").id("src").children(vec![
+            TreeNode::new("main.rs"),
+            TreeNode::new("lib.rs")
+        ]),
+        TreeNode::new("assets").id("assets").lazy(),
+        TreeNode::new("README.md").id("readme")
+    ]);
+    fa_query.insert_none("selected");
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    tree!(id: "#test-tree", nodes: "files", model: "selected");
+}
+
+fn setup_test_multiple_tree(
+    mut famiq_res: ResMut<FamiqResource>,
+    mut fa_query: FaQuery
+) {
+    fa_query.insert_data("files", vec![TreeNode::new("a").id("a"), TreeNode::new("b").id("b")]);
+    fa_query.insert_str_list("selected", Vec::new());
+    FamiqBuilder::new(&mut fa_query, &mut famiq_res).inject();
+    tree!(nodes: "files", multiple: true, model: "selected");
+}
+
+fn tree_entity(app: &mut App) -> Entity {
+    app.world_mut()
+        .query_filtered::<Entity, With<IsFamiqTree>>()
+        .single(app.world())
+        .unwrap()
+}
+
+/// Labels of visible rows in display order.
+fn row_labels(app: &mut App) -> Vec<String> {
+    let entity = tree_entity(app);
+    let rows = app.world().get::<TreeRows>(entity).unwrap().entities.clone();
+
+    rows.iter()
+        .map(|row| {
+            let label = *app.world().get::<Children>(*row).unwrap().last().unwrap();
+            app.world().get::<Text>(label).unwrap().0.clone()
+        })
+        .collect()
+}
+
+fn tree_events(app: &App) -> Vec<(TreeEventType, String)> {
+    let events = app.world().resource::<Events<FaTreeEvent>>();
+    events
+        .get_cursor()
+        .read(events)
+        .map(|e| (e.event_type, e.node.clone()))
+        .collect()
+}
+
+#[test]
+fn test_tree_keyboard_expand_and_select() {
+    let mut app = create_famiq_test_app(setup_test_tree);
+    app.update();
+    let entity = tree_entity(&mut app);
+    assert_eq!(vec!["src", "assets", "README.md"], row_labels(&mut app));
+
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    press_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    assert_eq!(Some(&RVal::Str("src".to_string())), app.world().resource::<RData>().data.get("selected"));
+
+    press_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    assert_eq!(vec!["src", "main.rs", "lib.rs", "assets", "README.md"], row_labels(&mut app));
+    assert!(tree_events(&app).contains(&(TreeEventType::Expand, "src".to_string())));
+
+    // moves to the first child, nodes without id are keyed by index path
+    press_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    assert_eq!(TreeSelected(vec!["0.0".to_string()]), *app.world().get::<TreeSelected>(entity).unwrap());
+
+    press_key(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+    press_key(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+    assert_eq!(vec!["src", "assets", "README.md"], row_labels(&mut app));
+    assert_eq!(TreeActive(Some("src".to_string())), *app.world().get::<TreeActive>(entity).unwrap());
+}
+
+#[test]
+fn test_tree_lazy_children() {
+    let mut app = create_famiq_test_app(setup_test_tree);
+    app.update();
+    let entity = tree_entity(&mut app);
+
+    app.world_mut().get_mut::<TreeExpanded>(entity).unwrap().0.insert("assets".to_string());
+    app.update();
+    assert_eq!(vec!["src", "assets", LOADING_LABEL, "README.md"], row_labels(&mut app));
+
+    let mut r_data = app.world_mut().resource_mut::<RData>();
+    *get_reactive_value_mut(&mut r_data.data, "files.1.children").unwrap() = vec![TreeNode::new("icon.png")].into_rval();
+    r_data.mark_changed("files.1.children");
+    app.update();
+    assert_eq!(vec!["src", "assets", "icon.png", "README.md"], row_labels(&mut app));
+}
+
+#[test]
+fn test_tree_multiple_selection_follows_model() {
+    let mut app = create_famiq_test_app(setup_test_multiple_tree);
+    app.update();
+    let entity = tree_entity(&mut app);
+
+    app.world_mut()
+        .resource_mut::<RData>()
+        .data
+        .insert("selected".to_string(), RVal::List(vec!["a".to_string(), "b".to_string()]));
+    app.update();
+    assert_eq!(TreeSelected(vec!["a".to_string(), "b".to_string()]), *app.world().get::<TreeSelected>(entity).unwrap());
+
+    let rows = app.world().get::<TreeRows>(entity).unwrap().entities.clone();
+    for row in rows {
+        assert_ne!(Color::NONE, app.world().get::<BackgroundColor>(row).unwrap().0);
+    }
+
+    // space toggles the active node
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    app.world_mut().get_mut::<TreeActive>(entity).unwrap().0 = Some("a".to_string());
+    press_key(&mut app, KeyCode::Space, Key::Space);
+    assert_eq!(Some(&RVal::List(vec!["b".to_string()])), app.world().resource::<RData>().data.get("selected"));
+}
+
+#[test]
+fn test_tree_selection_sends_reactive_data_changed() {
+    let mut app = create_famiq_test_app(setup_test_tree);
+    app.update();
+    let entity = tree_entity(&mut app);
+
+    app.world_mut().resource_mut::<FamiqResource>().update_or_insert_focus_state(entity, true);
+    press_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+
+    let events = app.world().resource::<Events<ReactiveDataChanged>>();
+    let changes: Vec<&ReactiveDataChanged> = events.iter_current_update_events().collect();
+    assert_eq!(changes, vec![&ReactiveDataChanged {
+        key: "selected".to_string(),
+        old: RVal::None,
+        new: RVal::Str("src".to_string())
+    }]);
+}
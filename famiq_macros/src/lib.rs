@@ -33,3 +33,148 @@ pub fn set_widget_attributes(_attr: TokenStream, item: TokenStream) -> TokenStre
     };
     expanded.into()
 }
+
+/// Derive `IntoRVal` for structs and unit-only enums.
+///
+/// Structs with named fields become `RVal::Map`, newtype structs become their inner value
+/// and unit enum variants become `RVal::Str` holding the variant name.
+#[proc_macro_derive(IntoRVal)]
+pub fn derive_into_rval(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    let name = &input.ident;
+    let generics = with_bound(&input.generics, quote! { ::famiq::reactivity::IntoRVal });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let entries = fields.named.iter().map(|f| {
+                    let ident = f.ident.as_ref().unwrap();
+                    let key = ident.to_string();
+                    quote! {
+                        (#key.to_string(), ::famiq::reactivity::IntoRVal::into_rval(self.#ident))
+                    }
+                });
+                quote! {
+                    ::famiq::reactivity::RVal::Map([ #( #entries ),* ].into_iter().collect())
+                }
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                ::famiq::reactivity::IntoRVal::into_rval(self.0)
+            },
+            _ => return unsupported(name, "IntoRVal")
+        },
+        Data::Enum(data) if data.variants.iter().all(|v| v.fields.is_empty()) => {
+            let arms = data.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let variant = ident.to_string();
+                quote! { Self::#ident => #variant }
+            });
+            quote! {
+                ::famiq::reactivity::RVal::Str(match self { #( #arms ),* }.to_string())
+            }
+        }
+        _ => return unsupported(name, "IntoRVal")
+    };
+
+    quote! {
+        impl #impl_generics ::famiq::reactivity::IntoRVal for #name #ty_generics #where_clause {
+            fn into_rval(self) -> ::famiq::reactivity::RVal {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Derive `FromRVal` for structs and unit-only enums, the opposite of `IntoRVal`.
+///
+/// Missing fields are read as `RVal::None`, so `Option` fields may be missing.
+#[proc_macro_derive(FromRVal)]
+pub fn derive_from_rval(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    let name = &input.ident;
+    let generics = with_bound(&input.generics, quote! { ::famiq::reactivity::FromRVal });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let fields = fields.named.iter().map(|f| {
+                    let ident = f.ident.as_ref().unwrap();
+                    let key = ident.to_string();
+                    quote! {
+                        #ident: match map.get(#key) {
+                            Some(v) => ::famiq::reactivity::FromRVal::from_rval(v)?,
+                            None => ::famiq::reactivity::FromRVal::from_rval(&::famiq::reactivity::RVal::None)
+                                .map_err(|_| ::famiq::errors::FamiqError::KeyNotFound(#key.to_string()))?
+                        }
+                    }
+                });
+                quote! {
+                    match value {
+                        ::famiq::reactivity::RVal::Map(map) => Ok(Self { #( #fields ),* }),
+                        _ => Err(::famiq::errors::FamiqError::TypeMismatch {
+                            expected: "Map",
+                            found: value.variant_name()
+                        })
+                    }
+                }
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                Ok(Self(::famiq::reactivity::FromRVal::from_rval(value)?))
+            },
+            _ => return unsupported(name, "FromRVal")
+        },
+        Data::Enum(data) if data.variants.iter().all(|v| v.fields.is_empty()) => {
+            let arms = data.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let variant = ident.to_string();
+                quote! { ::famiq::reactivity::RVal::Str(s) if s == #variant => Ok(Self::#ident) }
+            });
+            quote! {
+                match value {
+                    #( #arms, )*
+                    _ => Err(::famiq::errors::FamiqError::TypeMismatch {
+                        expected: stringify!(#name),
+                        found: value.variant_name()
+                    })
+                }
+            }
+        }
+        _ => return unsupported(name, "FromRVal")
+    };
+
+    quote! {
+        impl #impl_generics ::famiq::reactivity::FromRVal for #name #ty_generics #where_clause {
+            fn from_rval(
+                value: &::famiq::reactivity::RVal
+            ) -> ::core::result::Result<Self, ::famiq::errors::FamiqError> {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+/// Generics with `bound` required on every type parameter, fields of generic types
+/// are converted through it.
+fn with_bound(generics: &Generics, bound: proc_macro2::TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    let where_clause = generics.make_where_clause();
+
+    for param in params {
+        where_clause.predicates.push(parse_quote! { #param: #bound });
+    }
+    generics
+}
+
+fn unsupported(name: &Ident, derive: &str) -> TokenStream {
+    Error::new(
+        name.span(),
+        format!("{} can only be derived for structs with named fields, newtype structs and unit-only enums", derive)
+    )
+    .to_compile_error()
+    .into()
+}